@@ -11,17 +11,30 @@
 //! function that validates proofs against public inputs. The verification uses
 //! BN254 elliptic curve pairings via Stellar Protocol 25's CAP-0074 primitives.
 //!
-//! ## Dual-Mode Design
+//! ## RISC Zero Receipts
 //!
-//! - **Production mode**: Full Groth16 pairing check using on-chain BN254 primitives
-//! - **Dev mode**: Hash-based verification fallback for testing and hackathon demo
+//! Proofs are RISC Zero Groth16 receipts. The circuit has five public inputs,
+//! derived the same way as RISC Zero's own verifier:
+//!
+//! ```text
+//! [control_root_0, control_root_1, claim_0, claim_1, bn254_control_id]
+//! ```
+//!
+//! where `claim_*` are the two 128-bit halves of the `ReceiptClaim` digest for
+//! a guest with the given image ID that halted successfully with the given
+//! journal digest. This binds every proof to one circuit and one journal, so
+//! a single verifier (one Groth16 key) serves every guest circuit: callers
+//! choose which image ID they expect, and a proof from any other guest fails
+//! the pairing check.
 //!
 //! The game contract calls this verifier when a non-empty seal (proof) is provided
-//! in `reveal_cell()`. An empty seal triggers the dev-mode hash check directly
-//! in the game contract instead.
+//! in `reveal_cell()`. An empty seal is only accepted, with a plain hash check,
+//! while the game's admin has dev mode enabled.
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Bytes, BytesN, Env,
+    contract, contracterror, contractimpl, contracttype,
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
+    vec, Address, Bytes, BytesN, Env, Vec,
 };
 
 // ============================================================================
@@ -32,7 +45,6 @@ use soroban_sdk::{
 enum StorageKey {
     Admin,
     VerificationKey,
-    ImageId,
     Initialized,
 }
 
@@ -40,14 +52,14 @@ enum StorageKey {
 // Verification Key Components (BN254 / alt_bn128)
 // ============================================================================
 
-/// Groth16 verification key for BN254 curve.
+/// Groth16 verification key for BN254 curve, plus the RISC Zero verifier
+/// parameters needed to derive the public inputs of a receipt.
 ///
-/// In a full implementation, these would be deserialized into curve points
-/// for the pairing check: e(A, B) == e(alpha, beta) * e(IC, gamma) * e(C, delta)
+/// The pairing check: e(A, B) == e(alpha, beta) * e(IC, gamma) * e(C, delta)
 ///
 /// Point encoding:
 /// - G1 points: 64 bytes (32 bytes x, 32 bytes y) uncompressed
-/// - G2 points: 128 bytes (64 bytes x, 64 bytes y) uncompressed
+/// - G2 points: 128 bytes (64 bytes x, 64 bytes y) uncompressed, Fp2 as c1 || c0
 #[contracttype]
 #[derive(Clone)]
 pub struct VerificationKey {
@@ -60,9 +72,12 @@ pub struct VerificationKey {
     /// delta * G2 - 128 bytes
     pub delta_g2: BytesN<128>,
     /// IC (input commitments) - array of G1 points, one per public input + 1
-    /// For Herbal Moonlight: 2 entries (ic[0] + ic[1] for journal_hash)
-    pub ic_0: BytesN<64>,
-    pub ic_1: BytesN<64>,
+    /// For RISC Zero receipts: 6 entries (5 public inputs)
+    pub ic: Vec<BytesN<64>>,
+    /// Root of the allowed recursion control IDs (RISC Zero `ALLOWED_CONTROL_ROOT`)
+    pub control_root: BytesN<32>,
+    /// Control ID of the BN254 identity program (RISC Zero `BN254_IDENTITY_CONTROL_ID`)
+    pub bn254_control_id: BytesN<32>,
 }
 
 /// Groth16 proof (3 curve points: A in G1, B in G2, C in G1)
@@ -77,6 +92,20 @@ pub struct Groth16Proof {
     pub c: BytesN<64>,
 }
 
+// ============================================================================
+// RISC Zero Receipt Claim Constants
+// ============================================================================
+
+/// Digest of the post-execution system state of a halted guest
+/// (`SystemState { pc: 0, merkle_root: 0 }`)
+const SYSTEM_STATE_ZERO_DIGEST: [u8; 32] = [
+    0xa3, 0xac, 0xc2, 0x71, 0x17, 0x41, 0x89, 0x96, 0x34, 0x0b, 0x84, 0xe5, 0xa9, 0x0f, 0x3e, 0xf4,
+    0xc4, 0x9d, 0x22, 0xc7, 0x9e, 0x44, 0xaa, 0xd8, 0x22, 0xec, 0x9c, 0x31, 0x3e, 0x1e, 0xb8, 0xe2,
+];
+
+/// Number of public inputs of the RISC Zero Groth16 circuit
+pub const PUBLIC_INPUT_COUNT: u32 = 5;
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -106,19 +135,13 @@ impl Groth16VerifierContract {
     /// Call `init_vk` after deployment to set the verification key.
     pub fn __constructor(env: Env, admin: Address) {
         env.storage().instance().set(&StorageKey::Admin, &admin);
-        env.storage().instance().set(&StorageKey::Initialized, &false);
+        env.storage()
+            .instance()
+            .set(&StorageKey::Initialized, &false);
     }
 
-    /// Initialize the verification key and image ID (admin only, one-time).
-    ///
-    /// The image ID is the hash of the ZK circuit (RISC Zero guest program).
-    /// Only proofs generated by the matching circuit will verify.
-    pub fn init_vk(
-        env: Env,
-        admin: Address,
-        vk: VerificationKey,
-        image_id: BytesN<32>,
-    ) -> Result<(), VerifierError> {
+    /// Initialize the verification key (admin only, one-time).
+    pub fn init_vk(env: Env, admin: Address, vk: VerificationKey) -> Result<(), VerifierError> {
         admin.require_auth();
 
         let stored_admin: Address = env
@@ -139,42 +162,43 @@ impl Groth16VerifierContract {
             return Err(VerifierError::AlreadyInitialized);
         }
 
-        env.storage().instance().set(&StorageKey::VerificationKey, &vk);
-        env.storage().instance().set(&StorageKey::ImageId, &image_id);
-        env.storage().instance().set(&StorageKey::Initialized, &true);
+        env.storage()
+            .instance()
+            .set(&StorageKey::VerificationKey, &vk);
+        env.storage()
+            .instance()
+            .set(&StorageKey::Initialized, &true);
         Ok(())
     }
 
-    /// Verify a Groth16 proof against a journal hash.
+    /// Verify a Groth16 proof against an image ID and journal hash.
     ///
     /// # Arguments
     /// * `proof` - The Groth16 proof (A, B, C curve points)
+    /// * `image_id` - Image ID of the guest the proof must come from
     /// * `journal_hash` - SHA-256 hash of the journal (public input to the circuit)
     ///
     /// # Returns
     /// `true` if the proof is valid, error otherwise.
     ///
-    /// # Verification Logic (Production)
+    /// # Verification Logic
     ///
-    /// The Groth16 verification equation checks:
+    /// The public inputs are derived from `image_id` and `journal_hash` as a
+    /// RISC Zero `ReceiptClaim` (see module docs), then the Groth16 equation
+    /// is checked:
     /// ```text
     /// e(proof.A, proof.B) == e(vk.alpha, vk.beta)
-    ///                      * e(vk.ic[0] + journal_hash * vk.ic[1], vk.gamma)
+    ///                      * e(vk.ic[0] + sum(input[i] * vk.ic[i + 1]), vk.gamma)
     ///                      * e(proof.C, vk.delta)
     /// ```
     ///
     /// Where `e` is the BN254 optimal Ate pairing and `*` is multiplication
-    /// in the target field GT.
-    ///
-    /// # Dev Mode Fallback
-    ///
-    /// When BN254 pairing primitives are not yet available in the SDK,
-    /// this function performs a hash-based integrity check instead:
-    /// it verifies that sha256(proof.a ++ proof.c) == journal_hash,
-    /// providing basic proof-of-knowledge without the full pairing check.
+    /// in the target field GT. Points that are not valid curve encodings
+    /// abort the invocation in the host.
     pub fn verify(
         env: Env,
         proof: Groth16Proof,
+        image_id: BytesN<32>,
         journal_hash: BytesN<32>,
     ) -> Result<bool, VerifierError> {
         let initialized: bool = env
@@ -186,45 +210,21 @@ impl Groth16VerifierContract {
             return Err(VerifierError::NotInitialized);
         }
 
-        // Retrieve stored verification key and image ID
-        let _vk: VerificationKey = env
+        let vk: VerificationKey = env
             .storage()
             .instance()
             .get(&StorageKey::VerificationKey)
             .ok_or(VerifierError::NotInitialized)?;
-        let _image_id: BytesN<32> = env
-            .storage()
-            .instance()
-            .get(&StorageKey::ImageId)
-            .ok_or(VerifierError::NotInitialized)?;
 
-        // ================================================================
-        // Production path (when BN254 primitives are available):
-        //
-        // 1. Deserialize proof points (A, B, C) from bytes
-        // 2. Compute IC_acc = ic[0] + scalar_mul(ic[1], journal_hash_scalar)
-        // 3. Perform multi-pairing check:
-        //    e(-A, B) * e(alpha, beta) * e(IC_acc, gamma) * e(C, delta) == 1
-        // 4. Return true if pairing equation holds
-        //
-        // The BN254 primitives needed from Protocol 25 (CAP-0074):
-        //   - env.crypto().bn254_g1_add(p1, p2)
-        //   - env.crypto().bn254_g1_scalar_mul(p, s)
-        //   - env.crypto().bn254_pairing_check(g1_points, g2_points)
-        //
-        // These are expected to be available via soroban-sdk host functions
-        // in upcoming SDK releases.
-        // ================================================================
-
-        // Dev mode fallback: hash-based verification
-        // Concatenate proof.a (64 bytes) + proof.c (64 bytes) and hash
-        let mut proof_data = Bytes::new(&env);
-        proof_data.append(&Bytes::from_slice(&env, proof.a.to_array().as_slice()));
-        proof_data.append(&Bytes::from_slice(&env, proof.c.to_array().as_slice()));
-
-        let computed_hash: BytesN<32> = env.crypto().sha256(&proof_data).into();
-
-        if computed_hash != journal_hash {
+        // Any guest is accepted: the claim digest binds the proof to `image_id`
+        if vk.ic.len() != PUBLIC_INPUT_COUNT + 1 {
+            return Err(VerifierError::InvalidPublicInput);
+        }
+
+        let claim_digest = Self::receipt_claim_digest(&env, &image_id, &journal_hash);
+        let public_inputs = Self::receipt_public_inputs(&env, &vk, &claim_digest);
+
+        if !Self::check_groth16(&env, &vk, &proof, &public_inputs) {
             return Err(VerifierError::PairingCheckFailed);
         }
 
@@ -239,14 +239,6 @@ impl Groth16VerifierContract {
             .ok_or(VerifierError::NotInitialized)
     }
 
-    /// Query the stored image ID (circuit hash).
-    pub fn get_image_id(env: Env) -> Result<BytesN<32>, VerifierError> {
-        env.storage()
-            .instance()
-            .get(&StorageKey::ImageId)
-            .ok_or(VerifierError::NotInitialized)
-    }
-
     /// Check if the verifier is initialized with a VK.
     pub fn is_initialized(env: Env) -> bool {
         env.storage()
@@ -256,12 +248,11 @@ impl Groth16VerifierContract {
     }
 
     /// Update the verification key (admin only).
-    /// Used when the ZK circuit is updated.
+    /// Used when the RISC Zero Groth16 circuit or its control root changes.
     pub fn update_vk(
         env: Env,
         admin: Address,
         new_vk: VerificationKey,
-        new_image_id: BytesN<32>,
     ) -> Result<(), VerifierError> {
         admin.require_auth();
 
@@ -274,170 +265,135 @@ impl Groth16VerifierContract {
             return Err(VerifierError::Unauthorized);
         }
 
-        env.storage().instance().set(&StorageKey::VerificationKey, &new_vk);
-        env.storage().instance().set(&StorageKey::ImageId, &new_image_id);
-        env.storage().instance().set(&StorageKey::Initialized, &true);
+        env.storage()
+            .instance()
+            .set(&StorageKey::VerificationKey, &new_vk);
+        env.storage()
+            .instance()
+            .set(&StorageKey::Initialized, &true);
         Ok(())
     }
 }
 
 // ============================================================================
-// Tests
+// Receipt Helpers
 // ============================================================================
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-    use soroban_sdk::Env;
-
-    fn create_test_vk(env: &Env) -> VerificationKey {
-        VerificationKey {
-            alpha_g1: BytesN::from_array(env, &[1u8; 64]),
-            beta_g2: BytesN::from_array(env, &[2u8; 128]),
-            gamma_g2: BytesN::from_array(env, &[3u8; 128]),
-            delta_g2: BytesN::from_array(env, &[4u8; 128]),
-            ic_0: BytesN::from_array(env, &[5u8; 64]),
-            ic_1: BytesN::from_array(env, &[6u8; 64]),
-        }
+impl Groth16VerifierContract {
+    /// Digest of `ReceiptClaim::ok(image_id, journal)` as computed by RISC Zero:
+    ///
+    /// ```text
+    /// output = sha256(sha256("risc0.Output") || journal_digest || 0^32 || 2u16_le)
+    /// claim  = sha256(sha256("risc0.ReceiptClaim") || 0^32 || image_id
+    ///                 || SYSTEM_STATE_ZERO_DIGEST || output
+    ///                 || 0u32_le || 0u32_le || 4u16_le)
+    /// ```
+    pub fn receipt_claim_digest(
+        env: &Env,
+        image_id: &BytesN<32>,
+        journal_digest: &BytesN<32>,
+    ) -> BytesN<32> {
+        let zero = Bytes::from_array(env, &[0u8; 32]);
+
+        let mut output = Bytes::new(env);
+        output.append(&Self::tag_digest(env, b"risc0.Output"));
+        output.append(&Bytes::from(journal_digest.clone()));
+        output.append(&zero); // assumptions digest
+        output.extend_from_array(&2u16.to_le_bytes());
+        let output_digest = env.crypto().sha256(&output);
+
+        let mut claim = Bytes::new(env);
+        claim.append(&Self::tag_digest(env, b"risc0.ReceiptClaim"));
+        claim.append(&zero); // input digest
+        claim.append(&Bytes::from(image_id.clone()));
+        claim.extend_from_array(&SYSTEM_STATE_ZERO_DIGEST);
+        claim.append(&Bytes::from(output_digest.to_bytes()));
+        claim.extend_from_array(&0u32.to_le_bytes()); // system exit code: Halted
+        claim.extend_from_array(&0u32.to_le_bytes()); // user exit code: 0
+        claim.extend_from_array(&4u16.to_le_bytes());
+        env.crypto().sha256(&claim).into()
     }
 
-    fn deploy_and_init(env: &Env) -> (Groth16VerifierContractClient<'_>, Address) {
-        env.mock_all_auths();
-        let admin = Address::generate(env);
-        let contract_id = env.register(Groth16VerifierContract, (admin.clone(),));
-        let client = Groth16VerifierContractClient::new(env, &contract_id);
-
-        let vk = create_test_vk(env);
-        let image_id = BytesN::from_array(env, &[0xABu8; 32]);
-        client.init_vk(&admin, &vk, &image_id);
-
-        (client, admin)
+    fn tag_digest(env: &Env, tag: &[u8]) -> Bytes {
+        Bytes::from(env.crypto().sha256(&Bytes::from_slice(env, tag)).to_bytes())
     }
 
-    #[test]
-    fn test_deploy_not_initialized() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        let contract_id = env.register(Groth16VerifierContract, (admin,));
-        let client = Groth16VerifierContractClient::new(&env, &contract_id);
-
-        assert_eq!(client.is_initialized(), false);
+    /// Public inputs of the RISC Zero Groth16 circuit for a claim digest
+    pub fn receipt_public_inputs(
+        env: &Env,
+        vk: &VerificationKey,
+        claim_digest: &BytesN<32>,
+    ) -> Vec<Fr> {
+        let (a0, a1) = Self::split_digest(env, &vk.control_root);
+        let (c0, c1) = Self::split_digest(env, claim_digest);
+
+        // The control ID is a little-endian encoded field element
+        let mut id = vk.bn254_control_id.to_array();
+        id.reverse();
+        let id = Fr::from_bytes(BytesN::from_array(env, &id));
+
+        vec![env, a0, a1, c0, c1, id]
     }
 
-    #[test]
-    fn test_init_vk_and_query() {
-        let env = Env::default();
-        let (client, _) = deploy_and_init(&env);
-
-        assert_eq!(client.is_initialized(), true);
-        let vk = client.get_vk();
-        assert_eq!(vk.alpha_g1, BytesN::from_array(&env, &[1u8; 64]));
+    /// Split a digest into two 128-bit scalars after reversing its byte order
+    /// (RISC Zero `split_digest`): returns (low half, high half).
+    fn split_digest(env: &Env, digest: &BytesN<32>) -> (Fr, Fr) {
+        let bytes = digest.to_array();
+        let mut low = [0u8; 32];
+        let mut high = [0u8; 32];
+        for i in 0..16 {
+            low[31 - i] = bytes[i];
+            high[31 - i] = bytes[16 + i];
+        }
+        (
+            Fr::from_bytes(BytesN::from_array(env, &low)),
+            Fr::from_bytes(BytesN::from_array(env, &high)),
+        )
     }
 
-    #[test]
-    fn test_query_image_id() {
-        let env = Env::default();
-        let (client, _) = deploy_and_init(&env);
-
-        let image_id = client.get_image_id();
-        assert_eq!(image_id, BytesN::from_array(&env, &[0xABu8; 32]));
-    }
+    /// Groth16 pairing check:
+    /// e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+    fn check_groth16(
+        env: &Env,
+        vk: &VerificationKey,
+        proof: &Groth16Proof,
+        public_inputs: &Vec<Fr>,
+    ) -> bool {
+        if vk.ic.len() != public_inputs.len() + 1 {
+            return false;
+        }
 
-    #[test]
-    #[should_panic(expected = "Error(Contract, #2)")]
-    fn test_init_vk_twice_fails() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let (client, admin) = deploy_and_init(&env);
-
-        // Second init should fail with AlreadyInitialized
-        let vk = create_test_vk(&env);
-        let image_id = BytesN::from_array(&env, &[0xCDu8; 32]);
-        client.init_vk(&admin, &vk, &image_id);
-    }
+        let bn254 = env.crypto().bn254();
 
-    #[test]
-    fn test_verify_valid_proof_dev_mode() {
-        let env = Env::default();
-        let (client, _) = deploy_and_init(&env);
-
-        // Build a proof where sha256(a ++ c) produces a known hash
-        let proof_a_bytes = [0x11u8; 64];
-        let proof_c_bytes = [0x22u8; 64];
-
-        // Compute expected hash: sha256(proof_a ++ proof_c)
-        let mut proof_data = Bytes::new(&env);
-        proof_data.append(&Bytes::from_slice(&env, &proof_a_bytes));
-        proof_data.append(&Bytes::from_slice(&env, &proof_c_bytes));
-        let expected_hash: BytesN<32> = env.crypto().sha256(&proof_data).into();
-
-        let proof = Groth16Proof {
-            a: BytesN::from_array(&env, &proof_a_bytes),
-            b: BytesN::from_array(&env, &[0x33u8; 128]),
-            c: BytesN::from_array(&env, &proof_c_bytes),
-        };
-
-        let result = client.verify(&proof, &expected_hash);
-        assert_eq!(result, true);
-    }
+        // vk_x = ic[0] + sum(input[i] * ic[i + 1])
+        let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get_unchecked(0));
+        for (i, input) in public_inputs.iter().enumerate() {
+            let ic = Bn254G1Affine::from_bytes(vk.ic.get_unchecked(i as u32 + 1));
+            vk_x = bn254.g1_add(&vk_x, &bn254.g1_mul(&ic, &input));
+        }
 
-    #[test]
-    #[should_panic(expected = "Error(Contract, #6)")]
-    fn test_verify_invalid_proof() {
-        let env = Env::default();
-        let (client, _) = deploy_and_init(&env);
-
-        let proof = Groth16Proof {
-            a: BytesN::from_array(&env, &[0x11u8; 64]),
-            b: BytesN::from_array(&env, &[0x33u8; 128]),
-            c: BytesN::from_array(&env, &[0x22u8; 64]),
-        };
-
-        // Wrong journal hash -> should fail
-        let wrong_hash = BytesN::from_array(&env, &[0xFFu8; 32]);
-        client.verify(&proof, &wrong_hash);
+        let neg_a = -Bn254G1Affine::from_bytes(proof.a.clone());
+        let g1 = vec![
+            env,
+            neg_a,
+            Bn254G1Affine::from_bytes(vk.alpha_g1.clone()),
+            vk_x,
+            Bn254G1Affine::from_bytes(proof.c.clone()),
+        ];
+        let g2 = vec![
+            env,
+            Bn254G2Affine::from_bytes(proof.b.clone()),
+            Bn254G2Affine::from_bytes(vk.beta_g2.clone()),
+            Bn254G2Affine::from_bytes(vk.gamma_g2.clone()),
+            Bn254G2Affine::from_bytes(vk.delta_g2.clone()),
+        ];
+        bn254.pairing_check(g1, g2)
     }
+}
 
-    #[test]
-    #[should_panic(expected = "Error(Contract, #1)")]
-    fn test_verify_before_init_fails() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        let contract_id = env.register(Groth16VerifierContract, (admin,));
-        let client = Groth16VerifierContractClient::new(&env, &contract_id);
-
-        let proof = Groth16Proof {
-            a: BytesN::from_array(&env, &[0x11u8; 64]),
-            b: BytesN::from_array(&env, &[0x33u8; 128]),
-            c: BytesN::from_array(&env, &[0x22u8; 64]),
-        };
-        let hash = BytesN::from_array(&env, &[0xFFu8; 32]);
-        client.verify(&proof, &hash);
-    }
+// ============================================================================
+// Tests
+// ============================================================================
 
-    #[test]
-    fn test_update_vk() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let (client, admin) = deploy_and_init(&env);
-
-        let new_vk = VerificationKey {
-            alpha_g1: BytesN::from_array(&env, &[0xAAu8; 64]),
-            beta_g2: BytesN::from_array(&env, &[0xBBu8; 128]),
-            gamma_g2: BytesN::from_array(&env, &[0xCCu8; 128]),
-            delta_g2: BytesN::from_array(&env, &[0xDDu8; 128]),
-            ic_0: BytesN::from_array(&env, &[0xEEu8; 64]),
-            ic_1: BytesN::from_array(&env, &[0xFFu8; 64]),
-        };
-        let new_image_id = BytesN::from_array(&env, &[0xCDu8; 32]);
-
-        client.update_vk(&admin, &new_vk, &new_image_id);
-
-        let stored_vk = client.get_vk();
-        assert_eq!(stored_vk.alpha_g1, BytesN::from_array(&env, &[0xAAu8; 64]));
-
-        let stored_id = client.get_image_id();
-        assert_eq!(stored_id, BytesN::from_array(&env, &[0xCDu8; 32]));
-    }
-}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,425 @@
+#![cfg(test)]
+
+use crate::{
+    Groth16Proof, Groth16VerifierContract, Groth16VerifierContractClient, VerificationKey,
+    VerifierError,
+};
+use soroban_sdk::crypto::bn254::Fr;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Known-Answer Vectors
+// ============================================================================
+//
+// Hex strings are split into 32-byte words. G2 points are encoded c1 || c0.
+
+// RISC Zero 1.2 Groth16 verifying key and verifier parameters
+// (risc0-groth16 `verifying_key()`, risc0-circuit-recursion control IDs).
+const R0_ALPHA: &str = concat!(
+    "2d4d9aa7e302d9df41749d5507949d05dbea33fbb16c643b22f599a2be6df2e2",
+    "14bedd503c37ceb061d8ec60209fe345ce89830a19230301f076caff004d1926",
+);
+const R0_BETA: &str = concat!(
+    "0967032fcbf776d1afc985f88877f182d38480a653f2decaa9794cbc3bf3060c",
+    "0e187847ad4c798374d0d6732bf501847dd68bc0e071241e0213bc7fc13db7ab",
+    "304cfbd1e08a704a99f5e847d93f8c3caafddec46b7a0d379da69a4d112346a7",
+    "1739c1b1a457a8c7313123d24d2f9192f896b7c63eea05a9d57f06547ad0cec8",
+);
+const R0_GAMMA: &str = concat!(
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+);
+const R0_DELTA: &str = concat!(
+    "03b03cd5effa95ac9bee94f1f5ef907157bda4812ccf0b4c91f42bb629f83a1c",
+    "1aa085ff28179a12d922dba0547057ccaae94b9d69cfaa4e60401fea7f3e0333",
+    "110c10134f200b19f6490846d518c9aea868366efb7228ca5c91d2940d030762",
+    "1e60f31fcbf757e837e867178318832d0b2d74d59e2fea1c7142df187d3fc6d3",
+);
+const R0_IC0: &str = concat!(
+    "12ac9a25dcd5e1a832a9061a082c15dd1d61aa9c4d553505739d0f5d65dc3be4",
+    "025aa744581ebe7ad91731911c898569106ff5a2d30f3eee2b23c60ee980acd4",
+);
+const R0_IC1: &str = concat!(
+    "0707b920bc978c02f292fae2036e057be54294114ccc3c8769d883f688a1423f",
+    "2e32a094b7589554f7bc357bf63481acd2d55555c203383782a4650787ff6642",
+);
+const R0_IC2: &str = concat!(
+    "0bca36e2cbe6394b3e249751853f961511011c7148e336f4fd974644850fc347",
+    "2ede7c9acf48cf3a3729fa3d68714e2a8435d4fa6db8f7f409c153b1fcdf9b8b",
+);
+const R0_IC3: &str = concat!(
+    "1b8af999dbfbb3927c091cc2aaf201e488cbacc3e2c6b6fb5a25f9112e04f2a7",
+    "2b91a26aa92e1b6f5722949f192a81c850d586d81a60157f3e9cf04f679cccd6",
+);
+const R0_IC4: &str = concat!(
+    "2b5f494ed674235b8ac1750bdfd5a7615f002d4a1dcefeddd06eda5a076ccd0d",
+    "2fe520ad2020aab9cbba817fcbb9a863b8a76ff88f14f912c5e71665b2ad5e82",
+);
+const R0_IC5: &str = concat!(
+    "0f1c3c0d5d9da0fa03666843cde4e82e869ba5252fce3c25d5940320b1c4d493",
+    "214bfcff74f425f6fe8c0d07b307482d8bc8bb2f3608f68287aa01bd0b69e809",
+);
+const R0_CONTROL_ROOT: &str = "8cdad9242664be3112aba377c5425a4df735eb1c6966472b561d2855932c0469";
+const R0_BN254_CONTROL_ID: &str =
+    "c07a65145c3cb48b6101962ea607a4dd93c753bb26975cb47feb00d3666e4404";
+
+// ReceiptClaim::ok([0xAB; 32], journal = 0x00..=0x48) and its public inputs,
+// computed with risc0-zkvm 1.2 `ReceiptClaim::digest` and risc0-groth16 `split_digest`.
+const KAT_JOURNAL_DIGEST: &str = "169f6f093a9be82febe1a6a4471425697ec25d5040b472c5b1822aeea2625988";
+const KAT_CLAIM_DIGEST: &str = "596e23c279b8cc7919c9cd7d4d14eb41f80f0e333836a1c8ab83b8eaa0a22de8";
+const KAT_CONTROL_ROOT_0: &str = "000000000000000000000000000000004d5a42c577a3ab1231be642624d9da8c";
+const KAT_CONTROL_ROOT_1: &str = "0000000000000000000000000000000069042c9355281d562b4766691ceb35f7";
+const KAT_CLAIM_0: &str = "0000000000000000000000000000000041eb144d7dcdc91979ccb879c2236e59";
+const KAT_CLAIM_1: &str = "00000000000000000000000000000000e82da2a0eab883abc8a13638330e0ff8";
+const KAT_BN254_CONTROL_ID: &str =
+    "04446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0";
+
+// Groth16 proof over BN254 with one public input (33), from the snarkjs
+// fixtures shipped with risc0-groth16 (tests/data/*.json).
+const SNARK_ALPHA: &str = concat!(
+    "02dc744e59b3d00ffd61de8d61ac18db220976c01634b09b7532aade64ed2dd4",
+    "1d7a91192f8b382f0621d2735ff2c97cc0e68d068ac878cb06ac852e868be639",
+);
+const SNARK_BETA: &str = concat!(
+    "270dfe92c202d7d10fb7df5caa491f449d1337ce531d910f952a4990417539fa",
+    "04ce0fb5abe034d3bb5c41ed7d73b21869e82c8f39f62b70bfb0b3eaaefe5353",
+    "0d0f2e77869c1238b563d52d6140d1bd75931e9055550d1578ef3a299dd20429",
+    "21c483230e95028a8ee06c9b2071aee86b59ff40d3bebea770bcd75d9c3b4e38",
+);
+const SNARK_GAMMA: &str = concat!(
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+);
+const SNARK_DELTA: &str = concat!(
+    "126af0a26cab66c4734a5a944c5771a27897a2988551a72971f2ee0ea5c787ab",
+    "27fa3d486f6da42bbfe3ac8c3c1ab8bc5e892aab137f6656947caf062b05456b",
+    "1fae50ae804740bf2349f00eeb7deae32133122692da9265a162e22541ea170a",
+    "2641641291712c42b63633b978f204808a1537890be38acb44e7db7413491be2",
+);
+const SNARK_IC0: &str = concat!(
+    "20e6744a9c23e2f3d8e7a5a789cda64794d0ed5a84a1dc7486c8569f185f884d",
+    "1f4b1bb22bddc4b9d2caeae17a311b499e5f960792067a70034d431f24d02270",
+);
+const SNARK_IC1: &str = concat!(
+    "1bd5f4e4f770eaf1a2a40773e0bc860485a20115b68d5c0ce7f56a90b9a20ba6",
+    "220033576e8d9e8579801f969d67e67f84ef8136bb7a8e2d9d4ccf235323cb16",
+);
+const SNARK_PROOF_A: &str = concat!(
+    "2bab42c4ff2336339b486238247bfb19aa74bd53021df26ace515e663aa4b0e7",
+    "187944728cddc0480b2799d58c522ecdf0337bbd6ad88dd0ed8ecbb5a3e2f2aa",
+);
+const SNARK_PROOF_B: &str = concat!(
+    "1c4f0491e1c9e3e3763894e00de5fdb8516517c42e13ea654e69c8699bedbea7",
+    "178afa48a33939ce9ba903f425faad8dd8c935d2c319d035e426ba9c9f7db45f",
+    "154c98da14795bc57d2b050eada9c97ee5b6bffa0291148c6c12ff3e30a2b1f9",
+    "0588d6ed253ace86b59dc19b05ae61554f7bdc08addb88e09f74a8b6e80afe03",
+);
+const SNARK_PROOF_C: &str = concat!(
+    "0777a5cd0062ff9073d34fbf22a9390146295d8bdab0b267092d305f8a86ca86",
+    "07d32d54eb838df53558a9475d2d4c17d1eb376384ebe272398b0ef6ba41c439",
+);
+const SNARK_PUBLIC_INPUT: &str = "0000000000000000000000000000000000000000000000000000000000000021";
+
+// Seal for ReceiptClaim::ok([0xAB; 32], journal = 0x00..=0x48) from the arkworks
+// stand-in prover (`receipt-prover`, seed 0x4842_4d4c). It has the RISC Zero seal
+// layout but comes from a different Groth16 setup, so the production key must
+// reject it.
+const RCPT_SEAL: &str = concat!(
+    "258769d7c73fce4081ab61d5dde15a89ebf73517ef664a829ff5c8cb27a2684a",
+    "290c531da607334dc010768e328ad8f20f6fcea92d3a90e62bbb43d751a519cb",
+    "19373fe3e99c47632f80f487520bc213c25f56d3681b55abbda6cbbc90f493cd",
+    "1c024d12b60e72faf30d9261a2066ae52b18fec232c5609a8c31df6d32a14ac5",
+    "1eaaeccffd7723469fa10a862031b247a36ed2f707952ec7b90515095c8b0c19",
+    "003f7c139bf39aa6164b54078bbea1f62f8c1be18f3c5bb2fbdb7f3a99b91c7f",
+    "10fbeb450029cdd6931cf5e1816b4d5fab80a67b01c1e7a52aff2a90d6d7d652",
+    "2990516c6e1756e7029923752383b5ea75c6c8a934ad03c427604d457ffcce44",
+);
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn hex<const N: usize>(env: &Env, s: &str) -> BytesN<N> {
+    let s = s.as_bytes();
+    assert_eq!(s.len(), N * 2);
+    let nibble = |c: u8| match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        _ => panic!("invalid hex"),
+    };
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = (nibble(s[2 * i]) << 4) | nibble(s[2 * i + 1]);
+    }
+    BytesN::from_array(env, &out)
+}
+
+fn fr(env: &Env, s: &str) -> Fr {
+    Fr::from_bytes(hex(env, s))
+}
+
+fn risc0_vk(env: &Env) -> VerificationKey {
+    VerificationKey {
+        alpha_g1: hex(env, R0_ALPHA),
+        beta_g2: hex(env, R0_BETA),
+        gamma_g2: hex(env, R0_GAMMA),
+        delta_g2: hex(env, R0_DELTA),
+        ic: vec![
+            env,
+            hex(env, R0_IC0),
+            hex(env, R0_IC1),
+            hex(env, R0_IC2),
+            hex(env, R0_IC3),
+            hex(env, R0_IC4),
+            hex(env, R0_IC5),
+        ],
+        control_root: hex(env, R0_CONTROL_ROOT),
+        bn254_control_id: hex(env, R0_BN254_CONTROL_ID),
+    }
+}
+
+fn snark_vk(env: &Env) -> VerificationKey {
+    VerificationKey {
+        alpha_g1: hex(env, SNARK_ALPHA),
+        beta_g2: hex(env, SNARK_BETA),
+        gamma_g2: hex(env, SNARK_GAMMA),
+        delta_g2: hex(env, SNARK_DELTA),
+        ic: vec![env, hex(env, SNARK_IC0), hex(env, SNARK_IC1)],
+        control_root: BytesN::from_array(env, &[0u8; 32]),
+        bn254_control_id: BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+fn snark_proof(env: &Env) -> Groth16Proof {
+    Groth16Proof {
+        a: hex(env, SNARK_PROOF_A),
+        b: hex(env, SNARK_PROOF_B),
+        c: hex(env, SNARK_PROOF_C),
+    }
+}
+
+fn receipt_journal(env: &Env) -> Bytes {
+    Bytes::from_array(env, &core::array::from_fn::<u8, 73, _>(|i| i as u8))
+}
+
+fn receipt_proof(env: &Env) -> Groth16Proof {
+    let seal = hex::<256>(env, RCPT_SEAL).to_array();
+    Groth16Proof {
+        a: BytesN::from_array(env, seal[0..64].try_into().unwrap()),
+        b: BytesN::from_array(env, seal[64..192].try_into().unwrap()),
+        c: BytesN::from_array(env, seal[192..256].try_into().unwrap()),
+    }
+}
+
+fn deploy(env: &Env) -> (Groth16VerifierContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    let contract_id = env.register(Groth16VerifierContract, (admin.clone(),));
+    (Groth16VerifierContractClient::new(env, &contract_id), admin)
+}
+
+fn deploy_and_init(env: &Env) -> (Groth16VerifierContractClient<'_>, Address) {
+    let (client, admin) = deploy(env);
+    client.init_vk(&admin, &risc0_vk(env));
+    (client, admin)
+}
+
+// ============================================================================
+// Admin / Storage Tests
+// ============================================================================
+
+#[test]
+fn test_deploy_not_initialized() {
+    let env = Env::default();
+    let (client, _) = deploy(&env);
+
+    assert!(!client.is_initialized());
+}
+
+#[test]
+fn test_init_vk_and_query() {
+    let env = Env::default();
+    let (client, _) = deploy_and_init(&env);
+
+    assert!(client.is_initialized());
+    let vk = client.get_vk();
+    assert_eq!(vk.alpha_g1, hex(&env, R0_ALPHA));
+    assert_eq!(vk.ic.len(), 6);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_init_vk_twice_fails() {
+    let env = Env::default();
+    let (client, admin) = deploy_and_init(&env);
+
+    // Second init should fail with AlreadyInitialized
+    client.init_vk(&admin, &risc0_vk(&env));
+}
+
+#[test]
+fn test_update_vk() {
+    let env = Env::default();
+    let (client, admin) = deploy_and_init(&env);
+
+    client.update_vk(&admin, &snark_vk(&env));
+
+    let stored_vk = client.get_vk();
+    assert_eq!(stored_vk.alpha_g1, hex(&env, SNARK_ALPHA));
+    assert_eq!(stored_vk.ic.len(), 2);
+}
+
+// ============================================================================
+// Receipt Claim Known-Answer Tests
+// ============================================================================
+
+#[test]
+fn test_receipt_claim_digest_known_answer() {
+    let env = Env::default();
+    let journal = Bytes::from_array(&env, &core::array::from_fn::<u8, 73, _>(|i| i as u8));
+    let journal_digest: BytesN<32> = env.crypto().sha256(&journal).into();
+    assert_eq!(journal_digest, hex(&env, KAT_JOURNAL_DIGEST));
+
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let digest = Groth16VerifierContract::receipt_claim_digest(&env, &image_id, &journal_digest);
+    assert_eq!(digest, hex(&env, KAT_CLAIM_DIGEST));
+}
+
+#[test]
+fn test_receipt_public_inputs_known_answer() {
+    let env = Env::default();
+    let vk = risc0_vk(&env);
+    let inputs =
+        Groth16VerifierContract::receipt_public_inputs(&env, &vk, &hex(&env, KAT_CLAIM_DIGEST));
+
+    let expected: Vec<Fr> = vec![
+        &env,
+        fr(&env, KAT_CONTROL_ROOT_0),
+        fr(&env, KAT_CONTROL_ROOT_1),
+        fr(&env, KAT_CLAIM_0),
+        fr(&env, KAT_CLAIM_1),
+        fr(&env, KAT_BN254_CONTROL_ID),
+    ];
+    assert_eq!(inputs, expected);
+}
+
+// ============================================================================
+// Pairing Check Tests
+// ============================================================================
+
+#[test]
+fn test_pairing_check_known_answer() {
+    let env = Env::default();
+    let inputs = vec![&env, fr(&env, SNARK_PUBLIC_INPUT)];
+    assert!(Groth16VerifierContract::check_groth16(
+        &env,
+        &snark_vk(&env),
+        &snark_proof(&env),
+        &inputs
+    ));
+}
+
+#[test]
+fn test_pairing_check_rejects_wrong_input() {
+    let env = Env::default();
+    let mut wrong = [0u8; 32];
+    wrong[31] = 34;
+    let inputs = vec![&env, Fr::from_bytes(BytesN::from_array(&env, &wrong))];
+    assert!(!Groth16VerifierContract::check_groth16(
+        &env,
+        &snark_vk(&env),
+        &snark_proof(&env),
+        &inputs
+    ));
+}
+
+#[test]
+fn test_pairing_check_rejects_swapped_points() {
+    let env = Env::default();
+    let mut proof = snark_proof(&env);
+    core::mem::swap(&mut proof.a, &mut proof.c);
+    let inputs = vec![&env, fr(&env, SNARK_PUBLIC_INPUT)];
+    assert!(!Groth16VerifierContract::check_groth16(
+        &env,
+        &snark_vk(&env),
+        &proof,
+        &inputs
+    ));
+}
+
+// ============================================================================
+// verify() Tests
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_verify_rejects_proof_for_other_circuit() {
+    let env = Env::default();
+    let (client, _) = deploy_and_init(&env);
+
+    // A valid Groth16 proof, but not for the RISC Zero circuit / this claim
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let journal_hash = hex(&env, KAT_JOURNAL_DIGEST);
+    client.verify(&snark_proof(&env), &image_id, &journal_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_verify_wrong_image_id() {
+    let env = Env::default();
+    let (client, _) = deploy_and_init(&env);
+
+    // Proof from a different circuit -> should fail
+    let other_image_id = BytesN::from_array(&env, &[0xCDu8; 32]);
+    let journal_hash = hex(&env, KAT_JOURNAL_DIGEST);
+    client.verify(&snark_proof(&env), &other_image_id, &journal_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_verify_rejects_vk_with_wrong_input_count() {
+    let env = Env::default();
+    let (client, admin) = deploy(&env);
+
+    // The snarkjs key has a single public input, not RISC Zero's five
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    client.init_vk(&admin, &snark_vk(&env));
+    client.verify(
+        &snark_proof(&env),
+        &image_id,
+        &hex(&env, KAT_JOURNAL_DIGEST),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_verify_before_init_fails() {
+    let env = Env::default();
+    let (client, _) = deploy(&env);
+
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let hash = BytesN::from_array(&env, &[0xFFu8; 32]);
+    client.verify(&snark_proof(&env), &image_id, &hash);
+}
+
+// ============================================================================
+// Receipt Tests
+// ============================================================================
+
+#[test]
+fn test_verify_rejects_receipt_from_other_setup() {
+    let env = Env::default();
+    let (client, _) = deploy_and_init(&env);
+
+    // Well-formed seal for a matching claim, but not proven under the RISC Zero key
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&receipt_journal(&env)).into();
+    assert_eq!(journal_hash, hex(&env, KAT_JOURNAL_DIGEST));
+    let result = client.try_verify(&receipt_proof(&env), &image_id, &journal_hash);
+    assert_eq!(result, Err(Ok(VerifierError::PairingCheckFailed)));
+}
@@ -3,15 +3,18 @@
 //! # Herbal Moonlight Game
 //!
 //! An asymmetric two-player strategy game where the Gardener defends with hidden plants
-//! and the Creature must navigate a 5x5 grid to reach the house.
+//! and the Creature must navigate the grid (5x5 by default) to reach the house.
+//!
+//! Tunable rules (board size, HP, moon chances and modifiers, sense cost, plant limit) come from
+//! a `RuleSet` registered by the admin and chosen per session in `start_game`.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games through the Game Hub contract.
 //! Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype,
-    vec, Address, Bytes, BytesN, Env, IntoVal, Vec,
+    address_payload::AddressPayload, contract, contractclient, contracterror, contractevent,
+    contractimpl, contracttype, token, vec, Address, Bytes, BytesN, Env, IntoVal, Map, Vec,
 };
 
 // ============================================================================
@@ -33,6 +36,20 @@ pub trait GameHub {
     fn end_game(env: Env, session_id: u32, player1_won: bool);
 }
 
+// ============================================================================
+// Groth16 Verifier Client Interface
+// ============================================================================
+
+#[contractclient(name = "Groth16VerifierClient")]
+pub trait Groth16Verifier {
+    fn verify(
+        env: Env,
+        proof: Groth16Proof,
+        image_id: BytesN<32>,
+        journal_hash: BytesN<32>,
+    ) -> bool;
+}
+
 // ============================================================================
 // Enums
 // ============================================================================
@@ -44,6 +61,16 @@ pub enum GamePhase {
     WaitingForProof = 1,
     Playing = 2,
     Finished = 3,
+    WaitingForSenseProof = 4,
+    /// Aborted by both players with no result
+    Cancelled = 5,
+}
+
+impl GamePhase {
+    /// Whether the session has ended, with or without a result
+    fn is_over(&self) -> bool {
+        matches!(self, GamePhase::Finished | GamePhase::Cancelled)
+    }
 }
 
 #[contracttype]
@@ -54,10 +81,132 @@ pub enum MoonPhase {
     Balanced = 2,
 }
 
+impl From<&MoonPhase> for herbal_rules::MoonPhase {
+    fn from(moon_phase: &MoonPhase) -> Self {
+        match moon_phase {
+            MoonPhase::FullMoon => herbal_rules::MoonPhase::FullMoon,
+            MoonPhase::NewMoon => herbal_rules::MoonPhase::NewMoon,
+            MoonPhase::Balanced => herbal_rules::MoonPhase::Balanced,
+        }
+    }
+}
+
+impl From<herbal_rules::MoonPhase> for MoonPhase {
+    fn from(moon_phase: herbal_rules::MoonPhase) -> Self {
+        match moon_phase {
+            herbal_rules::MoonPhase::FullMoon => MoonPhase::FullMoon,
+            herbal_rules::MoonPhase::NewMoon => MoonPhase::NewMoon,
+            herbal_rules::MoonPhase::Balanced => MoonPhase::Balanced,
+        }
+    }
+}
+
+/// Spirit Sense abilities the Creature can spend HP on
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SenseKind {
+    /// Whether the cells left and right of the Creature hold a plant
+    PeekAdjacent = 0,
+    /// How many plants are in the next two rows
+    SmellAhead = 1,
+}
+
+/// Creature types, chosen by the Creature in `start_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CreatureKind {
+    /// Standard HP; 1 row forward per turn, at most 1 column sideways
+    Ghost = 0,
+    /// Fragile; may Flutter 2 rows straight ahead, revealing every traversed cell
+    MoonMoth = 1,
+    /// Extra HP; 1 row forward per turn, never sideways once on the board
+    ShadowWolf = 2,
+}
+
+/// HP and movement rules live in `herbal_rules::CreatureKind`, shared with
+/// the no-path circuit
+impl From<CreatureKind> for herbal_rules::CreatureKind {
+    fn from(kind: CreatureKind) -> Self {
+        match kind {
+            CreatureKind::Ghost => herbal_rules::CreatureKind::Ghost,
+            CreatureKind::MoonMoth => herbal_rules::CreatureKind::MoonMoth,
+            CreatureKind::ShadowWolf => herbal_rules::CreatureKind::ShadowWolf,
+        }
+    }
+}
+
+impl CreatureKind {
+    /// Starting HP before the Full Moon bonus
+    fn starting_hp(&self, rules: &RuleSet) -> u32 {
+        herbal_rules::CreatureKind::from(*self).starting_hp(&rules.into())
+    }
+
+    /// Whether a move advancing `rows` rows and shifting `columns` columns is
+    /// allowed. Entering the board (from row 0) may pick any column.
+    fn can_move(&self, rows: u32, columns: u32, entering: bool) -> bool {
+        herbal_rules::CreatureKind::from(*self).can_move(rows, columns, entering)
+    }
+}
+
 // ============================================================================
 // Data Structures
 // ============================================================================
 
+/// Tunable rules of a session. Mirrors `herbal_rules::RuleSet` field for
+/// field; its canonical encoding is hashed into `GameSession::rule_set_hash`
+/// and must match the hash committed by every proof.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuleSet {
+    pub board_width: u32,
+    pub board_height: u32,
+    pub creature_starting_hp: u32,
+    pub full_moon_bonus_hp: u32,
+    pub max_plants: u32,
+    pub full_moon_chance: u32,
+    pub new_moon_chance: u32,
+    pub full_moon_damage_reduction: u32,
+    pub new_moon_damage_bonus: u32,
+    pub sense_hp_cost: u32,
+    pub moon_cycle_turns: u32,
+}
+
+impl From<herbal_rules::RuleSet> for RuleSet {
+    fn from(rules: herbal_rules::RuleSet) -> Self {
+        RuleSet {
+            board_width: rules.board_width,
+            board_height: rules.board_height,
+            creature_starting_hp: rules.creature_starting_hp,
+            full_moon_bonus_hp: rules.full_moon_bonus_hp,
+            max_plants: rules.max_plants,
+            full_moon_chance: rules.full_moon_chance,
+            new_moon_chance: rules.new_moon_chance,
+            full_moon_damage_reduction: rules.full_moon_damage_reduction,
+            new_moon_damage_bonus: rules.new_moon_damage_bonus,
+            sense_hp_cost: rules.sense_hp_cost,
+            moon_cycle_turns: rules.moon_cycle_turns,
+        }
+    }
+}
+
+impl From<&RuleSet> for herbal_rules::RuleSet {
+    fn from(rules: &RuleSet) -> Self {
+        herbal_rules::RuleSet {
+            board_width: rules.board_width,
+            board_height: rules.board_height,
+            creature_starting_hp: rules.creature_starting_hp,
+            full_moon_bonus_hp: rules.full_moon_bonus_hp,
+            max_plants: rules.max_plants,
+            full_moon_chance: rules.full_moon_chance,
+            new_moon_chance: rules.new_moon_chance,
+            full_moon_damage_reduction: rules.full_moon_damage_reduction,
+            new_moon_damage_bonus: rules.new_moon_damage_bonus,
+            sense_hp_cost: rules.sense_hp_cost,
+            moon_cycle_turns: rules.moon_cycle_turns,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameSession {
@@ -67,14 +216,58 @@ pub struct GameSession {
     pub gardener_points: i128,
     pub creature_points: i128,
     pub garden_commitment: BytesN<32>,
+    /// Whether the commitment came with a proof that it opens to a valid garden
+    pub garden_proven: bool,
+    pub creature_kind: CreatureKind,
     pub creature_x: u32,
     pub creature_y: u32,
     pub creature_hp: u32,
+    /// Cells of the last move still to be revealed, ending at the Creature's
+    /// position (2 after a Moon Moth Flutter, otherwise 1)
+    pub pending_reveals: u32,
     pub phase: GamePhase,
+    /// Moon phase of the current turn
     pub moon_phase: MoonPhase,
+    /// Whether the moon phase is final or still being drawn by commit-reveal
+    pub moon: MoonState,
+    /// Turns into the lunar cycle, advanced by every move; 0 unless the rule
+    /// set's moon cycles
+    pub moon_clock: u32,
     pub revealed_cells: Vec<u32>,
+    /// Cells whose plant has triggered; a consumed plant never damages again
+    pub consumed_plants: Vec<u32>,
     pub turn_number: u32,
     pub damage_reduction: u32,
+    /// Total damage the Gardener's plants have dealt this game
+    pub damage_dealt: u32,
+    /// Ledger sequence of the last state transition (start of the current phase)
+    pub last_action_ledger: u32,
+    /// Spirit Sense awaiting a proof, or the most recent answer
+    pub sense: SenseState,
+    /// Rule set the session was started with (copied, so later registrations cannot change it)
+    pub rule_set_id: u32,
+    pub rules: RuleSet,
+    /// sha256 of the canonical rule set encoding, committed by every proof
+    pub rule_set_hash: BytesN<32>,
+    /// Match this session is a round of, if any
+    pub match_id: Option<u32>,
+    /// Set when the game finishes
+    pub winner: Option<Address>,
+    /// Token both players staked with `start_wager_game`, held until the game ends
+    pub wager_token: Option<Address>,
+    /// Stake of each player (0 without a wager)
+    pub wager_amount: i128,
+    /// Cut of the pot paid to the treasury, in basis points (fixed at game start)
+    pub wager_fee_bps: u32,
+    /// Treasury the fee is paid to (fixed at game start; `None` without a fee)
+    pub wager_treasury: Option<Address>,
+    /// Player who proposed cancelling the game, if the proposal is open;
+    /// every other state-changing call withdraws it
+    pub cancel_proposed_by: Option<Address>,
+    /// Cell index (y * board_width + x) the Creature moved to on each turn
+    pub path: Vec<u32>,
+    /// Every revealed cell, in reveal order
+    pub reveals: Vec<CellRevealResult>,
 }
 
 #[contracttype]
@@ -87,6 +280,202 @@ pub struct CellRevealResult {
     pub damage_dealt: u32,
 }
 
+/// Compact summary of an ended session, kept in persistent storage after the
+/// session itself expires so past games stay auditable
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRecord {
+    pub session_id: u32,
+    pub gardener: Address,
+    pub creature: Address,
+    pub creature_kind: CreatureKind,
+    pub rule_set_id: u32,
+    /// Moon phase of the first turn
+    pub moon_phase: MoonPhase,
+    /// Cell index (y * board_width + x) the Creature moved to on each turn
+    pub path: Vec<u32>,
+    /// Every revealed cell, in reveal order
+    pub reveals: Vec<CellRevealResult>,
+    /// `None` if the game was cancelled
+    pub winner: Option<Address>,
+    pub turn_count: u32,
+    /// Ledger sequence the game ended in
+    pub ended_ledger: u32,
+    /// Cells the Gardener chose to disclose after the game (cell index -> plant type)
+    pub disclosed_cells: Map<u32, u32>,
+}
+
+/// Answer to a Spirit Sense. Only the fields of `kind` are meaningful:
+/// `left_has_plant`/`right_has_plant` for PeekAdjacent, `plants_ahead` for SmellAhead.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenseResult {
+    pub kind: SenseKind,
+    pub x: u32,
+    pub y: u32,
+    pub left_has_plant: bool,
+    pub right_has_plant: bool,
+    pub plants_ahead: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SenseState {
+    None,
+    /// Requested by the Creature, awaiting the Gardener's proof
+    Pending(SenseKind),
+    /// Most recently answered sense
+    Answered(SenseResult),
+}
+
+/// Two-party moon draw: each player committed to sha256 of a secret seed
+/// (the Creature in `start_game`, the Gardener in `commit_garden`), and the
+/// moon is drawn once both seeds are revealed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoonDraw {
+    pub gardener_seed_hash: BytesN<32>,
+    pub creature_seed_hash: BytesN<32>,
+    pub gardener_seed: Option<BytesN<32>>,
+    pub creature_seed: Option<BytesN<32>>,
+    /// After this ledger `settle_moon` draws without the missing seed
+    pub deadline_ledger: u32,
+}
+
+/// While the moon is not `Fixed`, `moon_phase` is a Balanced placeholder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum MoonState {
+    /// `moon_phase` is final
+    Fixed,
+    /// The Creature committed a seed hash; the Gardener commits theirs with the garden
+    AwaitingGardenerSeed(BytesN<32>),
+    /// Waiting for the players to reveal their seeds
+    Drawing(MoonDraw),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PlayerRole {
+    Gardener = 0,
+    Creature = 1,
+}
+
+/// Lifetime record of a player, kept in persistent storage and updated
+/// whenever one of their games finishes. Each role has its own Elo rating.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub gardener_games: u32,
+    pub gardener_wins: u32,
+    pub gardener_losses: u32,
+    pub creature_games: u32,
+    pub creature_wins: u32,
+    pub creature_losses: u32,
+    /// Total damage the player's plants dealt as Gardener
+    pub damage_dealt: u32,
+    /// Sum of the turn counts of all finished games (average = total_turns / games)
+    pub total_turns: u32,
+    pub gardener_rating: u32,
+    pub creature_rating: u32,
+}
+
+/// Open challenge in the lobby: the creator's signed consent to play one
+/// side of a game, waiting for any opponent to accept the other side
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub challenge_id: u32,
+    pub creator: Address,
+    /// Role the creator plays; the accepting player takes the other one
+    pub role: PlayerRole,
+    /// Points each player commits
+    pub points: i128,
+    pub rule_set_id: u32,
+    pub creature_kind: CreatureKind,
+    /// Last ledger on which the challenge can be accepted
+    pub expires_ledger: u32,
+}
+
+/// Best-of-N series between two players who swap roles every round.
+/// The match, not its rounds, is the Game Hub session: points are locked
+/// once and the hub only hears the match result.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    /// Also the match's Game Hub session id (allocated like a session id)
+    pub match_id: u32,
+    /// Gardener in odd rounds, Creature in even rounds
+    pub player1: Address,
+    pub player2: Address,
+    /// Points each player commits
+    pub points: i128,
+    /// Number of rounds at most (odd); the first to win a majority takes the match
+    pub best_of: u32,
+    pub rule_set_id: u32,
+    /// Kind played by the Creature in every round
+    pub creature_kind: CreatureKind,
+    pub player1_wins: u32,
+    pub player2_wins: u32,
+    /// Current round, from 1
+    pub round: u32,
+    /// Session of the current (or, once finished, the last) round
+    pub session_id: u32,
+    pub finished: bool,
+}
+
+/// Token stake each player deposits in `start_wager_game`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Wager {
+    /// Stellar Asset Contract (or any SEP-41 token) of the stake
+    pub token: Address,
+    /// Amount each player deposits
+    pub amount: i128,
+}
+
+/// Where wager fees go and how large they are
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WagerFee {
+    pub treasury: Address,
+    /// Basis points of the pot (both stakes)
+    pub fee_bps: u32,
+}
+
+/// Proof from the `garden-validity` circuit that a commitment opens to a
+/// garden that is valid under the session's rule set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentProof {
+    pub journal_bytes: Bytes,
+    pub journal_hash: BytesN<32>,
+    pub seal: Bytes,
+}
+
+/// What the Gardener discloses about a finished game's garden
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Disclosure {
+    /// The full commitment preimage: one byte per cell (row-major) and the
+    /// salt; the board dimensions come from the session's rule set
+    Garden(Bytes, BytesN<16>),
+    /// A batch reveal proof of chosen cells: journal bytes, journal hash and seal
+    Cells(Bytes, BytesN<32>, Bytes),
+}
+
+/// Groth16 proof points as expected by the verifier contract.
+/// Decoded from the 256-byte RiscZero seal: [a:64][b:128][c:64]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Groth16Proof {
+    pub a: BytesN<64>,
+    pub b: BytesN<128>,
+    pub c: BytesN<64>,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -94,7 +483,209 @@ pub enum DataKey {
     GameHubAddress,
     VerifierId,
     ImageId,
+    SenseImageId(SenseKind),
+    NoPathImageId,
+    BatchRevealImageId,
+    CommitmentImageId,
+    /// Whether empty seals are accepted (admin-set, off by default)
+    DevMode,
+    RuleSet(u32),
     Session(u32),
+    PlayerStats(Address),
+    NextSessionId,
+    NextChallengeId,
+    Challenge(u32),
+    Match(u32),
+    WagerFee,
+    GameRecord(u32),
+    /// Number of archived games of a player
+    PlayerGameCount(Address),
+    /// Session id of a player's n-th archived game (0-based, oldest first)
+    PlayerGame(Address, u32),
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionCreated {
+    #[topic]
+    pub session_id: u32,
+    pub gardener: Address,
+    pub creature: Address,
+    pub creature_kind: CreatureKind,
+    pub rule_set_id: u32,
+    pub moon_phase: MoonPhase,
+    pub creature_hp: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoonFixed {
+    #[topic]
+    pub session_id: u32,
+    pub moon_phase: MoonPhase,
+    pub creature_hp: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GardenCommitted {
+    #[topic]
+    pub session_id: u32,
+    pub garden_commitment: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatureMoved {
+    #[topic]
+    pub session_id: u32,
+    pub x: u32,
+    pub y: u32,
+    pub turn_number: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CellRevealed {
+    #[topic]
+    pub session_id: u32,
+    pub x: u32,
+    pub y: u32,
+    pub has_plant: bool,
+    pub plant_type: u32,
+    pub damage_dealt: u32,
+    pub creature_hp: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenseRequested {
+    #[topic]
+    pub session_id: u32,
+    pub kind: SenseKind,
+    pub creature_hp: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenseRevealed {
+    #[topic]
+    pub session_id: u32,
+    pub result: SenseResult,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GardenDisclosed {
+    #[topic]
+    pub session_id: u32,
+    pub disclosed_cells: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeCreated {
+    #[topic]
+    pub challenge_id: u32,
+    pub creator: Address,
+    pub role: PlayerRole,
+    pub points: i128,
+    pub rule_set_id: u32,
+    pub creature_kind: CreatureKind,
+    pub expires_ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeAccepted {
+    #[topic]
+    pub challenge_id: u32,
+    pub opponent: Address,
+    pub session_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeCancelled {
+    #[topic]
+    pub challenge_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameFinished {
+    #[topic]
+    pub session_id: u32,
+    pub winner: Address,
+    pub gardener_won: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WagerPaid {
+    #[topic]
+    pub session_id: u32,
+    pub winner: Address,
+    pub payout: i128,
+    pub fee: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerResigned {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelProposed {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameCancelled {
+    #[topic]
+    pub session_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchStarted {
+    #[topic]
+    pub match_id: u32,
+    pub player1: Address,
+    pub player2: Address,
+    pub best_of: u32,
+    pub rule_set_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchRoundStarted {
+    #[topic]
+    pub match_id: u32,
+    pub round: u32,
+    pub session_id: u32,
+    pub gardener: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchFinished {
+    #[topic]
+    pub match_id: u32,
+    pub winner: Address,
+    pub player1_wins: u32,
+    pub player2_wins: u32,
 }
 
 // ============================================================================
@@ -117,16 +708,60 @@ pub enum Error {
     InvalidCoordinates = 10,
     GameAlreadyFinished = 11,
     SelfPlayNotAllowed = 12,
+    SessionMismatch = 13,
+    GardenerMismatch = 14,
+    TimeoutNotReached = 15,
+    SenseNotAllowed = 16,
+    InsufficientHp = 17,
+    SenseKindMismatch = 18,
+    RuleSetNotFound = 19,
+    InvalidRuleSet = 20,
+    RuleSetMismatch = 21,
+    CreatureStateMismatch = 22,
+    ChallengeNotFound = 23,
+    ChallengeExpired = 24,
+    MoonNotFixed = 25,
+    MoonSeedMismatch = 26,
+    MoonSeedAlreadyRevealed = 27,
+    NotAPlayer = 28,
+    MatchNotFound = 29,
+    InvalidMatchLength = 30,
+    InvalidWager = 31,
+    InvalidWagerFee = 32,
+    NoCancelProposal = 33,
+    GameRecordNotFound = 34,
 }
 
 // ============================================================================
 // Constants
 // ============================================================================
 
-const GRID_SIZE: u32 = 5;
-const CREATURE_STARTING_HP: u32 = 6;
-const JOURNAL_LEN: u32 = 73;
+const JOURNAL_LEN: u32 = 105;
+/// No Path journal: one moon clock byte longer than a cell reveal journal
+const NO_PATH_JOURNAL_LEN: u32 = JOURNAL_LEN + 1;
+/// Garden validity journal: [commitment:32] followed by the session binding
+const COMMITMENT_JOURNAL_LEN: u32 = 32 + BINDING_LEN;
+/// Trailing session binding of every journal: [session_id:4][gardener_pubkey:32][rule_set_hash:32]
+const BINDING_LEN: u32 = 4 + 32 + 32;
+/// Bytes per cell in a batch reveal journal: [x][y][has_plant][plant_type][damage]
+const BATCH_CELL_LEN: u32 = 5;
+const DEFAULT_RULE_SET_ID: u32 = 0;
+const SEAL_LEN: u32 = 256;
 const GAME_TTL_LEDGERS: u32 = 518_400; // 30 days
+const TURN_TIMEOUT_LEDGERS: u32 = 720; // ~1 hour per phase
+const STATS_TTL_LEDGERS: u32 = 6_307_200; // ~1 year
+const CHALLENGE_EXPIRY_LEDGERS: u32 = 17_280; // ~1 day
+const MAX_MATCH_ROUNDS: u32 = 9;
+const MAX_WAGER_FEE_BPS: u32 = 1_000; // 10% of the pot
+const INITIAL_RATING: u32 = 1200;
+const MAX_HISTORY_PAGE: u32 = 20;
+const MAX_CHALLENGE_PAGE: u32 = 20;
+const ELO_K: u32 = 32;
+/// Elo expected score (per mille) of the higher-rated player for rating gaps of
+/// 0, 50, 100, ... 800: 1000 / (1 + 10^(-gap / 400))
+const ELO_EXPECTED: [u32; 17] = [
+    500, 571, 640, 703, 760, 808, 849, 882, 909, 930, 947, 960, 969, 977, 983, 987, 990,
+];
 
 // ============================================================================
 // Contract Definition
@@ -144,6 +779,8 @@ impl HerbalMoonlight {
     /// * `game_hub` - Address of the GameHub contract
     /// * `verifier_id` - Address of the Groth16 verifier contract
     /// * `image_id` - Image ID of the RiscZero circuit (32 bytes)
+    ///
+    /// The standard rules are registered as rule set 0.
     pub fn __constructor(
         env: Env,
         admin: Address,
@@ -156,6 +793,10 @@ impl HerbalMoonlight {
         storage.set(&DataKey::GameHubAddress, &game_hub);
         storage.set(&DataKey::VerifierId, &verifier_id);
         storage.set(&DataKey::ImageId, &image_id);
+        storage.set(
+            &DataKey::RuleSet(DEFAULT_RULE_SET_ID),
+            &RuleSet::from(herbal_rules::DEFAULT_RULES),
+        );
     }
 
     /// Start a new game between Gardener and Creature
@@ -163,112 +804,200 @@ impl HerbalMoonlight {
     /// **CRITICAL:** This method requires authorization from THIS contract.
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
+    /// The session id is allocated by the contract. The moon phase is only
+    /// drawn when the players opt into the commit-reveal draw; otherwise the
+    /// game starts under the rule set's base moon, which neither player can
+    /// steer.
+    ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier
     /// * `gardener` - Address of the Gardener player
     /// * `creature` - Address of the Creature player
     /// * `gardener_points` - Points amount committed by Gardener
     /// * `creature_points` - Points amount committed by Creature
+    /// * `rule_set_id` - Registered rule set to play under (0 = standard rules)
+    /// * `creature_kind` - Creature type, chosen (and authorized) by the Creature
+    /// * `creature_seed_hash` - Optional sha256 of the Creature's secret moon
+    ///   seed. With it the moon is drawn by commit-reveal (see
+    ///   `reveal_moon_seed`); without, the base moon applies.
+    ///
+    /// # Returns
+    /// * The session id of the new game
+    #[allow(clippy::too_many_arguments)]
     pub fn start_game(
         env: Env,
-        session_id: u32,
         gardener: Address,
         creature: Address,
         gardener_points: i128,
         creature_points: i128,
-    ) -> Result<(), Error> {
+        rule_set_id: u32,
+        creature_kind: CreatureKind,
+        creature_seed_hash: Option<BytesN<32>>,
+    ) -> Result<u32, Error> {
         // Prevent self-play
         if gardener == creature {
             return Err(Error::SelfPlayNotAllowed);
         }
 
-        // SECURITY: Prevent session ID collision - check if session already exists
-        let game_key = DataKey::Session(session_id);
-        if env.storage().temporary().has(&game_key) {
-            return Err(Error::AlreadyInitialized);
-        }
+        let rules: RuleSet = env
+            .storage()
+            .instance()
+            .get(&DataKey::RuleSet(rule_set_id))
+            .ok_or(Error::RuleSetNotFound)?;
 
-        // Require authentication from both players (they consent to committing points)
+        // Require authentication from both players
+        // (they consent to committing points under these rules)
         gardener.require_auth_for_args(vec![
             &env,
-            session_id.into_val(&env),
             gardener_points.into_val(&env),
+            rule_set_id.into_val(&env),
         ]);
         creature.require_auth_for_args(vec![
             &env,
-            session_id.into_val(&env),
             creature_points.into_val(&env),
+            rule_set_id.into_val(&env),
+            creature_kind.into_val(&env),
+            creature_seed_hash.into_val(&env),
         ]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .ok_or(Error::NotInitialized)?;
-
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Call Game Hub to start the session and lock points
-        // CRITICAL: Call Game Hub BEFORE creating the session
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &gardener,
-            &creature,
-            &gardener_points,
-            &creature_points,
-        );
-
-        // Determine moon phase (deterministic based on session_id)
-        let moon_phase = Self::determine_moon_phase(&env, session_id);
-
-        // Calculate starting HP based on moon phase
-        let creature_hp = match moon_phase {
-            MoonPhase::FullMoon => CREATURE_STARTING_HP + 2,
-            _ => CREATURE_STARTING_HP,
+        let moon = match creature_seed_hash {
+            Some(hash) => MoonState::AwaitingGardenerSeed(hash),
+            None => MoonState::Fixed,
         };
 
-        // Create game session
-        let session = GameSession {
+        let session_id = Self::allocate_session_id(&env);
+        Self::create_session(
+            &env,
             session_id,
-            gardener: gardener.clone(),
-            creature: creature.clone(),
+            gardener,
+            creature,
             gardener_points,
             creature_points,
-            garden_commitment: BytesN::from_array(&env, &[0u8; 32]),
-            creature_x: 2,  // Center of top row
-            creature_y: 0,  // Starting position (outside board)
-            creature_hp,
-            phase: GamePhase::WaitingForCommitment,
-            moon_phase,
-            revealed_cells: Vec::new(&env),
-            turn_number: 0,
-            damage_reduction: 0,
-        };
-
-        // Store game in temporary storage with 30-day TTL
-        env.storage().temporary().set(&game_key, &session);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
-
-        // Event emitted by the Game Hub contract (GameStarted)
-
-        Ok(())
+            rule_set_id,
+            rules,
+            creature_kind,
+            moon,
+            None,
+            None,
+        )?;
+
+        Ok(session_id)
     }
 
-    /// Gardener submits the garden commitment hash
+    /// Start a game in which both players stake a token wager
+    ///
+    /// Works like `start_game`, except that besides its Game Hub `points`
+    /// each player deposits `wager.amount` of `wager.token`, held by this
+    /// contract. When the game finishes by any path the winner receives both
+    /// deposits minus the wager fee (see `set_wager_fee`; the fee and treasury
+    /// in effect now apply); `cancel_game` refunds them. A wager whose pot or
+    /// fee would overflow returns `InvalidWager`.
+    ///
+    /// # Arguments
+    /// * `gardener` - Address of the Gardener player
+    /// * `creature` - Address of the Creature player
+    /// * `points` - Game Hub points each player commits
+    /// * `wager` - Token and amount each player deposits
+    /// * `rule_set_id` - Registered rule set to play under (0 = standard rules)
+    /// * `creature_kind` - Creature type, chosen (and authorized) by the Creature
+    /// * `creature_seed_hash` - Optional sha256 of the Creature's secret moon seed
+    ///
+    /// # Returns
+    /// * The session id of the new game
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_wager_game(
+        env: Env,
+        gardener: Address,
+        creature: Address,
+        points: i128,
+        wager: Wager,
+        rule_set_id: u32,
+        creature_kind: CreatureKind,
+        creature_seed_hash: Option<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        if gardener == creature {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+        if wager.amount <= 0 {
+            return Err(Error::InvalidWager);
+        }
+        let fee_bps = Self::get_wager_fee(env.clone()).map_or(0, |fee| fee.fee_bps);
+        Self::wager_split(wager.amount, fee_bps).ok_or(Error::InvalidWager)?;
+
+        let rules: RuleSet = env
+            .storage()
+            .instance()
+            .get(&DataKey::RuleSet(rule_set_id))
+            .ok_or(Error::RuleSetNotFound)?;
+
+        gardener.require_auth_for_args(vec![
+            &env,
+            points.into_val(&env),
+            wager.into_val(&env),
+            rule_set_id.into_val(&env),
+        ]);
+        creature.require_auth_for_args(vec![
+            &env,
+            points.into_val(&env),
+            wager.into_val(&env),
+            rule_set_id.into_val(&env),
+            creature_kind.into_val(&env),
+            creature_seed_hash.into_val(&env),
+        ]);
+
+        // Escrow both stakes
+        let token = token::TokenClient::new(&env, &wager.token);
+        let escrow = env.current_contract_address();
+        token.transfer(&gardener, &escrow, &wager.amount);
+        token.transfer(&creature, &escrow, &wager.amount);
+
+        let moon = match creature_seed_hash {
+            Some(hash) => MoonState::AwaitingGardenerSeed(hash),
+            None => MoonState::Fixed,
+        };
+
+        let session_id = Self::allocate_session_id(&env);
+        Self::create_session(
+            &env,
+            session_id,
+            gardener,
+            creature,
+            points,
+            points,
+            rule_set_id,
+            rules,
+            creature_kind,
+            moon,
+            None,
+            Some(wager),
+        )?;
+
+        Ok(session_id)
+    }
+
+    /// Gardener submits the garden commitment hash
     /// After this, the game begins and Creature can move
     ///
+    /// Without a proof any 32 bytes are accepted; a commitment to an invalid
+    /// garden then simply cannot be revealed. With `validity_proof`, the
+    /// `garden-validity` journal must open to this commitment and be bound to
+    /// the session, gardener and rule set, checked like `reveal_cell` against
+    /// the image ID at `DataKey::CommitmentImageId`. The session records
+    /// `garden_proven` so the Creature can see the garden is playable.
+    ///
+    /// If the Creature committed a moon seed in `start_game`, the Gardener must
+    /// commit theirs here, and the reveal deadline starts now.
+    ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `garden_commitment` - SHA256 hash of the garden layout (32 bytes)
+    /// * `validity_proof` - Optional proof that the commitment opens to a valid garden
+    /// * `moon_seed_hash` - sha256 of the Gardener's moon seed (commit-reveal games only)
     pub fn commit_garden(
         env: Env,
         session_id: u32,
         garden_commitment: BytesN<32>,
+        validity_proof: Option<CommitmentProof>,
+        moon_seed_hash: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         let key = DataKey::Session(session_id);
         let mut session: GameSession = env
@@ -285,31 +1014,68 @@ impl HerbalMoonlight {
             return Err(Error::InvalidPhase);
         }
 
-        // Store commitment and transition to Playing phase
-        session.garden_commitment = garden_commitment;
+        session.garden_commitment = garden_commitment.clone();
+
+        // Reject a commitment the proof cannot open
+        if let Some(proof) = validity_proof {
+            if proof.journal_bytes.len() != COMMITMENT_JOURNAL_LEN {
+                return Err(Error::ProofVerificationFailed);
+            }
+            Self::verify_reveal_journal(
+                &env,
+                &session,
+                session_id,
+                &proof.journal_bytes,
+                &proof.journal_hash,
+                &proof.seal,
+                &DataKey::CommitmentImageId,
+            )?;
+            session.garden_proven = true;
+        }
+
+        // Both seed hashes are in: the reveal window opens
+        session.moon = match (&session.moon, moon_seed_hash) {
+            (MoonState::Fixed, None) => MoonState::Fixed,
+            (MoonState::AwaitingGardenerSeed(creature_seed_hash), Some(gardener_seed_hash)) => {
+                MoonState::Drawing(MoonDraw {
+                    gardener_seed_hash,
+                    creature_seed_hash: creature_seed_hash.clone(),
+                    gardener_seed: None,
+                    creature_seed: None,
+                    deadline_ledger: env.ledger().sequence().saturating_add(TURN_TIMEOUT_LEDGERS),
+                })
+            }
+            _ => return Err(Error::MoonSeedMismatch),
+        };
+
+        // Transition to Playing phase
         session.phase = GamePhase::Playing;
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
 
         env.storage().temporary().set(&key, &session);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
+        GardenCommitted {
+            session_id,
+            garden_commitment,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
     /// Creature moves to a new position
-    /// After moving, state transitions to WaitingForProof
+    /// After moving, state transitions to WaitingForProof until every
+    /// traversed cell is revealed (one per row advanced)
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `new_x` - New X coordinate (0-4)
-    /// * `new_y` - New Y coordinate (0-4)
-    pub fn creature_move(
-        env: Env,
-        session_id: u32,
-        new_x: u32,
-        new_y: u32,
-    ) -> Result<(), Error> {
+    /// * `new_x` - New X coordinate (0 to board_width - 1)
+    /// * `new_y` - New Y coordinate (0 to board_height - 1)
+    pub fn creature_move(env: Env, session_id: u32, new_x: u32, new_y: u32) -> Result<(), Error> {
         let key = DataKey::Session(session_id);
         let mut session: GameSession = env
             .storage()
@@ -324,54 +1090,96 @@ impl HerbalMoonlight {
         if session.phase != GamePhase::Playing {
             return Err(Error::InvalidPhase);
         }
+        if session.moon != MoonState::Fixed {
+            return Err(Error::MoonNotFixed);
+        }
 
-        // Validate movement rules:
-        // - Must advance exactly 1 row forward
+        // Validate movement rules (see CreatureKind::can_move):
+        // - Must advance 1 row forward (2 for a Moon Moth Flutter)
         // - First move (from row 0): any column allowed (creature chooses entry)
-        // - Subsequent moves: at most 1 column left/right
-        let y_diff = new_y.saturating_sub(session.creature_y);
-        if y_diff != 1 || new_x >= GRID_SIZE || new_y >= GRID_SIZE {
+        // - Subsequent moves: sideways limit of the creature kind
+        let rules = &session.rules;
+        if new_y <= session.creature_y || new_x >= rules.board_width || new_y >= rules.board_height
+        {
             return Err(Error::InvalidMove);
         }
-        if session.creature_y > 0 {
-            let x_diff = if new_x > session.creature_x {
-                new_x - session.creature_x
-            } else {
-                session.creature_x - new_x
-            };
-            if x_diff > 1 {
-                return Err(Error::InvalidMove);
-            }
+        let rows = new_y - session.creature_y;
+        let columns = new_x.abs_diff(session.creature_x);
+        if !session
+            .creature_kind
+            .can_move(rows, columns, session.creature_y == 0)
+        {
+            return Err(Error::InvalidMove);
         }
 
-        // Update creature position
+        // Update creature position; cells (new_x, creature_y + 1..=new_y) await reveal
         session.creature_x = new_x;
         session.creature_y = new_y;
+        session.path.push_back(new_y * rules.board_width + new_x);
+        session.pending_reveals = rows;
         session.phase = GamePhase::WaitingForProof; // Waiting for ZK proof
         session.turn_number += 1;
+        session.last_action_ledger = env.ledger().sequence();
+
+        // A cycling moon moves on with every turn
+        let cycle_len = herbal_rules::RuleSet::from(&session.rules).moon_cycle_len();
+        if cycle_len > 0 {
+            session.moon_clock = (session.moon_clock + 1) % cycle_len;
+            session.moon_phase = Self::moon_at(&session.rules, session.moon_clock);
+        }
+        session.cancel_proposed_by = None;
 
         env.storage().temporary().set(&key, &session);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
+        CreatureMoved {
+            session_id,
+            x: new_x,
+            y: new_y,
+            turn_number: session.turn_number,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
     /// Gardener reveals a cell using ZK proof
     /// If Creature dies or reaches the house, the game ends
     ///
+    /// After a multi-row move the traversed cells are revealed in order, one
+    /// call each; the phase stays WaitingForProof until the last one.
+    ///
+    /// Each cell is revealed once, so a plant damages only the first time it
+    /// triggers: replaying the reveal of an earlier cell is refused with
+    /// `CellAlreadyRevealed`.
+    ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `journal_bytes` - The ZK proof journal bytes
     /// * `journal_hash` - SHA256 hash of the journal
     /// * `seal` - The Groth16 proof seal (empty for dev mode)
     ///
+    /// The journal must carry this `session_id` and the gardener's key
+    /// (`SessionMismatch` / `GardenerMismatch` otherwise), so a proof for one
+    /// game cannot be replayed into another that reuses the same commitment.
+    /// It must also carry the session's `rule_set_hash` (`RuleSetMismatch`
+    /// otherwise), so the circuit validated the garden under the same rules.
+    ///
+    /// # Production Mode
+    /// A non-empty seal must be a 256-byte Groth16 proof. It is checked by the
+    /// verifier contract at `DataKey::VerifierId` against the stored image ID
+    /// and `journal_hash`; any failure returns `ProofVerificationFailed`.
+    ///
     /// # Dev Mode
-    /// If the seal is empty, the contract operates in dev mode:
+    /// If the seal is empty and the admin enabled dev mode (`set_dev_mode`):
     /// - Only verifies that sha256(journal_bytes) == journal_hash
     /// - Does NOT provide cryptographic security
     /// - Use only for development and testing
+    ///
+    /// With dev mode off (the default), an empty seal returns
+    /// `ProofVerificationFailed`.
     pub fn reveal_cell(
         env: Env,
         session_id: u32,
@@ -394,236 +1202,2028 @@ impl HerbalMoonlight {
             return Err(Error::InvalidPhase);
         }
 
-        // Verify commitment in journal
-        let journal_commitment = Self::extract_commitment(&journal_bytes)
-            .ok_or(Error::CommitmentMismatch)?;
-
-        if journal_commitment != session.garden_commitment {
-            return Err(Error::CommitmentMismatch);
-        }
-
-        // Verify proof based on mode
-        if seal.is_empty() {
-            // DEV MODE: Only verify journal hash
-            // WARNING: No cryptographic security! Only for development.
-            let computed_hash: BytesN<32> = env.crypto().sha256(&journal_bytes).into();
-            if computed_hash != journal_hash {
-                return Err(Error::ProofVerificationFailed);
-            }
-            // Dev mode passes - journal hash verified
-        } else {
-            // PRODUCTION MODE: Verify Groth16 proof
-            // TODO: Implement when Groth16 verifier contract is ready
-            // This will use Protocol 25 BN254 primitives (CAP-0074)
-            //
-            // let verifier_id: Address = env.storage().instance()
-            //     .get(&DataKey::VerifierId)
-            //     .ok_or(Error::NotInitialized)?;
-            //
-            // let image_id: BytesN<32> = env.storage().instance()
-            //     .get(&DataKey::ImageId)
-            //     .ok_or(Error::NotInitialized)?;
-            //
-            // if !Self::verify_groth16_proof(&env, &verifier_id, &seal, &image_id, &journal_hash) {
-            //     return Err(Error::ProofVerificationFailed);
-            // }
-
-            // For now, also verify journal hash as basic check
-            let computed_hash: BytesN<32> = env.crypto().sha256(&journal_bytes).into();
-            if computed_hash != journal_hash {
-                return Err(Error::ProofVerificationFailed);
-            }
-        }
+        Self::verify_reveal_journal(
+            &env,
+            &session,
+            session_id,
+            &journal_bytes,
+            &journal_hash,
+            &seal,
+            &DataKey::ImageId,
+        )?;
 
         // Decode journal to extract cell reveal result
-        let mut result = Self::decode_journal(&journal_bytes)
-            .ok_or(Error::ProofVerificationFailed)?;
-
-        // Verify coordinates match the creature's current position
-        if result.x != session.creature_x || result.y != session.creature_y {
-            return Err(Error::InvalidCoordinates);
-        }
+        let mut result =
+            Self::decode_journal(&journal_bytes).ok_or(Error::ProofVerificationFailed)?;
 
-        // Mark cell as revealed
-        let cell_index = result.y * GRID_SIZE + result.x;
-        session.revealed_cells.push_back(cell_index);
+        let outcome = Self::apply_reveal(&env, &mut session, &mut result)?;
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
 
-        // Apply damage if plant exists
-        if result.has_plant {
-            // Validate plant type is known (1=Lavender, 2=Mint, 3=Mandrake)
-            if result.plant_type < 1 || result.plant_type > 3 {
-                return Err(Error::ProofVerificationFailed);
-            }
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-            // Contract computes damage from plant type (authoritative)
-            let base_damage = Self::base_damage_for_plant(result.plant_type);
-            let moon_adjusted = Self::calculate_damage(base_damage, &session.moon_phase);
+        // CRITICAL: Call Game Hub end_game if the game ended
+        if let Some(gardener_won) = outcome {
+            Self::end_game_on_hub(&env, &session, gardener_won)?;
+        }
 
-            // Apply Lavender calming mist reduction from previous hit
-            let after_reduction = moon_adjusted.saturating_sub(session.damage_reduction);
-            session.damage_reduction = 0;
+        Ok(result)
+    }
 
-            // Minimum 1 damage from any plant
-            let final_damage = if after_reduction == 0 { 1 } else { after_reduction };
+    /// Gardener reveals several cells of the pending move with one batch proof
+    ///
+    /// The proof comes from the `batch-reveal` circuit and is checked like
+    /// `reveal_cell` against the image ID at `DataKey::BatchRevealImageId`.
+    /// Its cells are applied in order exactly as separate `reveal_cell` calls
+    /// would be, so a Moon Moth Flutter settles in one transaction. The batch
+    /// may not go past the pending move or the end of the game
+    /// (`InvalidCoordinates`).
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `journal_bytes` - The ZK proof journal bytes
+    /// * `journal_hash` - SHA256 hash of the journal
+    /// * `seal` - The Groth16 proof seal (empty for dev mode)
+    pub fn reveal_cells(
+        env: Env,
+        session_id: u32,
+        journal_bytes: Bytes,
+        journal_hash: BytesN<32>,
+        seal: Bytes,
+    ) -> Result<Vec<CellRevealResult>, Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
 
-            // If this plant is Lavender, set calming mist for next hit
-            if result.plant_type == 1 {
-                session.damage_reduction = 1;
-            }
+        // Only Gardener can reveal
+        session.gardener.require_auth();
 
-            result.damage_dealt = final_damage;
-            session.creature_hp = session.creature_hp.saturating_sub(final_damage);
+        // Must be in WaitingForProof phase
+        if session.phase != GamePhase::WaitingForProof {
+            return Err(Error::InvalidPhase);
         }
 
-        // Check win conditions
-        let game_ended: bool;
-        let gardener_won: bool;
-
-        if session.creature_hp == 0 {
-            // Gardener wins - Creature dies
-            session.phase = GamePhase::Finished;
-            game_ended = true;
-            gardener_won = true;
-        } else if session.creature_y >= 4 {
-            // Creature wins - Reached the house (bottom row)
-            session.phase = GamePhase::Finished;
-            game_ended = true;
-            gardener_won = false;
-        } else {
-            // Game continues
-            session.phase = GamePhase::Playing;
-            game_ended = false;
-            gardener_won = false;
+        Self::verify_reveal_journal(
+            &env,
+            &session,
+            session_id,
+            &journal_bytes,
+            &journal_hash,
+            &seal,
+            &DataKey::BatchRevealImageId,
+        )?;
+
+        let cells =
+            Self::decode_batch_journal(&journal_bytes).ok_or(Error::ProofVerificationFailed)?;
+
+        let mut results = Vec::new(&env);
+        let mut outcome = None;
+        for mut result in cells.iter() {
+            // Every cell must belong to the move still being revealed
+            if session.phase != GamePhase::WaitingForProof {
+                return Err(Error::InvalidCoordinates);
+            }
+            outcome = Self::apply_reveal(&env, &mut session, &mut result)?;
+            results.push_back(result);
         }
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
 
         env.storage().temporary().set(&key, &session);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-        // CRITICAL: Call Game Hub end_game if the game ended
-        if game_ended {
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .ok_or(Error::NotInitialized)?;
-
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
-            game_hub.end_game(&session_id, &gardener_won);
+        if let Some(gardener_won) = outcome {
+            Self::end_game_on_hub(&env, &session, gardener_won)?;
         }
 
-        Ok(result)
+        Ok(results)
     }
 
-    /// Get the current session state
+    /// Creature spends HP on a Spirit Sense instead of moving
+    /// After requesting, state transitions to WaitingForSenseProof
+    ///
+    /// Moon phase rules:
+    /// - Full Moon: the sense is free
+    /// - New Moon: senses are not allowed (`SenseNotAllowed`)
+    /// - Balanced: costs `rules.sense_hp_cost` HP; the Creature cannot spend
+    ///   its last HP (`InsufficientHp`)
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    ///
-    /// # Returns
-    /// * `GameSession` - The complete game state
-    pub fn get_session(env: Env, session_id: u32) -> Result<GameSession, Error> {
-        env.storage()
+    /// * `kind` - The sense to use
+    pub fn request_sense(env: Env, session_id: u32, kind: SenseKind) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
             .temporary()
-            .get(&DataKey::Session(session_id))
-            .ok_or(Error::SessionNotFound)
-    }
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
 
-    /// Get the configured Game Hub address
-    ///
-    /// # Returns
-    /// * `Address` - The Game Hub contract address
-    pub fn get_hub(env: Env) -> Result<Address, Error> {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .ok_or(Error::NotInitialized)
-    }
+        // Only Creature can sense
+        session.creature.require_auth();
 
-    /// Update the Game Hub address (admin only)
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
+        // Must be in Playing phase
+        if session.phase != GamePhase::Playing {
+            return Err(Error::InvalidPhase);
+        }
+        if session.moon != MoonState::Fixed {
+            return Err(Error::MoonNotFixed);
+        }
+
+        let cost = match session.moon_phase {
+            MoonPhase::FullMoon => 0,
+            MoonPhase::NewMoon => return Err(Error::SenseNotAllowed),
+            MoonPhase::Balanced => session.rules.sense_hp_cost,
+        };
+        if cost > 0 && session.creature_hp <= cost {
+            return Err(Error::InsufficientHp);
+        }
 
+        session.creature_hp -= cost;
+        session.sense = SenseState::Pending(kind);
+        session.phase = GamePhase::WaitingForSenseProof;
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
+
+        env.storage().temporary().set(&key, &session);
         env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        SenseRequested {
+            session_id,
+            kind,
+            creature_hp: session.creature_hp,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
+    /// Gardener answers the pending Spirit Sense using a ZK proof
+    /// The game returns to Playing and the result is kept in `session.sense`
+    ///
+    /// Proofs come from the `peek-adjacent` / `smell-ahead` circuits and are
+    /// checked exactly like `reveal_cell` (commitment, journal hash, session and
+    /// gardener binding), against the image ID at `DataKey::SenseImageId(kind)`.
     ///
     /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `journal_bytes` - The ZK proof journal bytes
+    /// * `journal_hash` - SHA256 hash of the journal
+    /// * `seal` - The Groth16 proof seal (empty for dev mode)
+    pub fn reveal_sense(
+        env: Env,
+        session_id: u32,
+        journal_bytes: Bytes,
+        journal_hash: BytesN<32>,
+        seal: Bytes,
+    ) -> Result<SenseResult, Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
 
-    // ========================================================================
-    // Internal Helper Functions
-    // ========================================================================
+        // Only Gardener can reveal
+        session.gardener.require_auth();
 
-    /// Determine moon phase deterministically based on session_id
-    /// Ensures consistent randomness between simulation and submission
-    fn determine_moon_phase(env: &Env, session_id: u32) -> MoonPhase {
-        // Use deterministic PRNG with session_id as seed
-        let mut seed_bytes = Bytes::new(env);
-        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
-        let hash = env.crypto().keccak256(&seed_bytes);
+        // Must be in WaitingForSenseProof phase
+        let kind = match (&session.phase, &session.sense) {
+            (GamePhase::WaitingForSenseProof, SenseState::Pending(kind)) => *kind,
+            _ => return Err(Error::InvalidPhase),
+        };
 
-        let seed_val = hash.to_array()[0] as u32;
-        match seed_val % 100 {
-            0..=19 => MoonPhase::FullMoon,
-            20..=39 => MoonPhase::NewMoon,
-            _ => MoonPhase::Balanced,
+        Self::verify_reveal_journal(
+            &env,
+            &session,
+            session_id,
+            &journal_bytes,
+            &journal_hash,
+            &seal,
+            &DataKey::SenseImageId(kind),
+        )?;
+
+        let result =
+            Self::decode_sense_journal(&journal_bytes).ok_or(Error::ProofVerificationFailed)?;
+
+        // The proof must answer the sense that was requested, from the creature's position
+        if result.kind != kind {
+            return Err(Error::SenseKindMismatch);
         }
-    }
-
-    /// Get base damage for a plant type
-    /// 1=Lavender (1), 2=Mint (2), 3=Mandrake (3)
-    fn base_damage_for_plant(plant_type: u32) -> u32 {
-        match plant_type {
-            1 => 1,
-            2 => 2,
-            3 => 3,
-            _ => 0,
+        if result.x != session.creature_x || result.y != session.creature_y {
+            return Err(Error::InvalidCoordinates);
         }
-    }
 
-    /// Calculate damage based on moon phase modifier
-    fn calculate_damage(base_damage: u32, moon_phase: &MoonPhase) -> u32 {
-        match moon_phase {
-            MoonPhase::FullMoon => base_damage.saturating_sub(1),
-            MoonPhase::NewMoon => base_damage.saturating_add(1),
-            MoonPhase::Balanced => base_damage,
-        }
-    }
+        session.sense = SenseState::Answered(result.clone());
+        session.phase = GamePhase::Playing;
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
 
-    /// Extract garden commitment (first 32 bytes of journal)
-    fn extract_commitment(journal: &Bytes) -> Option<BytesN<32>> {
-        if journal.len() < 32 {
-            return None;
-        }
-        let mut arr = [0u8; 32];
-        for i in 0..32 {
-            arr[i] = journal.get(i as u32)?;
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        SenseRevealed {
+            session_id,
+            result: result.clone(),
         }
-        Some(BytesN::from_array(journal.env(), &arr))
+        .publish(&env);
+
+        Ok(result)
+    }
+
+    /// Gardener ends the game early by proving the Creature has no valid path
+    /// forward: every legal sequence of moves to the house is lethal
+    ///
+    /// Only on the Creature's turn (Playing). The proof comes from the
+    /// `no-path` circuit and is checked like `reveal_cell` against the image
+    /// ID at `DataKey::NoPathImageId`. It must be for the Creature's current
+    /// position (`InvalidCoordinates`) and its current kind, moon phase and
+    /// HP (`CreatureStateMismatch`).
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `journal_bytes` - The ZK proof journal bytes
+    /// * `journal_hash` - SHA256 hash of the journal
+    /// * `seal` - The Groth16 proof seal (empty for dev mode)
+    pub fn claim_no_path(
+        env: Env,
+        session_id: u32,
+        journal_bytes: Bytes,
+        journal_hash: BytesN<32>,
+        seal: Bytes,
+    ) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        // Only Gardener can claim
+        session.gardener.require_auth();
+
+        // Must be the Creature's turn
+        if session.phase != GamePhase::Playing {
+            return Err(Error::InvalidPhase);
+        }
+        if session.moon != MoonState::Fixed {
+            return Err(Error::MoonNotFixed);
+        }
+
+        Self::verify_reveal_journal(
+            &env,
+            &session,
+            session_id,
+            &journal_bytes,
+            &journal_hash,
+            &seal,
+            &DataKey::NoPathImageId,
+        )?;
+
+        let (x, y, kind, moon_phase, hp, moon_clock) =
+            Self::decode_no_path_journal(&journal_bytes).ok_or(Error::ProofVerificationFailed)?;
+
+        if x != session.creature_x || y != session.creature_y {
+            return Err(Error::InvalidCoordinates);
+        }
+        if kind != session.creature_kind
+            || moon_phase != session.moon_phase
+            || hp != session.creature_hp
+            || moon_clock != session.moon_clock
+        {
+            return Err(Error::CreatureStateMismatch);
+        }
+
+        Self::finish(&mut session, true);
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
+
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::end_game_on_hub(&env, &session, true)
+    }
+
+    /// Gardener voluntarily discloses (part of) the garden of a finished game,
+    /// e.g. for tournaments, coaching or dispute resolution
+    ///
+    /// Either the full preimage, checked with
+    /// sha256(width || height || cells || salt) against
+    /// the stored commitment (`CommitmentMismatch`), or a `batch-reveal` proof
+    /// of chosen cells, checked like `reveal_cells`. Disclosed cells are
+    /// added to the game record's `disclosed_cells`, which outlives the
+    /// session; disclosing again adds to them. The session itself must still
+    /// be live (`SessionNotFound`), as it holds the commitment.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `disclosure` - The garden preimage or a proof of chosen cells
+    pub fn disclose(env: Env, session_id: u32, disclosure: Disclosure) -> Result<(), Error> {
+        let session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)?;
+
+        // Only Gardener can disclose, and only once the game is over
+        session.gardener.require_auth();
+        if !session.phase.is_over() {
+            return Err(Error::InvalidPhase);
+        }
+
+        let storage = env.storage().persistent();
+        let key = DataKey::GameRecord(session_id);
+        let mut record: GameRecord = storage.get(&key).ok_or(Error::GameRecordNotFound)?;
+
+        match disclosure {
+            Disclosure::Garden(cells, salt) => {
+                if cells.len() != session.rules.board_width * session.rules.board_height {
+                    return Err(Error::CommitmentMismatch);
+                }
+                // Registered boards are at most 9x9 (`herbal_rules::MAX_BOARD_SIZE`)
+                let mut preimage = Bytes::from_array(
+                    &env,
+                    &[
+                        session.rules.board_width as u8,
+                        session.rules.board_height as u8,
+                    ],
+                );
+                preimage.append(&cells);
+                preimage.append(&salt.into());
+                let commitment: BytesN<32> = env.crypto().sha256(&preimage).into();
+                if commitment != session.garden_commitment {
+                    return Err(Error::CommitmentMismatch);
+                }
+
+                for (index, plant_type) in cells.iter().enumerate() {
+                    record.disclosed_cells.set(index as u32, plant_type as u32);
+                }
+            }
+            Disclosure::Cells(journal_bytes, journal_hash, seal) => {
+                Self::verify_reveal_journal(
+                    &env,
+                    &session,
+                    session_id,
+                    &journal_bytes,
+                    &journal_hash,
+                    &seal,
+                    &DataKey::BatchRevealImageId,
+                )?;
+                let cells = Self::decode_batch_journal(&journal_bytes)
+                    .ok_or(Error::ProofVerificationFailed)?;
+
+                for cell in cells.iter() {
+                    if cell.x >= session.rules.board_width || cell.y >= session.rules.board_height {
+                        return Err(Error::InvalidCoordinates);
+                    }
+                    let index = cell.y * session.rules.board_width + cell.x;
+                    record.disclosed_cells.set(index, cell.plant_type);
+                }
+            }
+        }
+
+        storage.set(&key, &record);
+        storage.extend_ttl(&key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+
+        GardenDisclosed {
+            session_id,
+            disclosed_cells: record.disclosed_cells.len(),
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Win by forfeit when the opponent has not acted within the turn timeout
+    ///
+    /// The player the game is waiting on forfeits:
+    /// - `WaitingForCommitment` / `WaitingForProof` / `WaitingForSenseProof`:
+    ///   Gardener stalled, Creature claims
+    /// - `Playing`: Creature stalled, Gardener claims
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn claim_timeout(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        let gardener_won = match session.phase {
+            GamePhase::WaitingForCommitment
+            | GamePhase::WaitingForProof
+            | GamePhase::WaitingForSenseProof => false,
+            // The Creature cannot move before the moon is fixed
+            GamePhase::Playing if session.moon != MoonState::Fixed => {
+                return Err(Error::MoonNotFixed)
+            }
+            GamePhase::Playing => true,
+            GamePhase::Finished | GamePhase::Cancelled => return Err(Error::GameAlreadyFinished),
+        };
+
+        // Only the waiting player can claim the forfeit
+        if gardener_won {
+            session.gardener.require_auth();
+        } else {
+            session.creature.require_auth();
+        }
+
+        let deadline = session
+            .last_action_ledger
+            .saturating_add(TURN_TIMEOUT_LEDGERS);
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        Self::finish(&mut session, gardener_won);
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
+
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::end_game_on_hub(&env, &session, gardener_won)
+    }
+
+    /// Resign a game, awarding it to the opponent
+    ///
+    /// Ends the game like any other win: both players' statistics, the wager
+    /// payout and the Game Hub `end_game` call (for a match round, the match
+    /// score) all follow.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The resigning player (Gardener or Creature)
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        player.require_auth();
+
+        if session.phase.is_over() {
+            return Err(Error::GameAlreadyFinished);
+        }
+        let gardener_won = if player == session.creature {
+            true
+        } else if player == session.gardener {
+            false
+        } else {
+            return Err(Error::NotAPlayer);
+        };
+
+        Self::finish(&mut session, gardener_won);
+        session.last_action_ledger = env.ledger().sequence();
+        session.cancel_proposed_by = None;
+
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        PlayerResigned { session_id, player }.publish(&env);
+        Self::end_game_on_hub(&env, &session, gardener_won)
+    }
+
+    /// Propose aborting a game with no result; the opponent confirms with
+    /// `accept_cancel`. The proposal lapses as soon as either player acts.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The proposing player (Gardener or Creature)
+    pub fn propose_cancel(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        player.require_auth();
+        Self::check_cancellable(&session, &player)?;
+
+        session.cancel_proposed_by = Some(player.clone());
+
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        CancelProposed { session_id, player }.publish(&env);
+        Ok(())
+    }
+
+    /// Accept the opponent's open cancellation proposal, aborting the game
+    /// like `cancel_game`
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The accepting player (the proposer's opponent)
+    pub fn accept_cancel(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        player.require_auth();
+        Self::check_cancellable(&session, &player)?;
+
+        let proposer = session
+            .cancel_proposed_by
+            .clone()
+            .ok_or(Error::NoCancelProposal)?;
+        if proposer == player {
+            return Err(Error::NoCancelProposal);
+        }
+
+        Self::cancel(&env, session);
+        Ok(())
+    }
+
+    /// Abort a game with no result, with both players signing at once
+    ///
+    /// The session moves to `Cancelled` without a winner, any wager is
+    /// refunded, and neither player's statistics change. The Game Hub session
+    /// is left as it is: the hub can only close a session with a winner, and
+    /// no one won. Match rounds cannot be cancelled.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn cancel_game(env: Env, session_id: u32) -> Result<(), Error> {
+        let session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)?;
+
+        session.gardener.require_auth();
+        session.creature.require_auth();
+        Self::check_cancellable(&session, &session.gardener)?;
+
+        Self::cancel(&env, session);
+        Ok(())
+    }
+
+    /// Reveal a player's moon seed for a session drawn by commit-reveal
+    ///
+    /// `sha256(seed)` must match the hash the player committed in `start_game`
+    /// (Creature) or `commit_garden` (Gardener). Once both seeds are revealed
+    /// the moon phase is drawn from them, the session id and the reveal
+    /// deadline, all fixed before either seed is known; a Full Moon adds its
+    /// bonus HP.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The revealing player (Gardener or Creature)
+    /// * `seed` - The secret seed
+    pub fn reveal_moon_seed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+    ) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        player.require_auth();
+
+        if session.phase.is_over() {
+            return Err(Error::GameAlreadyFinished);
+        }
+        let MoonState::Drawing(mut draw) = session.moon.clone() else {
+            return Err(Error::InvalidPhase);
+        };
+
+        let (hash, slot) = if player == session.gardener {
+            (&draw.gardener_seed_hash, &mut draw.gardener_seed)
+        } else if player == session.creature {
+            (&draw.creature_seed_hash, &mut draw.creature_seed)
+        } else {
+            return Err(Error::NotAPlayer);
+        };
+        if slot.is_some() {
+            return Err(Error::MoonSeedAlreadyRevealed);
+        }
+        let seed_hash: BytesN<32> = env.crypto().sha256(&Bytes::from(seed.clone())).into();
+        if seed_hash != *hash {
+            return Err(Error::MoonSeedMismatch);
+        }
+        *slot = Some(seed);
+
+        if draw.gardener_seed.is_some() && draw.creature_seed.is_some() {
+            Self::fix_moon(&env, &mut session, &draw);
+        } else {
+            session.moon = MoonState::Drawing(draw);
+        }
+        session.cancel_proposed_by = None;
+
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Draw the moon without a missing seed once the reveal deadline
+    /// (720 ledgers after `commit_garden`) has passed. Anyone can call it; the
+    /// seeds that were revealed still count.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn settle_moon(env: Env, session_id: u32) -> Result<(), Error> {
+        let key = DataKey::Session(session_id);
+        let mut session: GameSession = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::SessionNotFound)?;
+
+        if session.phase.is_over() {
+            return Err(Error::GameAlreadyFinished);
+        }
+        let MoonState::Drawing(draw) = session.moon.clone() else {
+            return Err(Error::InvalidPhase);
+        };
+        if env.ledger().sequence() <= draw.deadline_ledger {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        Self::fix_moon(&env, &mut session, &draw);
+        session.cancel_proposed_by = None;
+
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Post an open challenge to the lobby
+    ///
+    /// The creator consents here, in their own transaction, to play `role`
+    /// with `points` at stake under the given rules; any other player can
+    /// then start the game alone with `accept_challenge`. Unaccepted
+    /// challenges expire after ~1 day.
+    ///
+    /// # Arguments
+    /// * `creator` - Address posting the challenge
+    /// * `role` - Role the creator plays
+    /// * `points` - Points amount each player commits
+    /// * `rule_set_id` - Registered rule set to play under (0 = standard rules)
+    /// * `creature_kind` - Creature type the Creature plays
+    ///
+    /// # Returns
+    /// * The challenge id
+    pub fn create_challenge(
+        env: Env,
+        creator: Address,
+        role: PlayerRole,
+        points: i128,
+        rule_set_id: u32,
+        creature_kind: CreatureKind,
+    ) -> Result<u32, Error> {
+        creator.require_auth();
+
+        if !env.storage().instance().has(&DataKey::RuleSet(rule_set_id)) {
+            return Err(Error::RuleSetNotFound);
+        }
+
+        let storage = env.storage().instance();
+        let challenge_id: u32 = storage.get(&DataKey::NextChallengeId).unwrap_or(0);
+        storage.set(&DataKey::NextChallengeId, &(challenge_id + 1));
+
+        let challenge = Challenge {
+            challenge_id,
+            creator: creator.clone(),
+            role,
+            points,
+            rule_set_id,
+            creature_kind,
+            expires_ledger: env.ledger().sequence() + CHALLENGE_EXPIRY_LEDGERS,
+        };
+        let key = DataKey::Challenge(challenge_id);
+        env.storage().temporary().set(&key, &challenge);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        ChallengeCreated {
+            challenge_id,
+            creator,
+            role,
+            points,
+            rule_set_id,
+            creature_kind,
+            expires_ledger: challenge.expires_ledger,
+        }
+        .publish(&env);
+
+        Ok(challenge_id)
+    }
+
+    /// Accept an open challenge, taking the role the creator left free
+    ///
+    /// Allocates a fresh session id and starts the game on the Game Hub with
+    /// both players committing the challenge's points.
+    ///
+    /// # Arguments
+    /// * `challenge_id` - The challenge to accept
+    /// * `player` - Address of the accepting player
+    ///
+    /// # Returns
+    /// * The session id of the new game
+    pub fn accept_challenge(env: Env, challenge_id: u32, player: Address) -> Result<u32, Error> {
+        player.require_auth();
+
+        let challenge = Self::load_challenge(&env, challenge_id).ok_or(Error::ChallengeNotFound)?;
+        if Self::is_expired(&env, &challenge) {
+            return Err(Error::ChallengeExpired);
+        }
+        if player == challenge.creator {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+
+        let rules: RuleSet = env
+            .storage()
+            .instance()
+            .get(&DataKey::RuleSet(challenge.rule_set_id))
+            .ok_or(Error::RuleSetNotFound)?;
+
+        Self::remove_challenge(&env, challenge_id);
+
+        let (gardener, creature) = match challenge.role {
+            PlayerRole::Gardener => (challenge.creator, player.clone()),
+            PlayerRole::Creature => (player.clone(), challenge.creator),
+        };
+        let session_id = Self::allocate_session_id(&env);
+        Self::create_session(
+            &env,
+            session_id,
+            gardener,
+            creature,
+            challenge.points,
+            challenge.points,
+            challenge.rule_set_id,
+            rules,
+            challenge.creature_kind,
+            MoonState::Fixed,
+            None,
+            None,
+        )?;
+
+        ChallengeAccepted {
+            challenge_id,
+            opponent: player,
+            session_id,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// Withdraw an unaccepted challenge (creator only, expired or not)
+    ///
+    /// # Arguments
+    /// * `challenge_id` - The challenge to cancel
+    pub fn cancel_challenge(env: Env, challenge_id: u32) -> Result<(), Error> {
+        let challenge = Self::load_challenge(&env, challenge_id).ok_or(Error::ChallengeNotFound)?;
+        challenge.creator.require_auth();
+
+        Self::remove_challenge(&env, challenge_id);
+        ChallengeCancelled { challenge_id }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a challenge by id
+    ///
+    /// # Arguments
+    /// * `challenge_id` - The challenge id
+    pub fn get_challenge(env: Env, challenge_id: u32) -> Result<Challenge, Error> {
+        Self::load_challenge(&env, challenge_id).ok_or(Error::ChallengeNotFound)
+    }
+
+    /// Number of challenges ever posted (the next challenge id)
+    pub fn get_challenge_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextChallengeId)
+            .unwrap_or(0)
+    }
+
+    /// One page of challenge ids, newest first, keeping those that can still
+    /// be accepted. Accepted, cancelled and expired challenges are skipped,
+    /// so a page may hold fewer than `limit`; page on until `offset` reaches
+    /// `get_challenge_count`.
+    ///
+    /// # Arguments
+    /// * `offset` - Number of most recent challenge ids to skip
+    /// * `limit` - Number of challenge ids to scan (at most 20)
+    pub fn get_open_challenges(env: Env, offset: u32, limit: u32) -> Vec<Challenge> {
+        let end = Self::get_challenge_count(env.clone()).saturating_sub(offset);
+        let start = end.saturating_sub(limit.min(MAX_CHALLENGE_PAGE));
+
+        let mut open = Vec::new(&env);
+        for id in (start..end).rev() {
+            if let Some(challenge) = Self::load_challenge(&env, id) {
+                if !Self::is_expired(&env, &challenge) {
+                    open.push_back(challenge);
+                }
+            }
+        }
+        open
+    }
+
+    /// Start a best-of-`best_of` match between two players
+    ///
+    /// The match is a single Game Hub session: both players' points are
+    /// locked once, here, and `GameHub::end_game` is called once a player has
+    /// won a majority of the rounds. Round 1 starts immediately with player1
+    /// as the Gardener; every finished round starts the next one with the
+    /// roles swapped (see `MatchRoundStarted` or `get_match` for its session).
+    ///
+    /// # Arguments
+    /// * `player1`, `player2` - The players; each authorizes the points, the
+    ///   match length, the rule set and the creature kind
+    /// * `points` - Points each player commits
+    /// * `best_of` - Odd number of rounds, at most 9
+    /// * `rule_set_id` - Rule set of every round
+    /// * `creature_kind` - Kind the Creature plays in every round
+    pub fn start_match(
+        env: Env,
+        player1: Address,
+        player2: Address,
+        points: i128,
+        best_of: u32,
+        rule_set_id: u32,
+        creature_kind: CreatureKind,
+    ) -> Result<u32, Error> {
+        if player1 == player2 {
+            return Err(Error::SelfPlayNotAllowed);
+        }
+        if best_of.is_multiple_of(2) || best_of > MAX_MATCH_ROUNDS {
+            return Err(Error::InvalidMatchLength);
+        }
+
+        let rules: RuleSet = env
+            .storage()
+            .instance()
+            .get(&DataKey::RuleSet(rule_set_id))
+            .ok_or(Error::RuleSetNotFound)?;
+
+        for player in [&player1, &player2] {
+            player.require_auth_for_args(vec![
+                &env,
+                points.into_val(&env),
+                best_of.into_val(&env),
+                rule_set_id.into_val(&env),
+                creature_kind.into_val(&env),
+            ]);
+        }
+
+        let match_id = Self::allocate_session_id(&env);
+        Self::start_on_hub(&env, match_id, &player1, &player2, points, points)?;
+
+        let mut m = Match {
+            match_id,
+            player1: player1.clone(),
+            player2: player2.clone(),
+            points,
+            best_of,
+            rule_set_id,
+            creature_kind,
+            player1_wins: 0,
+            player2_wins: 0,
+            round: 1,
+            session_id: 0,
+            finished: false,
+        };
+
+        MatchStarted {
+            match_id,
+            player1,
+            player2,
+            best_of,
+            rule_set_id,
+        }
+        .publish(&env);
+
+        Self::start_match_round(&env, &mut m, rules)?;
+
+        let key = DataKey::Match(match_id);
+        env.storage().temporary().set(&key, &m);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Ok(match_id)
+    }
+
+    /// Get a match: score, current round and its session
+    pub fn get_match(env: Env, match_id: u32) -> Result<Match, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Match(match_id))
+            .ok_or(Error::MatchNotFound)
+    }
+
+    /// Get the current session state
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `GameSession` - The complete game state
+    pub fn get_session(env: Env, session_id: u32) -> Result<GameSession, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    /// Winner of a session, `None` while it is still being played or if it
+    /// was cancelled. Lets other contracts (e.g. tournaments) read results
+    /// without decoding `GameSession`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn get_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        Self::get_session(env, session_id).map(|session| session.winner)
+    }
+
+    /// Phase of a session, e.g. to tell a cancelled game from one still
+    /// being played
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn get_phase(env: Env, session_id: u32) -> Result<GamePhase, Error> {
+        Self::get_session(env, session_id).map(|session| session.phase)
+    }
+
+    /// Archived record of an ended game; outlives the session itself
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    pub fn get_game_record(env: Env, session_id: u32) -> Result<GameRecord, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GameRecord(session_id))
+            .ok_or(Error::GameRecordNotFound)
+    }
+
+    /// Number of archived games a player took part in, in either role
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    pub fn get_player_game_count(env: Env, player: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerGameCount(player))
+            .unwrap_or(0)
+    }
+
+    /// One page of a player's archived games, newest first. Records whose
+    /// storage has expired are skipped.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    /// * `offset` - Number of most recent games to skip
+    /// * `limit` - Page size (at most 20)
+    pub fn get_player_history(
+        env: Env,
+        player: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GameRecord> {
+        let count = Self::get_player_game_count(env.clone(), player.clone());
+        let end = count.saturating_sub(offset);
+        let start = end.saturating_sub(limit.min(MAX_HISTORY_PAGE));
+
+        let storage = env.storage().persistent();
+        let mut records = Vec::new(&env);
+        for index in (start..end).rev() {
+            let record = storage
+                .get::<_, u32>(&DataKey::PlayerGame(player.clone(), index))
+                .and_then(|session_id| storage.get(&DataKey::GameRecord(session_id)));
+            if let Some(record) = record {
+                records.push_back(record);
+            }
+        }
+        records
+    }
+
+    /// Lifetime statistics of a player (all zero, with initial ratings, if
+    /// they have never finished a game)
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        Self::load_stats(&env, &player)
+    }
+
+    /// Elo rating of a player in one role
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    /// * `role` - Role the rating is for
+    pub fn get_rating(env: Env, player: Address, role: PlayerRole) -> u32 {
+        let stats = Self::load_stats(&env, &player);
+        match role {
+            PlayerRole::Gardener => stats.gardener_rating,
+            PlayerRole::Creature => stats.creature_rating,
+        }
+    }
+
+    /// Get the configured Game Hub address
+    ///
+    /// # Returns
+    /// * `Address` - The Game Hub contract address
+    pub fn get_hub(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Update the Game Hub address (admin only)
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    pub fn set_hub(env: Env, new_hub: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+        Ok(())
+    }
+
+    /// Set the treasury and fee taken from wager pots (admin only)
+    ///
+    /// Only wager games started afterwards pay the new fee.
+    ///
+    /// # Arguments
+    /// * `treasury` - Address receiving the fees
+    /// * `fee_bps` - Fee in basis points of the pot (at most 1000 = 10%)
+    pub fn set_wager_fee(env: Env, treasury: Address, fee_bps: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if fee_bps > MAX_WAGER_FEE_BPS {
+            return Err(Error::InvalidWagerFee);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::WagerFee, &WagerFee { treasury, fee_bps });
+        Ok(())
+    }
+
+    /// Current wager fee, if one has been set
+    pub fn get_wager_fee(env: Env) -> Option<WagerFee> {
+        env.storage().instance().get(&DataKey::WagerFee)
+    }
+
+    /// Set the image ID of a Spirit Sense circuit (admin only)
+    ///
+    /// # Arguments
+    /// * `kind` - The sense the circuit proves
+    /// * `image_id` - Image ID of the RiscZero circuit (32 bytes)
+    pub fn set_sense_image_id(
+        env: Env,
+        kind: SenseKind,
+        image_id: BytesN<32>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SenseImageId(kind), &image_id);
+        Ok(())
+    }
+
+    /// Set the image ID of the no-path circuit (admin only)
+    ///
+    /// # Arguments
+    /// * `image_id` - Image ID of the RiscZero circuit (32 bytes)
+    pub fn set_no_path_image_id(env: Env, image_id: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::NoPathImageId, &image_id);
+        Ok(())
+    }
+
+    /// Set the image ID of the batch reveal circuit (admin only)
+    ///
+    /// # Arguments
+    /// * `image_id` - Image ID of the RiscZero circuit (32 bytes)
+    pub fn set_batch_reveal_image_id(env: Env, image_id: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::BatchRevealImageId, &image_id);
+        Ok(())
+    }
+
+    /// Set the image ID of the garden validity circuit (admin only)
+    ///
+    /// # Arguments
+    /// * `image_id` - Image ID of the RiscZero circuit (32 bytes)
+    pub fn set_commitment_image_id(env: Env, image_id: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::CommitmentImageId, &image_id);
+        Ok(())
+    }
+
+    /// Accept or refuse empty seals (admin only)
+    ///
+    /// With dev mode on, a proof with an empty seal is checked against its
+    /// journal hash only, with no cryptographic security. It is off after
+    /// deployment, and every proof then goes through the verifier.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether empty seals are accepted
+    pub fn set_dev_mode(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::DevMode, &enabled);
+        Ok(())
+    }
+
+    /// Whether empty seals are accepted
+    pub fn is_dev_mode(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::DevMode)
+            .unwrap_or(false)
+    }
+
+    /// Register a rule set under a new id (admin only)
+    ///
+    /// Rule sets are immutable once registered; running sessions keep
+    /// their own copy.
+    ///
+    /// # Arguments
+    /// * `rule_set_id` - Id players pass to `start_game`
+    /// * `rules` - The rules
+    pub fn register_rule_set(env: Env, rule_set_id: u32, rules: RuleSet) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::RuleSet(rule_set_id);
+        if env.storage().instance().has(&key) {
+            return Err(Error::AlreadyInitialized);
+        }
+        if !herbal_rules::RuleSet::from(&rules).is_valid() {
+            return Err(Error::InvalidRuleSet);
+        }
+
+        env.storage().instance().set(&key, &rules);
+        Ok(())
+    }
+
+    /// Get a registered rule set
+    ///
+    /// # Arguments
+    /// * `rule_set_id` - The rule set id
+    pub fn get_rule_set(env: Env, rule_set_id: u32) -> Result<RuleSet, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RuleSet(rule_set_id))
+            .ok_or(Error::RuleSetNotFound)
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    // ========================================================================
+    // Internal Helper Functions
+    // ========================================================================
+
+    /// Register a session with the Game Hub and store its initial state.
+    /// Callers have already checked the players' consent and that the
+    /// session id is free. A match round is not registered: its match is.
+    #[allow(clippy::too_many_arguments)]
+    fn create_session(
+        env: &Env,
+        session_id: u32,
+        gardener: Address,
+        creature: Address,
+        gardener_points: i128,
+        creature_points: i128,
+        rule_set_id: u32,
+        rules: RuleSet,
+        creature_kind: CreatureKind,
+        moon: MoonState,
+        match_id: Option<u32>,
+        wager: Option<Wager>,
+    ) -> Result<(), Error> {
+        let game_key = DataKey::Session(session_id);
+        // Snapshot the wager fee, so later changes do not apply to this game
+        let wager_fee = match wager {
+            Some(_) => Self::get_wager_fee(env.clone()),
+            None => None,
+        };
+
+        // Call Game Hub to start the session and lock points
+        // CRITICAL: Call Game Hub BEFORE creating the session
+        if match_id.is_none() {
+            Self::start_on_hub(
+                env,
+                session_id,
+                &gardener,
+                &creature,
+                gardener_points,
+                creature_points,
+            )?;
+        }
+
+        // The base moon, unless the players draw it by commit-reveal
+        let (moon_phase, moon_clock) = match moon {
+            MoonState::Fixed => (Self::moon_at(&rules, 0), 0),
+            _ => (MoonPhase::Balanced, 0),
+        };
+
+        // Calculate starting HP based on creature kind and moon phase
+        let creature_hp = match moon_phase {
+            MoonPhase::FullMoon => creature_kind.starting_hp(&rules) + rules.full_moon_bonus_hp,
+            _ => creature_kind.starting_hp(&rules),
+        };
+
+        // Create game session
+        let session = GameSession {
+            session_id,
+            gardener: gardener.clone(),
+            creature: creature.clone(),
+            gardener_points,
+            creature_points,
+            garden_commitment: BytesN::from_array(env, &[0u8; 32]),
+            garden_proven: false,
+            creature_kind,
+            creature_x: rules.board_width / 2, // Center of top row
+            creature_y: 0,                     // Starting position (outside board)
+            creature_hp,
+            pending_reveals: 0,
+            phase: GamePhase::WaitingForCommitment,
+            moon_phase,
+            moon,
+            moon_clock,
+            revealed_cells: Vec::new(env),
+            consumed_plants: Vec::new(env),
+            turn_number: 0,
+            damage_reduction: 0,
+            damage_dealt: 0,
+            last_action_ledger: env.ledger().sequence(),
+            sense: SenseState::None,
+            rule_set_id,
+            rule_set_hash: Self::rule_set_hash(env, &rules),
+            rules,
+            match_id,
+            winner: None,
+            wager_fee_bps: wager_fee.as_ref().map_or(0, |fee| fee.fee_bps),
+            wager_treasury: wager_fee.map(|fee| fee.treasury),
+            wager_amount: wager.as_ref().map_or(0, |w| w.amount),
+            wager_token: wager.map(|w| w.token),
+            cancel_proposed_by: None,
+            path: Vec::new(env),
+            reveals: Vec::new(env),
+        };
+
+        // Store game in temporary storage with 30-day TTL
+        env.storage().temporary().set(&game_key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        // The Game Hub also emits GameStarted
+        SessionCreated {
+            session_id,
+            gardener,
+            creature,
+            creature_kind,
+            rule_set_id,
+            moon_phase: session.moon_phase,
+            creature_hp,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Lock both players' points with the Game Hub for `session_id`
+    fn start_on_hub(
+        env: &Env,
+        session_id: u32,
+        player1: &Address,
+        player2: &Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::NotInitialized)?;
+
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            player1,
+            player2,
+            &player1_points,
+            &player2_points,
+        );
+        Ok(())
+    }
+
+    /// Create the session of the match's current round: player1 gardens in
+    /// odd rounds and player2 in even ones
+    fn start_match_round(env: &Env, m: &mut Match, rules: RuleSet) -> Result<(), Error> {
+        let (gardener, creature) = if m.round % 2 == 1 {
+            (&m.player1, &m.player2)
+        } else {
+            (&m.player2, &m.player1)
+        };
+        m.session_id = Self::allocate_session_id(env);
+        Self::create_session(
+            env,
+            m.session_id,
+            gardener.clone(),
+            creature.clone(),
+            m.points,
+            m.points,
+            m.rule_set_id,
+            rules,
+            m.creature_kind,
+            MoonState::Fixed,
+            Some(m.match_id),
+            None,
+        )?;
+
+        MatchRoundStarted {
+            match_id: m.match_id,
+            round: m.round,
+            session_id: m.session_id,
+            gardener: gardener.clone(),
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Score a finished round. The match ends on the Game Hub once a player
+    /// has won a majority of `best_of`; otherwise the next round starts with
+    /// the roles swapped.
+    fn advance_match(env: &Env, match_id: u32, winner: &Address) -> Result<(), Error> {
+        let key = DataKey::Match(match_id);
+        let mut m: Match = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::MatchNotFound)?;
+
+        let player1_won = *winner == m.player1;
+        if player1_won {
+            m.player1_wins += 1;
+        } else {
+            m.player2_wins += 1;
+        }
+
+        let majority = m.best_of / 2 + 1;
+        if m.player1_wins >= majority || m.player2_wins >= majority {
+            m.finished = true;
+
+            let game_hub_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::GameHubAddress)
+                .ok_or(Error::NotInitialized)?;
+            GameHubClient::new(env, &game_hub_addr).end_game(&match_id, &player1_won);
+
+            MatchFinished {
+                match_id,
+                winner: winner.clone(),
+                player1_wins: m.player1_wins,
+                player2_wins: m.player2_wins,
+            }
+            .publish(env);
+        } else {
+            // Rules are re-read from the registry, which never changes a registered set
+            let rules: RuleSet = env
+                .storage()
+                .instance()
+                .get(&DataKey::RuleSet(m.rule_set_id))
+                .ok_or(Error::RuleSetNotFound)?;
+            m.round += 1;
+            Self::start_match_round(env, &mut m, rules)?;
+        }
+
+        env.storage().temporary().set(&key, &m);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Next session id from the contract's counter (starting at 1), skipping
+    /// any id still held by a live session
+    fn allocate_session_id(env: &Env) -> u32 {
+        let storage = env.storage().instance();
+        let mut session_id: u32 = storage.get(&DataKey::NextSessionId).unwrap_or(1);
+        while env.storage().temporary().has(&DataKey::Session(session_id)) {
+            session_id = session_id.wrapping_add(1);
+        }
+        storage.set(&DataKey::NextSessionId, &session_id.wrapping_add(1));
+        session_id
+    }
+
+    fn load_challenge(env: &Env, challenge_id: u32) -> Option<Challenge> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Challenge(challenge_id))
+    }
+
+    fn is_expired(env: &Env, challenge: &Challenge) -> bool {
+        env.ledger().sequence() > challenge.expires_ledger
+    }
+
+    fn remove_challenge(env: &Env, challenge_id: u32) {
+        env.storage()
+            .temporary()
+            .remove(&DataKey::Challenge(challenge_id));
+    }
+
+    /// Mark a session finished and record its winner
+    fn finish(session: &mut GameSession, gardener_won: bool) {
+        session.phase = GamePhase::Finished;
+        session.winner = Some(if gardener_won {
+            session.gardener.clone()
+        } else {
+            session.creature.clone()
+        });
+    }
+
+    /// Record both players' statistics, publish GameFinished and report the
+    /// outcome to the Game Hub (Gardener is player1), or to the session's
+    /// match for a match round
+    fn end_game_on_hub(env: &Env, session: &GameSession, gardener_won: bool) -> Result<(), Error> {
+        Self::record_result(env, session, gardener_won);
+        Self::archive(env, session);
+
+        let winner = if gardener_won {
+            session.gardener.clone()
+        } else {
+            session.creature.clone()
+        };
+        GameFinished {
+            session_id: session.session_id,
+            winner: winner.clone(),
+            gardener_won,
+        }
+        .publish(env);
+
+        Self::pay_out_wager(env, session, &winner)?;
+
+        // A match round is scored by its match, which reports to the hub
+        if let Some(match_id) = session.match_id {
+            return Self::advance_match(env, match_id, &winner);
+        }
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .ok_or(Error::NotInitialized)?;
+
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        game_hub.end_game(&session.session_id, &gardener_won);
+        Ok(())
+    }
+
+    /// Whether `player` may cancel the session: it must be theirs, unfinished
+    /// and not a match round (rounds are settled by their match)
+    fn check_cancellable(session: &GameSession, player: &Address) -> Result<(), Error> {
+        if session.phase.is_over() {
+            return Err(Error::GameAlreadyFinished);
+        }
+        if session.match_id.is_some() {
+            return Err(Error::InvalidPhase);
+        }
+        if *player != session.gardener && *player != session.creature {
+            return Err(Error::NotAPlayer);
+        }
+        Ok(())
+    }
+
+    /// End a session with no result and refund any wager
+    fn cancel(env: &Env, mut session: GameSession) {
+        session.phase = GamePhase::Cancelled;
+        session.cancel_proposed_by = None;
+        session.last_action_ledger = env.ledger().sequence();
+
+        let key = DataKey::Session(session.session_id);
+        env.storage().temporary().set(&key, &session);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::archive(env, &session);
+
+        if let Some(wager_token) = &session.wager_token {
+            let token = token::TokenClient::new(env, wager_token);
+            let escrow = env.current_contract_address();
+            token.transfer(&escrow, &session.gardener, &session.wager_amount);
+            token.transfer(&escrow, &session.creature, &session.wager_amount);
+        }
+
+        GameCancelled {
+            session_id: session.session_id,
+        }
+        .publish(env);
+    }
+
+    /// Pay the escrowed stakes of a wager game to its winner, less the fee
+    fn pay_out_wager(env: &Env, session: &GameSession, winner: &Address) -> Result<(), Error> {
+        let Some(wager_token) = &session.wager_token else {
+            return Ok(());
+        };
+        let (pot, fee) = Self::wager_split(session.wager_amount, session.wager_fee_bps)
+            .ok_or(Error::InvalidWager)?;
+
+        let token = token::TokenClient::new(env, wager_token);
+        let escrow = env.current_contract_address();
+        if fee > 0 {
+            let treasury = session
+                .wager_treasury
+                .as_ref()
+                .ok_or(Error::NotInitialized)?;
+            token.transfer(&escrow, treasury, &fee);
+        }
+        token.transfer(&escrow, winner, &(pot - fee));
+
+        WagerPaid {
+            session_id: session.session_id,
+            winner: winner.clone(),
+            payout: pot - fee,
+            fee,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Pot of a wager of `amount` per player and the fee taken from it, or
+    /// `None` if either overflows
+    fn wager_split(amount: i128, fee_bps: u32) -> Option<(i128, i128)> {
+        let pot = amount.checked_mul(2)?;
+        let fee = pot.checked_mul(fee_bps as i128)? / 10_000;
+        Some((pot, fee))
+    }
+
+    /// Update both players' persistent statistics and role ratings
+    fn record_result(env: &Env, session: &GameSession, gardener_won: bool) {
+        let mut gardener = Self::load_stats(env, &session.gardener);
+        let mut creature = Self::load_stats(env, &session.creature);
+
+        gardener.gardener_games += 1;
+        creature.creature_games += 1;
+        if gardener_won {
+            gardener.gardener_wins += 1;
+            creature.creature_losses += 1;
+        } else {
+            gardener.gardener_losses += 1;
+            creature.creature_wins += 1;
+        }
+        gardener.damage_dealt += session.damage_dealt;
+        gardener.total_turns += session.turn_number;
+        creature.total_turns += session.turn_number;
+
+        // The Gardener is rated against the Creature's creature rating and vice versa
+        let (gardener_rating, creature_rating) = Self::elo_update(
+            gardener.gardener_rating,
+            creature.creature_rating,
+            gardener_won,
+        );
+        gardener.gardener_rating = gardener_rating;
+        creature.creature_rating = creature_rating;
+
+        for (player, stats) in [
+            (&session.gardener, &gardener),
+            (&session.creature, &creature),
+        ] {
+            let key = DataKey::PlayerStats(player.clone());
+            env.storage().persistent().set(&key, stats);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+        }
+    }
+
+    /// Store the persistent `GameRecord` of an ended session and append it to
+    /// both players' histories
+    fn archive(env: &Env, session: &GameSession) {
+        let record = GameRecord {
+            session_id: session.session_id,
+            gardener: session.gardener.clone(),
+            creature: session.creature.clone(),
+            creature_kind: session.creature_kind,
+            rule_set_id: session.rule_set_id,
+            moon_phase: Self::starting_moon(session),
+            path: session.path.clone(),
+            reveals: session.reveals.clone(),
+            winner: session.winner.clone(),
+            turn_count: session.turn_number,
+            ended_ledger: env.ledger().sequence(),
+            disclosed_cells: Map::new(env),
+        };
+        let storage = env.storage().persistent();
+        let key = DataKey::GameRecord(session.session_id);
+        storage.set(&key, &record);
+        storage.extend_ttl(&key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+
+        for player in [&session.gardener, &session.creature] {
+            let count_key = DataKey::PlayerGameCount(player.clone());
+            let count: u32 = storage.get(&count_key).unwrap_or(0);
+            let game_key = DataKey::PlayerGame(player.clone(), count);
+            storage.set(&game_key, &session.session_id);
+            storage.extend_ttl(&game_key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+            storage.set(&count_key, &(count + 1));
+            storage.extend_ttl(&count_key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+        }
+    }
+
+    /// Moon phase of a session's first turn: a cycling moon has moved on by
+    /// one phase per turn since then
+    fn starting_moon(session: &GameSession) -> MoonPhase {
+        let cycle_len = herbal_rules::RuleSet::from(&session.rules).moon_cycle_len();
+        if cycle_len == 0 {
+            return session.moon_phase.clone();
+        }
+        let turns = session.turn_number % cycle_len;
+        let moon_clock = (session.moon_clock + cycle_len - turns) % cycle_len;
+        Self::moon_at(&session.rules, moon_clock)
+    }
+
+    fn load_stats(env: &Env, player: &Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerStats(player.clone()))
+            .unwrap_or(PlayerStats {
+                gardener_games: 0,
+                gardener_wins: 0,
+                gardener_losses: 0,
+                creature_games: 0,
+                creature_wins: 0,
+                creature_losses: 0,
+                damage_dealt: 0,
+                total_turns: 0,
+                gardener_rating: INITIAL_RATING,
+                creature_rating: INITIAL_RATING,
+            })
+    }
+
+    /// New (a, b) ratings after a game between a and b, using an integer
+    /// approximation of the Elo expected score (K = ELO_K)
+    fn elo_update(a: u32, b: u32, a_won: bool) -> (u32, u32) {
+        // Expected score of the higher-rated player, interpolated from the table
+        let gap = a.abs_diff(b).min(800);
+        let (step, rest) = ((gap / 50) as usize, gap % 50);
+        let next = ELO_EXPECTED[(step + 1).min(ELO_EXPECTED.len() - 1)];
+        let high = ELO_EXPECTED[step] + (next - ELO_EXPECTED[step]) * rest / 50;
+        let expected_a = if a >= b { high } else { 1000 - high };
+
+        // a gains what b loses
+        if a_won {
+            let delta = ELO_K * (1000 - expected_a) / 1000;
+            (a + delta, b.saturating_sub(delta))
+        } else {
+            let delta = ELO_K * expected_a / 1000;
+            (a.saturating_sub(delta), b + delta)
+        }
+    }
+
+    /// Draw the moon of a commit-reveal session and apply it
+    fn fix_moon(env: &Env, session: &mut GameSession, draw: &MoonDraw) {
+        let seed_bytes = Self::moon_draw_seed(env, session.session_id, draw);
+        (session.moon_phase, session.moon_clock) =
+            Self::roll_moon(env, &seed_bytes, &session.rules);
+        session.moon = MoonState::Fixed;
+        if session.moon_phase == MoonPhase::FullMoon {
+            session.creature_hp += session.rules.full_moon_bonus_hp;
+        }
+        // The Creature's clock starts once it can move
+        if session.phase == GamePhase::Playing {
+            session.last_action_ledger = env.ledger().sequence();
+        }
+
+        MoonFixed {
+            session_id: session.session_id,
+            moon_phase: session.moon_phase.clone(),
+            creature_hp: session.creature_hp,
+        }
+        .publish(env);
+    }
+
+    /// Seed of a commit-reveal draw: the session id and reveal deadline, fixed
+    /// when the draw opened, then the revealed seeds (a missing seed counts as
+    /// zero). Nothing here depends on when or by whom the draw is settled.
+    fn moon_draw_seed(env: &Env, session_id: u32, draw: &MoonDraw) -> Bytes {
+        let missing = BytesN::from_array(env, &[0u8; 32]);
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(env, &draw.deadline_ledger.to_be_bytes()));
+        seed_bytes.append(&draw.gardener_seed.clone().unwrap_or(missing.clone()).into());
+        seed_bytes.append(&draw.creature_seed.clone().unwrap_or(missing).into());
+        seed_bytes
+    }
+
+    /// Map keccak256 of `seed_bytes` onto the rule set's moon chances, or
+    /// onto a starting point in the lunar cycle if the moon cycles.
+    /// Returns the moon phase and moon clock.
+    fn roll_moon(env: &Env, seed_bytes: &Bytes, rules: &RuleSet) -> (MoonPhase, u32) {
+        let hash = env.crypto().keccak256(seed_bytes).to_array();
+
+        let cycle_len = herbal_rules::RuleSet::from(rules).moon_cycle_len();
+        if cycle_len > 0 {
+            let moon_clock = u32::from_be_bytes([hash[1], hash[2], hash[3], hash[4]]) % cycle_len;
+            return (Self::moon_at(rules, moon_clock), moon_clock);
+        }
+
+        let roll = hash[0] as u32 % 100;
+        let moon_phase = if roll < rules.full_moon_chance {
+            MoonPhase::FullMoon
+        } else if roll < rules.full_moon_chance + rules.new_moon_chance {
+            MoonPhase::NewMoon
+        } else {
+            MoonPhase::Balanced
+        };
+        (moon_phase, 0)
+    }
+
+    /// Moon phase of a cycling moon at `moon_clock` (see `herbal_rules::MOON_CYCLE`)
+    fn moon_at(rules: &RuleSet, moon_clock: u32) -> MoonPhase {
+        herbal_rules::RuleSet::from(rules)
+            .moon_at(herbal_rules::MoonPhase::Balanced, moon_clock)
+            .into()
+    }
+
+    /// Look up a plant type in the shared rules table
+    fn plant_rule(plant_type: u32) -> Option<&'static herbal_rules::PlantRule> {
+        herbal_rules::plant_rule(u8::try_from(plant_type).ok()?)
+    }
+
+    /// sha256 of the canonical rule set encoding (see `herbal_rules::RuleSet::to_bytes`)
+    fn rule_set_hash(env: &Env, rules: &RuleSet) -> BytesN<32> {
+        let encoded = herbal_rules::RuleSet::from(rules).to_bytes();
+        env.crypto()
+            .sha256(&Bytes::from_array(env, &encoded))
+            .into()
+    }
+
+    /// Checks shared by every Gardener proof: the journal is for the committed
+    /// garden, matches `journal_hash`, is proven by `seal` for the image ID
+    /// stored at `image_key` (unless in dev mode), and is bound to this
+    /// session, gardener and rule set.
+    fn verify_reveal_journal(
+        env: &Env,
+        session: &GameSession,
+        session_id: u32,
+        journal_bytes: &Bytes,
+        journal_hash: &BytesN<32>,
+        seal: &Bytes,
+        image_key: &DataKey,
+    ) -> Result<(), Error> {
+        // Verify commitment in journal
+        let journal_commitment =
+            Self::extract_commitment(journal_bytes).ok_or(Error::CommitmentMismatch)?;
+
+        if journal_commitment != session.garden_commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        // Journal bytes must always match the hash bound by the proof
+        let computed_hash: BytesN<32> = env.crypto().sha256(journal_bytes).into();
+        if computed_hash != *journal_hash {
+            return Err(Error::ProofVerificationFailed);
+        }
+
+        // Verify proof based on mode
+        if seal.is_empty() {
+            // DEV MODE: only the journal hash check above applies.
+            // WARNING: No cryptographic security! Only for development.
+            if !Self::is_dev_mode(env.clone()) {
+                return Err(Error::ProofVerificationFailed);
+            }
+        } else {
+            // PRODUCTION MODE: Verify Groth16 proof via the verifier contract
+            let verifier_id: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::VerifierId)
+                .ok_or(Error::NotInitialized)?;
+            let image_id: BytesN<32> = env
+                .storage()
+                .instance()
+                .get(image_key)
+                .ok_or(Error::NotInitialized)?;
+
+            if !Self::verify_groth16_proof(env, &verifier_id, seal, &image_id, journal_hash) {
+                return Err(Error::ProofVerificationFailed);
+            }
+        }
+
+        // Verify the proof was generated for this session and this gardener,
+        // so a reveal can never be replayed into another game
+        let (journal_session_id, journal_gardener, journal_rules) =
+            Self::decode_journal_binding(journal_bytes).ok_or(Error::ProofVerificationFailed)?;
+        if journal_session_id != session_id {
+            return Err(Error::SessionMismatch);
+        }
+        if Some(journal_gardener) != Self::address_key(&session.gardener) {
+            return Err(Error::GardenerMismatch);
+        }
+        if journal_rules != session.rule_set_hash {
+            return Err(Error::RuleSetMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Apply one revealed cell of the pending move: check it is the next
+    /// traversed cell, deal its damage and publish `CellRevealed`.
+    /// Returns `Some(gardener_won)` once the game is over.
+    fn apply_reveal(
+        env: &Env,
+        session: &mut GameSession,
+        result: &mut CellRevealResult,
+    ) -> Result<Option<bool>, Error> {
+        // A cell already revealed (and any plant on it consumed) cannot be
+        // revealed again, e.g. by replaying an earlier turn's proof
+        let (width, height) = (session.rules.board_width, session.rules.board_height);
+        if result.x < width
+            && result.y < height
+            && session.revealed_cells.contains(result.y * width + result.x)
+        {
+            return Err(Error::CellAlreadyRevealed);
+        }
+
+        // Verify coordinates match the next traversed cell
+        // (the creature's current position once pending_reveals is 1)
+        let expected_y = session.creature_y + 1 - session.pending_reveals;
+        if result.x != session.creature_x || result.y != expected_y {
+            return Err(Error::InvalidCoordinates);
+        }
+        let cell_index = result.y * width + result.x;
+        session.pending_reveals -= 1;
+
+        // Mark cell as revealed
+        session.revealed_cells.push_back(cell_index);
+
+        // Apply damage if plant exists
+        if result.has_plant {
+            // Validate plant type is known to the rules table
+            let rule = Self::plant_rule(result.plant_type).ok_or(Error::ProofVerificationFailed)?;
+
+            // Contract computes damage from plant type (authoritative):
+            // moon-adjusted, minus the pending Calming Mist reduction, minimum 1.
+            // Lavender's calming mist then softens the next hit.
+            let (final_damage, reduction) = herbal_rules::hit(
+                rule.id,
+                (&session.moon_phase).into(),
+                session.damage_reduction,
+                &(&session.rules).into(),
+            );
+            session.damage_reduction = reduction;
+
+            result.damage_dealt = final_damage;
+            session.damage_dealt += final_damage;
+            session.creature_hp = session.creature_hp.saturating_sub(final_damage);
+            session.consumed_plants.push_back(cell_index);
+        }
+        session.reveals.push_back(result.clone());
+
+        // Check win conditions
+        let outcome = if session.creature_hp == 0 {
+            // Gardener wins - Creature dies
+            Self::finish(session, true);
+            Some(true)
+        } else if session.pending_reveals > 0 {
+            // Rest of a multi-row move still to be revealed
+            None
+        } else if session.creature_y >= session.rules.board_height - 1 {
+            // Creature wins - Reached the house (bottom row)
+            Self::finish(session, false);
+            Some(false)
+        } else {
+            // Game continues
+            session.phase = GamePhase::Playing;
+            None
+        };
+
+        CellRevealed {
+            session_id: session.session_id,
+            x: result.x,
+            y: result.y,
+            has_plant: result.has_plant,
+            plant_type: result.plant_type,
+            damage_dealt: result.damage_dealt,
+            creature_hp: session.creature_hp,
+        }
+        .publish(env);
+
+        Ok(outcome)
+    }
+
+    /// Extract garden commitment (first 32 bytes of journal)
+    fn extract_commitment(journal: &Bytes) -> Option<BytesN<32>> {
+        if journal.len() < 32 {
+            return None;
+        }
+        let mut arr = [0u8; 32];
+        journal.slice(0..32).copy_into_slice(&mut arr);
+        Some(BytesN::from_array(journal.env(), &arr))
     }
 
     /// Decode journal to extract cell reveal result
@@ -641,36 +3241,179 @@ impl HerbalMoonlight {
         })
     }
 
+    /// Decode a Spirit Sense journal
+    /// Layout: [commitment:32][kind:1][x:1][y:1][reading:2][session_id:4][gardener_pubkey:32]
+    ///         [rule_set_hash:32]
+    /// where reading is [left, right] for PeekAdjacent and [plants_ahead, 0] for SmellAhead
+    fn decode_sense_journal(journal: &Bytes) -> Option<SenseResult> {
+        if journal.len() != JOURNAL_LEN {
+            return None;
+        }
+
+        let kind = match journal.get(32)? {
+            0 => SenseKind::PeekAdjacent,
+            1 => SenseKind::SmellAhead,
+            _ => return None,
+        };
+        let x = journal.get(33)? as u32;
+        let y = journal.get(34)? as u32;
+        let (a, b) = (journal.get(35)?, journal.get(36)?);
+
+        Some(match kind {
+            SenseKind::PeekAdjacent => SenseResult {
+                kind,
+                x,
+                y,
+                left_has_plant: a != 0,
+                right_has_plant: b != 0,
+                plants_ahead: 0,
+            },
+            SenseKind::SmellAhead => SenseResult {
+                kind,
+                x,
+                y,
+                left_has_plant: false,
+                right_has_plant: false,
+                plants_ahead: a as u32,
+            },
+        })
+    }
+
+    /// Decode a No Path journal into (x, y, creature kind, moon phase, HP, moon clock)
+    /// Layout: [commitment:32][x:1][y:1][creature_kind:1][moon_phase:1][creature_hp:1]
+    ///         [moon_clock:1][session_id:4][gardener_pubkey:32][rule_set_hash:32]
+    fn decode_no_path_journal(
+        journal: &Bytes,
+    ) -> Option<(u32, u32, CreatureKind, MoonPhase, u32, u32)> {
+        if journal.len() != NO_PATH_JOURNAL_LEN {
+            return None;
+        }
+
+        let kind = match journal.get(34)? {
+            0 => CreatureKind::Ghost,
+            1 => CreatureKind::MoonMoth,
+            2 => CreatureKind::ShadowWolf,
+            _ => return None,
+        };
+        let moon_phase = match journal.get(35)? {
+            0 => MoonPhase::FullMoon,
+            1 => MoonPhase::NewMoon,
+            2 => MoonPhase::Balanced,
+            _ => return None,
+        };
+
+        Some((
+            journal.get(32)? as u32,
+            journal.get(33)? as u32,
+            kind,
+            moon_phase,
+            journal.get(36)? as u32,
+            journal.get(37)? as u32,
+        ))
+    }
+
+    /// Decode a batch reveal journal into its cells, in reveal order
+    /// Layout: [commitment:32][count:1][count x (x, y, has_plant, plant_type, damage)]
+    ///         [session_id:4][gardener_pubkey:32][rule_set_hash:32]
+    fn decode_batch_journal(journal: &Bytes) -> Option<Vec<CellRevealResult>> {
+        let count = journal.get(32)? as u32;
+        if count == 0 || journal.len() != 33 + count * BATCH_CELL_LEN + BINDING_LEN {
+            return None;
+        }
+
+        let mut cells = Vec::new(journal.env());
+        for i in 0..count {
+            let at = 33 + i * BATCH_CELL_LEN;
+            cells.push_back(CellRevealResult {
+                x: journal.get(at)? as u32,
+                y: journal.get(at + 1)? as u32,
+                has_plant: journal.get(at + 2)? != 0,
+                plant_type: journal.get(at + 3)? as u32,
+                damage_dealt: journal.get(at + 4)? as u32,
+            });
+        }
+        Some(cells)
+    }
+
+    /// Decode the session binding from the last BINDING_LEN bytes of the journal:
+    /// session_id (little-endian), gardener_pubkey and rule_set_hash. In the
+    /// fixed-size journals these are bytes 37..41, 41..73 and 73..105.
+    fn decode_journal_binding(journal: &Bytes) -> Option<(u32, BytesN<32>, BytesN<32>)> {
+        if journal.len() < 32 + BINDING_LEN {
+            return None;
+        }
+        let start = journal.len() - BINDING_LEN;
+
+        let mut session_id = [0u8; 4];
+        journal
+            .slice(start..start + 4)
+            .copy_into_slice(&mut session_id);
+        let mut pubkey = [0u8; 32];
+        journal
+            .slice(start + 4..start + 36)
+            .copy_into_slice(&mut pubkey);
+        let mut rules_hash = [0u8; 32];
+        journal
+            .slice(start + 36..start + 68)
+            .copy_into_slice(&mut rules_hash);
+
+        Some((
+            u32::from_le_bytes(session_id),
+            BytesN::from_array(journal.env(), &pubkey),
+            BytesN::from_array(journal.env(), &rules_hash),
+        ))
+    }
+
+    /// 32-byte key identifying an address in the journal:
+    /// the ed25519 public key for accounts, the contract hash for contracts
+    fn address_key(address: &Address) -> Option<BytesN<32>> {
+        match address.to_payload()? {
+            AddressPayload::AccountIdPublicKeyEd25519(key) => Some(key),
+            AddressPayload::ContractIdHash(hash) => Some(hash),
+        }
+    }
+
+    /// Decode a RiscZero Groth16 seal into its proof points
+    fn decode_seal(seal: &Bytes) -> Option<Groth16Proof> {
+        if seal.len() != SEAL_LEN {
+            return None;
+        }
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 128];
+        let mut c = [0u8; 64];
+        seal.slice(0..64).copy_into_slice(&mut a);
+        seal.slice(64..192).copy_into_slice(&mut b);
+        seal.slice(192..256).copy_into_slice(&mut c);
+
+        let env = seal.env();
+        Some(Groth16Proof {
+            a: BytesN::from_array(env, &a),
+            b: BytesN::from_array(env, &b),
+            c: BytesN::from_array(env, &c),
+        })
+    }
+
     /// Verify Groth16 proof against verifier contract
-    /// TODO: Implement when verifier contract is ready
-    #[allow(dead_code)]
+    ///
+    /// Any failure (malformed seal, verifier error or a `false` result)
+    /// is reported as an invalid proof.
     fn verify_groth16_proof(
-        _env: &Env,
-        _verifier_id: &Address,
-        _seal: &Bytes,
-        _image_id: &BytesN<32>,
-        _journal_hash: &BytesN<32>,
+        env: &Env,
+        verifier_id: &Address,
+        seal: &Bytes,
+        image_id: &BytesN<32>,
+        journal_hash: &BytesN<32>,
     ) -> bool {
-        // This function would call the Groth16 verifier contract
-        // using Protocol 25 BN254 primitives (CAP-0074)
-        //
-        // Example implementation pattern (pending verifier contract):
-        // let mut args: Vec<Val> = Vec::new(env);
-        // args.push_back(seal.into_val(env));
-        // args.push_back(image_id.into_val(env));
-        // args.push_back(journal_hash.into_val(env));
-        //
-        // match env.try_invoke_contract::<(), soroban_sdk::InvokeError>(
-        //     verifier_id,
-        //     &Symbol::new(env, "verify"),
-        //     args,
-        // ) {
-        //     Ok(Ok(())) => true,
-        //     _ => false,
-        // }
-
-        // Placeholder: return true for now
-        true
+        let proof = match Self::decode_seal(seal) {
+            Some(proof) => proof,
+            None => return false,
+        };
+
+        let verifier = Groth16VerifierClient::new(env, verifier_id);
+        matches!(
+            verifier.try_verify(&proof, image_id, journal_hash),
+            Ok(Ok(true))
+        )
     }
 }
 
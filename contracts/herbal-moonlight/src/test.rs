@@ -1,8 +1,21 @@
 #![cfg(test)]
 
-use crate::{GamePhase, HerbalMoonlight, HerbalMoonlightClient};
-use soroban_sdk::testutils::{Address as _, BytesN as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+extern crate std;
+
+use crate::{
+    CancelProposed, CellRevealed, ChallengeAccepted, CommitmentProof, CreatureKind, CreatureMoved,
+    DataKey, Disclosure, Error, GameCancelled, GameFinished, GamePhase, GameSession,
+    GardenCommitted, GardenDisclosed, HerbalMoonlight, HerbalMoonlightClient, MatchFinished,
+    MoonDraw, MoonFixed, MoonPhase, MoonState, PlayerResigned, PlayerRole, RuleSet, SenseKind,
+    SenseRequested, SenseResult, SenseRevealed, SenseState, SessionCreated, Wager, WagerPaid,
+    CHALLENGE_EXPIRY_LEDGERS, GAME_TTL_LEDGERS, TURN_TIMEOUT_LEDGERS,
+};
+use groth16_verifier::{Groth16VerifierContract, Groth16VerifierContractClient};
+use receipt_prover::ReceiptProver;
+use soroban_sdk::address_payload::AddressPayload;
+use soroban_sdk::testutils::{Address as _, BytesN as _, Events as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, xdr, Address, Bytes, BytesN, Env, Event};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -24,15 +37,43 @@ impl MockGameHub {
     ) {
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    /// Records the outcome so tests can assert who the hub was told won
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage().instance().set(&session_id, &player1_won);
     }
 }
 
+// ============================================================================
+// Groth16 Verifier for Unit Testing
+// ============================================================================
+
+/// Seed of the Groth16 setup the test verifier is initialized with
+const RECEIPT_SEED: u64 = 0x4d4f_4f4e;
+
+std::thread_local! {
+    /// Prover for the key installed in the test verifier (deterministic per seed)
+    static PROVER: ReceiptProver = ReceiptProver::new(RECEIPT_SEED, [0x11; 32], [0x22; 32]);
+}
+
+/// Deploy a real Groth16 verifier, initialized with `PROVER`'s key
+fn deploy_verifier(env: &Env, admin: &Address) -> Address {
+    let verifier = env.register(Groth16VerifierContract, (admin,));
+    let vk = PROVER.with(|prover| prover.verification_key(env));
+    Groth16VerifierContractClient::new(env, &verifier).init_vk(admin, &vk);
+    verifier
+}
+
 // ============================================================================
 // Test Helpers
 // ============================================================================
 
-fn setup_test() -> (Env, HerbalMoonlightClient<'static>, Address, Address, Address) {
+fn setup_test() -> (
+    Env,
+    HerbalMoonlightClient<'static>,
+    Address,
+    Address,
+    Address,
+) {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -49,11 +90,13 @@ fn setup_test() -> (Env, HerbalMoonlightClient<'static>, Address, Address, Addre
 
     let admin = Address::generate(&env);
     let hub_addr = env.register(MockGameHub, ());
-    let verifier = Address::generate(&env);
     let image_id = BytesN::<32>::random(&env);
+    let verifier = deploy_verifier(&env, &admin);
 
     let contract_id = env.register(HerbalMoonlight, (&admin, &hub_addr, &verifier, &image_id));
     let client = HerbalMoonlightClient::new(&env, &contract_id);
+    // Most tests reveal with empty seals
+    client.set_dev_mode(&true);
 
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
@@ -61,18 +104,22 @@ fn setup_test() -> (Env, HerbalMoonlightClient<'static>, Address, Address, Addre
     (env, client, admin, player1, player2)
 }
 
-/// Build a 73-byte journal for dev mode verification
-/// Format: [commitment:32][x:1][y:1][has_plant:1][plant_type:1][damage:1][padding:36]
+/// Build a 105-byte journal for dev mode verification, bound to the standard rules
+/// Format: [commitment:32][x:1][y:1][has_plant:1][plant_type:1][damage:1][session_id:4][gardener_pubkey:32]
+///         [rule_set_hash:32]
+#[allow(clippy::too_many_arguments)]
 fn build_journal(
     env: &Env,
     commitment: &BytesN<32>,
+    session_id: u32,
+    gardener: &Address,
     x: u8,
     y: u8,
     has_plant: bool,
     plant_type: u8,
     damage: u8,
 ) -> Bytes {
-    let mut data = [0u8; 73];
+    let mut data = [0u8; 105];
     let commitment_arr = commitment.to_array();
     data[0..32].copy_from_slice(&commitment_arr);
     data[32] = x;
@@ -80,36 +127,209 @@ fn build_journal(
     data[34] = if has_plant { 1 } else { 0 };
     data[35] = plant_type;
     data[36] = damage;
+    data[37..41].copy_from_slice(&session_id.to_le_bytes());
+    data[41..73].copy_from_slice(&address_key(gardener).to_array());
+    data[73..105].copy_from_slice(&rules_hash(env, &herbal_rules::DEFAULT_RULES).to_array());
+    Bytes::from_slice(env, &data)
+}
+
+/// sha256 of a rule set's canonical encoding, as committed in the journal
+fn rules_hash(env: &Env, rules: &herbal_rules::RuleSet) -> BytesN<32> {
+    env.crypto()
+        .sha256(&Bytes::from_array(env, &rules.to_bytes()))
+        .into()
+}
+
+/// Replace the rule set hash a journal is bound to
+fn rebind_rules(env: &Env, journal: &Bytes, hash: &BytesN<32>) -> Bytes {
+    let mut data = [0u8; 105];
+    journal.copy_into_slice(&mut data);
+    data[73..105].copy_from_slice(&hash.to_array());
     Bytes::from_slice(env, &data)
 }
 
+/// 32-byte key of an address as committed in the journal
+fn address_key(address: &Address) -> BytesN<32> {
+    match address.to_payload().unwrap() {
+        AddressPayload::AccountIdPublicKeyEd25519(key) => key,
+        AddressPayload::ContractIdHash(hash) => hash,
+    }
+}
+
 /// Compute SHA256 of a garden layout (25 bytes) to get the commitment
 fn compute_commitment(env: &Env, garden: &[u8; 25]) -> BytesN<32> {
+    compute_commitment_of(env, garden)
+}
+
+/// Commitment of a garden layout of any board size
+fn compute_commitment_of(env: &Env, garden: &[u8]) -> BytesN<32> {
     let garden_bytes = Bytes::from_slice(env, garden);
     env.crypto().sha256(&garden_bytes).into()
 }
 
-/// Empty seal triggers dev mode in reveal_cell
+/// Empty seal, accepted while dev mode is on
 fn dev_seal(env: &Env) -> Bytes {
     Bytes::new(env)
 }
 
+/// Outcome reported to the mock hub: Some(true) if the Gardener (player1) won
+fn hub_result(env: &Env, client: &HerbalMoonlightClient, session_id: u32) -> Option<bool> {
+    env.as_contract(&client.get_hub(), || {
+        env.storage().instance().get(&session_id)
+    })
+}
+
+/// Advance the ledger sequence by `ledgers`
+fn advance_ledger(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+/// Groth16 seal of a receipt for guest `image_id` with `journal`
+fn receipt_seal(env: &Env, image_id: &BytesN<32>, journal: &Bytes) -> Bytes {
+    PROVER.with(|prover| prover.seal(env, image_id, journal))
+}
+
+/// Image ID the contract expects for single-cell reveals
+fn reveal_image_id(env: &Env, client: &HerbalMoonlightClient) -> BytesN<32> {
+    env.as_contract(&client.address, || {
+        env.storage().instance().get(&DataKey::ImageId).unwrap()
+    })
+}
+
 /// Start a game and commit a garden, returning the commitment.
 /// Creature starts at (2, 0), phase transitions to Playing.
 fn start_and_commit(
     env: &Env,
     client: &HerbalMoonlightClient,
-    session_id: u32,
     gardener: &Address,
     creature: &Address,
     garden: &[u8; 25],
 ) -> BytesN<32> {
-    client.start_game(&session_id, gardener, creature, &100i128, &100i128);
+    let session_id = client.start_game(
+        gardener,
+        creature,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     let commitment = compute_commitment(env, garden);
-    client.commit_garden(&session_id, &commitment);
+    client.commit_garden(&session_id, &commitment, &None, &None);
     commitment
 }
 
+/// Build a 105-byte Spirit Sense journal, bound to the standard rules
+/// Format: [commitment:32][kind:1][x:1][y:1][reading:2][session_id:4][gardener_pubkey:32]
+///         [rule_set_hash:32]
+#[allow(clippy::too_many_arguments)]
+fn build_sense_journal(
+    env: &Env,
+    commitment: &BytesN<32>,
+    session_id: u32,
+    gardener: &Address,
+    kind: SenseKind,
+    x: u8,
+    y: u8,
+    reading: [u8; 2],
+) -> Bytes {
+    let mut data = [0u8; 105];
+    data[0..32].copy_from_slice(&commitment.to_array());
+    data[32] = kind as u8;
+    data[33] = x;
+    data[34] = y;
+    data[35..37].copy_from_slice(&reading);
+    data[37..41].copy_from_slice(&session_id.to_le_bytes());
+    data[41..73].copy_from_slice(&address_key(gardener).to_array());
+    data[73..105].copy_from_slice(&rules_hash(env, &herbal_rules::DEFAULT_RULES).to_array());
+    Bytes::from_slice(env, &data)
+}
+
+/// The session id the next game will be allocated
+fn next_session_id(env: &Env, client: &HerbalMoonlightClient) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextSessionId)
+            .unwrap_or(1)
+    })
+}
+
+/// Start a game under the standard rules, commit `garden` and draw moon phase
+/// `moon` by commit-reveal, returning the session id and garden commitment
+fn start_and_commit_under_moon(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    gardener: &Address,
+    creature: &Address,
+    garden: &[u8; 25],
+    moon: MoonPhase,
+) -> (u32, BytesN<32>) {
+    // Search Gardener seeds for one that draws `moon` against the Creature's
+    let session_id = next_session_id(env, client);
+    let (creature_seed, creature_hash) = moon_seed(env, 0x22);
+    let fill = (0..=u8::MAX)
+        .find(|&fill| {
+            let draw = MoonDraw {
+                gardener_seed_hash: moon_seed(env, fill).1,
+                creature_seed_hash: creature_hash.clone(),
+                gardener_seed: Some(moon_seed(env, fill).0),
+                creature_seed: Some(creature_seed.clone()),
+                deadline_ledger: env.ledger().sequence() + TURN_TIMEOUT_LEDGERS,
+            };
+            let seed_bytes = HerbalMoonlight::moon_draw_seed(env, session_id, &draw);
+            HerbalMoonlight::roll_moon(env, &seed_bytes, &default_rules()).0 == moon
+        })
+        .unwrap();
+    let (gardener_seed, gardener_hash) = moon_seed(env, fill);
+
+    client.start_game(
+        gardener,
+        creature,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &Some(creature_hash),
+    );
+    let commitment = compute_commitment(env, garden);
+    client.commit_garden(&session_id, &commitment, &None, &Some(gardener_hash));
+    client.reveal_moon_seed(&session_id, gardener, &gardener_seed);
+    client.reveal_moon_seed(&session_id, creature, &creature_seed);
+    assert_eq!(client.get_session(&session_id).moon_phase, moon);
+    (session_id, commitment)
+}
+
+/// The standard rules (rule set 0) as the contract type
+fn default_rules() -> RuleSet {
+    herbal_rules::DEFAULT_RULES.into()
+}
+
+/// Overwrite a stored session (to set up states that are slow to reach)
+fn update_session(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    session_id: u32,
+    f: impl FnOnce(&mut GameSession),
+) {
+    let mut session = client.get_session(&session_id);
+    f(&mut session);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .temporary()
+            .set(&DataKey::Session(session_id), &session);
+    });
+}
+
+/// Events published by the game contract during the last invocation
+fn game_events(env: &Env, client: &HerbalMoonlightClient) -> std::vec::Vec<xdr::ContractEvent> {
+    env.events()
+        .all()
+        .filter_by_contract(&client.address)
+        .events()
+        .to_vec()
+}
+
 /// Do a full turn: creature moves, then gardener reveals via dev mode journal
 fn do_turn(
     env: &Env,
@@ -124,16 +344,14 @@ fn do_turn(
 
     let cell = garden[(new_y * 5 + new_x) as usize];
     let has_plant = cell > 0;
-    let base_damage = match cell {
-        1 => 1,
-        2 => 2,
-        3 => 3,
-        _ => 0,
-    };
+    let base_damage = herbal_rules::base_damage(cell);
 
+    let gardener = client.get_session(&session_id).gardener;
     let journal = build_journal(
         env,
         commitment,
+        session_id,
+        &gardener,
         new_x as u8,
         new_y as u8,
         has_plant,
@@ -159,7 +377,15 @@ fn test_get_hub() {
 #[test]
 fn test_start_game_prevents_self_play() {
     let (_env, client, _admin, player1, _player2) = setup_test();
-    let result = client.try_start_game(&1u32, &player1, &player1, &100i128, &100i128);
+    let result = client.try_start_game(
+        &player1,
+        &player1,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     assert!(result.is_err());
 }
 
@@ -181,7 +407,15 @@ fn test_set_hub_requires_admin_auth() {
 #[test]
 fn test_start_game_success() {
     let (_env, client, _admin, player1, player2) = setup_test();
-    let result = client.try_start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    let result = client.try_start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     assert!(result.is_ok());
 
     let session = client.get_session(&1u32);
@@ -193,22 +427,49 @@ fn test_start_game_success() {
 }
 
 #[test]
-fn test_session_id_collision_prevented() {
+fn test_session_ids_allocated_by_contract() {
     let (_env, client, _admin, player1, player2) = setup_test();
-    let result1 = client.try_start_game(&1u32, &player1, &player2, &100i128, &100i128);
-    assert!(result1.is_ok());
+    let first = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    let second = client.start_game(
+        &player2,
+        &player1,
+        &200i128,
+        &200i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    assert_eq!((first, second), (1, 2));
 
-    let result2 = client.try_start_game(&1u32, &player2, &player1, &200i128, &200i128);
-    assert!(result2.is_err());
+    let session = client.get_session(&second);
+    assert_eq!(session.session_id, 2);
+    assert_eq!(session.gardener, player2);
+    assert_eq!(session.gardener_points, 200);
 }
 
 #[test]
 fn test_commit_garden() {
     let (env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
 
     let commitment = BytesN::<32>::random(&env);
-    let result = client.try_commit_garden(&1u32, &commitment);
+    let result = client.try_commit_garden(&1u32, &commitment, &None, &None);
     assert!(result.is_ok());
 
     let session = client.get_session(&1u32);
@@ -219,22 +480,38 @@ fn test_commit_garden() {
 #[test]
 fn test_commit_garden_wrong_phase() {
     let (env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
 
     let commitment = BytesN::<32>::random(&env);
-    client.commit_garden(&1u32, &commitment);
+    client.commit_garden(&1u32, &commitment, &None, &None);
 
     let commitment2 = BytesN::<32>::random(&env);
-    let result = client.try_commit_garden(&1u32, &commitment2);
+    let result = client.try_commit_garden(&1u32, &commitment2, &None, &None);
     assert!(result.is_err());
 }
 
 #[test]
 fn test_creature_move() {
     let (env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     let commitment = BytesN::<32>::random(&env);
-    client.commit_garden(&1u32, &commitment);
+    client.commit_garden(&1u32, &commitment, &None, &None);
 
     // Creature starts at (2, 0), move straight forward to (2, 1)
     let result = client.try_creature_move(&1u32, &2u32, &1u32);
@@ -249,9 +526,17 @@ fn test_creature_move() {
 #[test]
 fn test_creature_invalid_move() {
     let (env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     let commitment = BytesN::<32>::random(&env);
-    client.commit_garden(&1u32, &commitment);
+    client.commit_garden(&1u32, &commitment, &None, &None);
 
     // Move 2 rows forward (invalid)
     let result = client.try_creature_move(&1u32, &2u32, &2u32);
@@ -265,7 +550,15 @@ fn test_creature_invalid_move() {
 #[test]
 fn test_creature_move_wrong_phase() {
     let (_env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
 
     // Try to move before commitment (WaitingForCommitment phase)
     let result = client.try_creature_move(&1u32, &2u32, &1u32);
@@ -279,9 +572,17 @@ fn test_creature_move_wrong_phase() {
 #[test]
 fn test_first_move_any_column() {
     let (env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     let commitment = BytesN::<32>::random(&env);
-    client.commit_garden(&1u32, &commitment);
+    client.commit_garden(&1u32, &commitment, &None, &None);
 
     // First move from (2, 0): creature can go to ANY column in row 1
     // Move to far left column (0, 1) - x_diff=2, normally invalid
@@ -296,9 +597,17 @@ fn test_first_move_any_column() {
 #[test]
 fn test_first_move_far_right() {
     let (env, client, _admin, player1, player2) = setup_test();
-    client.start_game(&1u32, &player1, &player2, &100i128, &100i128);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
     let commitment = BytesN::<32>::random(&env);
-    client.commit_garden(&1u32, &commitment);
+    client.commit_garden(&1u32, &commitment, &None, &None);
 
     // First move to far right (4, 1) - x_diff=2
     let result = client.try_creature_move(&1u32, &4u32, &1u32);
@@ -314,7 +623,7 @@ fn test_second_move_restricted() {
 
     // Empty garden for easy reveals
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // First move: go to column 0
     do_turn(&env, &client, 1, 0, 1, &garden, &commitment);
@@ -337,14 +646,14 @@ fn test_reveal_commitment_mismatch() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let _commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let _commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // Creature moves
     client.creature_move(&1u32, &2u32, &1u32);
 
     // Build journal with WRONG commitment (different from on-chain)
     let wrong_commitment = BytesN::<32>::random(&env);
-    let journal = build_journal(&env, &wrong_commitment, 2, 1, false, 0, 0);
+    let journal = build_journal(&env, &wrong_commitment, 1, &player1, 2, 1, false, 0, 0);
     let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
     let seal = dev_seal(&env);
 
@@ -357,13 +666,13 @@ fn test_reveal_wrong_coordinates() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // Creature moves to (2, 1)
     client.creature_move(&1u32, &2u32, &1u32);
 
     // Build journal with WRONG coordinates (3, 1) instead of (2, 1)
-    let journal = build_journal(&env, &commitment, 3, 1, false, 0, 0);
+    let journal = build_journal(&env, &commitment, 1, &player1, 3, 1, false, 0, 0);
     let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
     let seal = dev_seal(&env);
 
@@ -376,11 +685,11 @@ fn test_reveal_invalid_journal_length() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let _commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let _commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     client.creature_move(&1u32, &2u32, &1u32);
 
-    // Build truncated journal (only 32 bytes instead of 73)
+    // Build truncated journal (only 32 bytes instead of 105)
     let short_data = [0u8; 32];
     let journal = Bytes::from_slice(&env, &short_data);
     let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
@@ -395,11 +704,11 @@ fn test_reveal_tampered_journal_hash() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     client.creature_move(&1u32, &2u32, &1u32);
 
-    let journal = build_journal(&env, &commitment, 2, 1, false, 0, 0);
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
     // Provide WRONG hash (random instead of sha256(journal))
     let wrong_hash = BytesN::<32>::random(&env);
     let seal = dev_seal(&env);
@@ -413,10 +722,10 @@ fn test_reveal_wrong_phase() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // Don't move creature - still in Playing phase, not WaitingForProof
-    let journal = build_journal(&env, &commitment, 2, 1, false, 0, 0);
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
     let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
     let seal = dev_seal(&env);
 
@@ -424,6 +733,202 @@ fn test_reveal_wrong_phase() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_reveal_replayed_from_other_session() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    // Two games using the same garden commitment
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+    client.creature_move(&2u32, &2u32, &1u32);
+
+    // Valid reveal for session 1 submitted to session 2
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let seal = dev_seal(&env);
+
+    let result = client.try_reveal_cell(&2u32, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::SessionMismatch)));
+
+    // Same proof is still accepted by the session it was generated for
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_reveal_wrong_gardener_pubkey() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    // Journal bound to the creature's key instead of the gardener's
+    let journal = build_journal(&env, &commitment, 1, &player2, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let seal = dev_seal(&env);
+
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::GardenerMismatch)));
+}
+
+#[test]
+fn test_reveal_unbound_journal_rejected() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    // Legacy journal with zeroed session_id / gardener_pubkey
+    let mut data = [0u8; 105];
+    data[0..32].copy_from_slice(&commitment.to_array());
+    data[32] = 2;
+    data[33] = 1;
+    let journal = Bytes::from_slice(&env, &data);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let seal = dev_seal(&env);
+
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::SessionMismatch)));
+}
+
+// ============================================================================
+// Reveal Cell - Production (Groth16) Path
+// ============================================================================
+
+#[test]
+fn test_reveal_with_valid_seal() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let seal = receipt_seal(&env, &reveal_image_id(&env, &client), &journal);
+
+    let result = client.reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert!(!result.has_plant);
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::Playing);
+}
+
+#[test]
+fn test_reveal_rejected_by_verifier() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    // Seal bound to a different journal -> pairing check fails
+    let other = build_journal(&env, &commitment, 1, &player1, 2, 1, true, 1, 1);
+    let seal = receipt_seal(&env, &reveal_image_id(&env, &client), &other);
+
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::ProofVerificationFailed)));
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::WaitingForProof);
+}
+
+#[test]
+fn test_reveal_empty_seal_rejected_without_dev_mode() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+
+    // The verifier is configured and dev mode is off: an empty seal is no proof
+    client.set_dev_mode(&false);
+    assert!(!client.is_dev_mode());
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result, Err(Ok(Error::ProofVerificationFailed)));
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::WaitingForProof);
+
+    client.set_dev_mode(&true);
+    client.reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::Playing);
+}
+
+#[test]
+fn test_dev_mode_off_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let hub = env.register(MockGameHub, ());
+    let image_id = BytesN::<32>::random(&env);
+    let verifier = deploy_verifier(&env, &admin);
+    let contract_id = env.register(HerbalMoonlight, (&admin, &hub, &verifier, &image_id));
+
+    assert!(!HerbalMoonlightClient::new(&env, &contract_id).is_dev_mode());
+}
+
+#[test]
+fn test_set_dev_mode_requires_admin_auth() {
+    let (env, client, _admin, _player1, _player2) = setup_test();
+    env.set_auths(&[]);
+
+    assert!(client.try_set_dev_mode(&false).is_err());
+    assert!(client.is_dev_mode());
+}
+
+#[test]
+fn test_reveal_malformed_seal() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    // Non-empty seal of the wrong length is never treated as dev mode
+    let seal = Bytes::from_slice(&env, &[1u8; 10]);
+
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::ProofVerificationFailed)));
+}
+
+#[test]
+fn test_reveal_verifier_error_is_proof_failure() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    // Point the contract at a verifier that has no key yet
+    let other_verifier = env.register(Groth16VerifierContract, (Address::generate(&env),));
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&crate::DataKey::VerifierId, &other_verifier);
+    });
+
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, false, 0, 0);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let seal = receipt_seal(&env, &reveal_image_id(&env, &client), &journal);
+
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::ProofVerificationFailed)));
+}
+
 // ============================================================================
 // Reveal Cell - Gameplay Tests
 // ============================================================================
@@ -434,7 +939,7 @@ fn test_reveal_empty_cell() {
 
     // All empty garden
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     let result = do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
 
@@ -460,7 +965,7 @@ fn test_reveal_mint_damage() {
         0, 0, 0, 0, 0, // row 3
         0, 0, 0, 0, 0, // row 4
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     let session_before = client.get_session(&1u32);
     let hp_before = session_before.creature_hp;
@@ -497,7 +1002,7 @@ fn test_reveal_mandrake_high_damage() {
         0, 0, 0, 0, 0,
         0, 0, 0, 0, 0,
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     let session_before = client.get_session(&1u32);
     let hp_before = session_before.creature_hp;
@@ -531,7 +1036,7 @@ fn test_reveal_lavender_calming_mist() {
         0, 0, 0, 0, 0,
         0, 0, 0, 0, 0,
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     let session = client.get_session(&1u32);
     let hp_start = session.creature_hp;
@@ -582,7 +1087,7 @@ fn test_calming_mist_persists_over_empty() {
         0, 0, 2, 0, 0, // Mint at (2,3)
         0, 0, 0, 0, 0,
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // Turn 1: Hit Lavender
     do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
@@ -624,7 +1129,8 @@ fn test_gardener_wins_creature_dies() {
         0, 0, 0, 0, 0,
         0, 0, 0, 0, 0,
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    next_session_id(&env, &client);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     let session = client.get_session(&1u32);
     let hp = session.creature_hp;
@@ -661,7 +1167,7 @@ fn test_creature_wins_reaches_row_4() {
 
     // All empty garden - creature walks through untouched
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // 4 turns to reach row 4: (2,1) -> (2,2) -> (2,3) -> (2,4)
     do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
@@ -671,6 +1177,7 @@ fn test_creature_wins_reaches_row_4() {
 
     let session = client.get_session(&1u32);
     assert_eq!(session.phase, GamePhase::Finished);
+    assert_eq!(session.winner, Some(player2));
     assert_eq!(session.creature_y, 4);
     // HP should be unchanged (no plants hit)
     let expected_hp = match session.moon_phase {
@@ -693,7 +1200,7 @@ fn test_full_game_with_damage() {
         0, 0, 2, 0, 0, // Mint at (2,3)
         0, 0, 0, 0, 0, // empty at (2,4)
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     let session = client.get_session(&1u32);
     let hp_start = session.creature_hp;
@@ -727,54 +1234,62 @@ fn test_full_game_with_damage() {
 // ============================================================================
 
 #[test]
-fn test_moon_phase_deterministic() {
-    let (_env, client, _admin, player1, player2) = setup_test();
-
-    // Same session_id always gives same moon phase
-    client.start_game(&42u32, &player1, &player2, &100i128, &100i128);
-    let s1 = client.get_session(&42u32);
+fn test_undrawn_moon_is_base_moon() {
+    let (env, client, _admin, player1, player2) = setup_test();
 
-    // Start another game with different players but same session_id won't work
-    // (collision prevented), so just verify the phase is one of the valid values
-    assert!(matches!(
-        s1.moon_phase,
-        crate::MoonPhase::FullMoon | crate::MoonPhase::NewMoon | crate::MoonPhase::Balanced
-    ));
+    // Without the commit-reveal draw nothing is rolled, whatever the ledger
+    for _ in 0..10 {
+        advance_ledger(&env, 1);
+        let session_id = client.start_game(
+            &player1,
+            &player2,
+            &100i128,
+            &100i128,
+            &0u32,
+            &CreatureKind::Ghost,
+            &None,
+        );
+        let s = client.get_session(&session_id);
+        assert_eq!(s.moon, MoonState::Fixed);
+        assert_eq!(s.moon_phase, MoonPhase::Balanced);
+        assert_eq!(s.creature_hp, 6);
+    }
 }
 
 #[test]
 fn test_full_moon_extra_hp() {
-    let (_env, client, _admin, player1, player2) = setup_test();
+    let (env, client, _admin, player1, player2) = setup_test();
 
-    // Try session IDs to find a Full Moon game
-    for id in 1..=200u32 {
-        let res = client.try_start_game(&id, &player1, &player2, &100i128, &100i128);
-        if res.is_ok() {
-            let s = client.get_session(&id);
-            if s.moon_phase == crate::MoonPhase::FullMoon {
-                assert_eq!(s.creature_hp, 8); // 6 + 2
-                return;
-            }
-        }
-    }
-    // If no Full Moon found in 200 tries, that's statistically unlikely but possible
-    // Don't panic - just skip
+    let garden = [0u8; 25];
+    let (session_id, _) = start_and_commit_under_moon(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        &garden,
+        MoonPhase::FullMoon,
+    );
+    let s = client.get_session(&session_id);
+    assert_eq!(s.moon_phase, MoonPhase::FullMoon);
+    assert_eq!(s.creature_hp, 8); // 6 + 2
 }
 
 #[test]
 fn test_new_moon_standard_hp() {
-    let (_env, client, _admin, player1, player2) = setup_test();
+    let (env, client, _admin, player1, player2) = setup_test();
 
-    for id in 1..=200u32 {
-        let res = client.try_start_game(&id, &player1, &player2, &100i128, &100i128);
-        if res.is_ok() {
-            let s = client.get_session(&id);
-            if s.moon_phase == crate::MoonPhase::NewMoon {
-                assert_eq!(s.creature_hp, 6); // standard
-                return;
-            }
-        }
-    }
+    let garden = [0u8; 25];
+    let (session_id, _) = start_and_commit_under_moon(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        &garden,
+        MoonPhase::NewMoon,
+    );
+    let s = client.get_session(&session_id);
+    assert_eq!(s.moon_phase, MoonPhase::NewMoon);
+    assert_eq!(s.creature_hp, 6); // standard
 }
 
 // ============================================================================
@@ -793,7 +1308,7 @@ fn test_multiple_turns_sequential() {
         0, 0, 0, 0, 0,
         0, 0, 0, 0, 0,
     ];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // Turn 1
     do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
@@ -824,7 +1339,7 @@ fn test_revealed_cells_tracked() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
     do_turn(&env, &client, 1, 3, 2, &garden, &commitment);
@@ -837,6 +1352,43 @@ fn test_revealed_cells_tracked() {
     assert_eq!(s.revealed_cells.get(1).unwrap(), 13);
 }
 
+#[test]
+fn test_consumed_plants_tracked() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 2; // (2,1) Mint
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
+    do_turn(&env, &client, 1, 2, 2, &garden, &commitment);
+
+    // Only the cell whose plant triggered is consumed
+    let s = client.get_session(&1u32);
+    assert_eq!(s.revealed_cells, soroban_sdk::vec![&env, 7u32, 12]);
+    assert_eq!(s.consumed_plants, soroban_sdk::vec![&env, 7u32]);
+}
+
+#[test]
+fn test_consumed_plant_cannot_damage_again() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 2; // (2,1) Mint
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
+    let hp = client.get_session(&1u32).creature_hp;
+
+    // The Gardener replays the Mint's proof against the next move
+    client.creature_move(&1u32, &2u32, &2u32);
+    let replay = reveal_at(&env, &client, 1, &garden, &commitment, 2, 1);
+    assert_eq!(replay, Err(Error::CellAlreadyRevealed));
+    let s = client.get_session(&1u32);
+    assert_eq!(s.creature_hp, hp);
+    assert_eq!(s.revealed_cells.len(), 1);
+    assert_eq!(s.phase, GamePhase::WaitingForProof);
+}
+
 // ============================================================================
 // Invalid Plant Type
 // ============================================================================
@@ -846,12 +1398,12 @@ fn test_reveal_invalid_plant_type() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     client.creature_move(&1u32, &2u32, &1u32);
 
     // Build journal claiming has_plant=true but plant_type=5 (invalid)
-    let journal = build_journal(&env, &commitment, 2, 1, true, 5, 1);
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, true, 5, 1);
     let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
     let seal = dev_seal(&env);
 
@@ -868,7 +1420,7 @@ fn test_cannot_move_after_game_finished() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // Play through to completion
     do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
@@ -888,7 +1440,7 @@ fn test_lateral_movement_at_edges() {
     let (env, client, _admin, player1, player2) = setup_test();
 
     let garden = [0u8; 25];
-    let commitment = start_and_commit(&env, &client, 1, &player1, &player2, &garden);
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
 
     // First move to left edge (column 0)
     do_turn(&env, &client, 1, 0, 1, &garden, &commitment);
@@ -902,3 +1454,2883 @@ fn test_lateral_movement_at_edges() {
     let result = client.try_creature_move(&1u32, &0u32, &2u32);
     assert!(result.is_ok());
 }
+
+// ============================================================================
+// Timeout / Forfeit Tests
+// ============================================================================
+
+#[test]
+fn test_timeout_waiting_for_commitment_creature_wins() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+
+    // Deadline not reached yet
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS);
+    let result = client.try_claim_timeout(&1u32);
+    assert_eq!(result, Err(Ok(Error::TimeoutNotReached)));
+
+    // Gardener never commits
+    assert_eq!(client.get_winner(&1u32), None);
+    advance_ledger(&env, 1);
+    client.claim_timeout(&1u32);
+
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::Finished);
+    assert_eq!(client.get_winner(&1u32), Some(player2));
+    assert_eq!(hub_result(&env, &client, 1), Some(false));
+}
+
+#[test]
+fn test_timeout_playing_gardener_wins() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
+
+    // Creature stops moving after the first reveal
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&1u32);
+
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::Finished);
+    assert_eq!(client.get_winner(&1u32), Some(player1));
+    assert_eq!(hub_result(&env, &client, 1), Some(true));
+}
+
+#[test]
+fn test_timeout_waiting_for_proof_creature_wins() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    // Gardener never reveals
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&1u32);
+
+    assert_eq!(client.get_session(&1u32).phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, 1), Some(false));
+}
+
+#[test]
+fn test_timeout_deadline_resets_on_action() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    // Creature moves just before the deadline, restarting the clock
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS);
+    client.creature_move(&1u32, &2u32, &1u32);
+    assert_eq!(
+        client.get_session(&1u32).last_action_ledger,
+        env.ledger().sequence()
+    );
+
+    advance_ledger(&env, 1);
+    let result = client.try_claim_timeout(&1u32);
+    assert_eq!(result, Err(Ok(Error::TimeoutNotReached)));
+    assert_eq!(hub_result(&env, &client, 1), None);
+}
+
+#[test]
+fn test_timeout_after_game_finished() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    do_turn(&env, &client, 1, 2, 1, &garden, &commitment);
+    do_turn(&env, &client, 1, 2, 2, &garden, &commitment);
+    do_turn(&env, &client, 1, 2, 3, &garden, &commitment);
+    do_turn(&env, &client, 1, 2, 4, &garden, &commitment);
+
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    let result = client.try_claim_timeout(&1u32);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyFinished)));
+    // Hub still holds the real result
+    assert_eq!(hub_result(&env, &client, 1), Some(false));
+}
+
+// ============================================================================
+// Spirit Sense Tests
+// ============================================================================
+
+#[test]
+fn test_peek_adjacent_costs_hp_on_balanced_moon() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let mut garden = [0u8; 25];
+    garden[5] = 2; // (0,1) Mint, left of the creature
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    do_turn(&env, &client, session_id, 1, 1, &garden, &commitment);
+
+    client.request_sense(&session_id, &SenseKind::PeekAdjacent);
+    let session = client.get_session(&session_id);
+    assert_eq!(session.phase, GamePhase::WaitingForSenseProof);
+    assert_eq!(session.creature_hp, 5);
+
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id,
+        &player1,
+        SenseKind::PeekAdjacent,
+        1,
+        1,
+        [1, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.reveal_sense(&session_id, &journal, &journal_hash, &dev_seal(&env));
+    assert!(result.left_has_plant);
+    assert!(!result.right_has_plant);
+
+    let session = client.get_session(&session_id);
+    assert_eq!(session.phase, GamePhase::Playing);
+    assert_eq!(session.sense, SenseState::Answered(result));
+    // Sensing does not reveal cells
+    assert_eq!(session.revealed_cells.len(), 1);
+}
+
+#[test]
+fn test_smell_ahead_free_on_full_moon() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let (session_id, commitment) = start_and_commit_under_moon(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        &garden,
+        MoonPhase::FullMoon,
+    );
+
+    client.request_sense(&session_id, &SenseKind::SmellAhead);
+    assert_eq!(client.get_session(&session_id).creature_hp, 8);
+
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id,
+        &player1,
+        SenseKind::SmellAhead,
+        2,
+        0,
+        [3, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.reveal_sense(&session_id, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result.kind, SenseKind::SmellAhead);
+    assert_eq!(result.plants_ahead, 3);
+}
+
+#[test]
+fn test_sense_not_allowed_on_new_moon() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let (session_id, _) = start_and_commit_under_moon(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        &garden,
+        MoonPhase::NewMoon,
+    );
+
+    let result = client.try_request_sense(&session_id, &SenseKind::PeekAdjacent);
+    assert_eq!(result, Err(Ok(Error::SenseNotAllowed)));
+    assert_eq!(client.get_session(&session_id).phase, GamePhase::Playing);
+}
+
+#[test]
+fn test_sense_cannot_spend_last_hp() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let garden = [0u8; 25];
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+    update_session(&env, &client, session_id, |s| s.creature_hp = 1);
+
+    let result = client.try_request_sense(&session_id, &SenseKind::SmellAhead);
+    assert_eq!(result, Err(Ok(Error::InsufficientHp)));
+}
+
+#[test]
+fn test_sense_wrong_phase() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let garden = [0u8; 25];
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    // Cannot sense while a cell proof is pending
+    client.creature_move(&session_id, &2u32, &1u32);
+    let result = client.try_request_sense(&session_id, &SenseKind::PeekAdjacent);
+    assert_eq!(result, Err(Ok(Error::InvalidPhase)));
+
+    // Cannot move while a sense proof is pending
+    update_session(&env, &client, session_id, |s| s.phase = GamePhase::Playing);
+    client.request_sense(&session_id, &SenseKind::PeekAdjacent);
+    let result = client.try_creature_move(&session_id, &2u32, &2u32);
+    assert_eq!(result, Err(Ok(Error::InvalidPhase)));
+}
+
+#[test]
+fn test_reveal_sense_kind_and_position_must_match() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    client.request_sense(&session_id, &SenseKind::PeekAdjacent);
+
+    // Answering a different sense
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id,
+        &player1,
+        SenseKind::SmellAhead,
+        2,
+        0,
+        [0, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.try_reveal_sense(&session_id, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result, Err(Ok(Error::SenseKindMismatch)));
+
+    // Answering from another cell
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id,
+        &player1,
+        SenseKind::PeekAdjacent,
+        3,
+        0,
+        [0, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.try_reveal_sense(&session_id, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result, Err(Ok(Error::InvalidCoordinates)));
+
+    // Replayed from another session
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id + 1,
+        &player1,
+        SenseKind::PeekAdjacent,
+        2,
+        0,
+        [0, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.try_reveal_sense(&session_id, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result, Err(Ok(Error::SessionMismatch)));
+
+    assert_eq!(
+        client.get_session(&session_id).phase,
+        GamePhase::WaitingForSenseProof
+    );
+}
+
+#[test]
+fn test_reveal_sense_with_valid_seal() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    client.request_sense(&session_id, &SenseKind::SmellAhead);
+
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id,
+        &player1,
+        SenseKind::SmellAhead,
+        2,
+        0,
+        [0, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let image_id = BytesN::<32>::random(&env);
+    let seal = receipt_seal(&env, &image_id, &journal);
+
+    // No image ID registered for this sense yet
+    let result = client.try_reveal_sense(&session_id, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+
+    // A receipt from the cell reveal guest does not pass as a sense proof
+    client.set_sense_image_id(&SenseKind::SmellAhead, &image_id);
+    let wrong = receipt_seal(&env, &reveal_image_id(&env, &client), &journal);
+    let result = client.try_reveal_sense(&session_id, &journal, &journal_hash, &wrong);
+    assert_eq!(result, Err(Ok(Error::ProofVerificationFailed)));
+
+    let result = client.reveal_sense(&session_id, &journal, &journal_hash, &seal);
+    assert_eq!(result.plants_ahead, 0);
+    assert_eq!(client.get_session(&session_id).phase, GamePhase::Playing);
+}
+
+#[test]
+fn test_timeout_waiting_for_sense_proof_creature_wins() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let garden = [0u8; 25];
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+    client.request_sense(&session_id, &SenseKind::PeekAdjacent);
+
+    // Gardener never answers the sense
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+
+    assert_eq!(client.get_session(&session_id).phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, session_id), Some(false));
+}
+
+// ============================================================================
+// Rules Conformance (herbal-rules table is the single source of truth)
+// ============================================================================
+
+#[test]
+fn test_reveal_damage_matches_rules_table() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    // Balanced moon: no damage modifier, so each plant deals its base damage
+    for rule in herbal_rules::PLANTS.iter() {
+        let session_id = next_session_id(&env, &client);
+
+        let mut garden = [0u8; 25];
+        garden[7] = rule.id; // (2,1)
+        let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+
+        let result = do_turn(&env, &client, session_id, 2, 1, &garden, &commitment);
+        assert_eq!(result.plant_type, rule.id as u32);
+        assert_eq!(result.damage_dealt, rule.base_damage as u32);
+
+        let expected_reduction = match rule.effect {
+            herbal_rules::PlantEffect::CalmingMist => herbal_rules::CALMING_MIST_REDUCTION as u32,
+            herbal_rules::PlantEffect::None => 0,
+        };
+        assert_eq!(
+            client.get_session(&session_id).damage_reduction,
+            expected_reduction
+        );
+    }
+}
+
+#[test]
+fn test_reveal_unknown_plant_id_rejected() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    // First id past the end of the rules table
+    let unknown = herbal_rules::PLANTS.len() as u8 + 1;
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, true, unknown, 9);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result, Err(Ok(Error::ProofVerificationFailed)));
+}
+
+// ============================================================================
+// Rule Set Tests
+// ============================================================================
+
+/// Always-Balanced rules with a fragile Creature and an expensive Spirit Sense
+fn custom_rules() -> RuleSet {
+    RuleSet {
+        creature_starting_hp: 3,
+        full_moon_chance: 0,
+        new_moon_chance: 0,
+        sense_hp_cost: 2,
+        ..default_rules()
+    }
+}
+
+#[test]
+fn test_default_rule_set_registered() {
+    let (_env, client, _admin, _player1, _player2) = setup_test();
+
+    assert_eq!(client.get_rule_set(&0u32), default_rules());
+    assert_eq!(
+        client.try_get_rule_set(&1u32),
+        Err(Ok(Error::RuleSetNotFound))
+    );
+}
+
+#[test]
+fn test_start_game_with_registered_rule_set() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    client.register_rule_set(&7u32, &custom_rules());
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &7u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+
+    let session = client.get_session(&1u32);
+    assert_eq!(session.rule_set_id, 7);
+    assert_eq!(session.rules, custom_rules());
+    assert_eq!(
+        session.rule_set_hash,
+        rules_hash(&env, &(&custom_rules()).into())
+    );
+    assert_eq!(session.moon_phase, MoonPhase::Balanced);
+    assert_eq!(session.creature_hp, 3);
+}
+
+#[test]
+fn test_start_game_unknown_rule_set() {
+    let (_env, client, _admin, player1, player2) = setup_test();
+
+    let result = client.try_start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &3u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::RuleSetNotFound)));
+}
+
+#[test]
+fn test_register_rule_set_rejects_invalid_and_duplicate() {
+    let (_env, client, _admin, _player1, _player2) = setup_test();
+
+    let too_many_plants = RuleSet {
+        max_plants: 21,
+        ..default_rules()
+    };
+    assert_eq!(
+        client.try_register_rule_set(&1u32, &too_many_plants),
+        Err(Ok(Error::InvalidRuleSet))
+    );
+
+    let too_big = RuleSet {
+        board_width: 10,
+        ..default_rules()
+    };
+    assert_eq!(
+        client.try_register_rule_set(&1u32, &too_big),
+        Err(Ok(Error::InvalidRuleSet))
+    );
+
+    let slow_moon = RuleSet {
+        moon_cycle_turns: 10,
+        ..default_rules()
+    };
+    assert_eq!(
+        client.try_register_rule_set(&1u32, &slow_moon),
+        Err(Ok(Error::InvalidRuleSet))
+    );
+
+    // Registered rule sets (including the standard one) are immutable
+    assert_eq!(
+        client.try_register_rule_set(&0u32, &custom_rules()),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_reveal_rejects_journal_for_other_rule_set() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    client.register_rule_set(&7u32, &custom_rules());
+    let mut garden = [0u8; 25];
+    garden[7] = 1; // (2,1) Lavender
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &7u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    let commitment = compute_commitment(&env, &garden);
+    client.commit_garden(&1u32, &commitment, &None, &None);
+    client.creature_move(&1u32, &2u32, &1u32);
+
+    // Proof generated under the standard rules
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, true, 1, 1);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.try_reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result, Err(Ok(Error::RuleSetMismatch)));
+
+    // Same reveal bound to the session's rules is accepted
+    let journal = rebind_rules(&env, &journal, &client.get_session(&1u32).rule_set_hash);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result.damage_dealt, 1);
+    assert_eq!(client.get_session(&1u32).creature_hp, 2);
+}
+
+#[test]
+fn test_rule_set_moon_modifiers_and_sense_cost() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    // Every drawn moon is a New Moon with +2 damage
+    let harsh = RuleSet {
+        full_moon_chance: 0,
+        new_moon_chance: 100,
+        new_moon_damage_bonus: 2,
+        ..default_rules()
+    };
+    client.register_rule_set(&1u32, &harsh);
+    let mut garden = [0u8; 25];
+    garden[7] = 1; // (2,1) Lavender
+    let (gardener_seed, gardener_hash) = moon_seed(&env, 0x11);
+    let (creature_seed, creature_hash) = moon_seed(&env, 0x22);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &1u32,
+        &CreatureKind::Ghost,
+        &Some(creature_hash),
+    );
+    let commitment = compute_commitment(&env, &garden);
+    client.commit_garden(&1u32, &commitment, &None, &Some(gardener_hash));
+    client.reveal_moon_seed(&1u32, &player1, &gardener_seed);
+    client.reveal_moon_seed(&1u32, &player2, &creature_seed);
+    assert_eq!(client.get_session(&1u32).moon_phase, MoonPhase::NewMoon);
+
+    client.creature_move(&1u32, &2u32, &1u32);
+    let journal = build_journal(&env, &commitment, 1, &player1, 2, 1, true, 1, 1);
+    let journal = rebind_rules(&env, &journal, &client.get_session(&1u32).rule_set_hash);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let result = client.reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+    assert_eq!(result.damage_dealt, 3);
+
+    // Balanced game under custom rules: the sense costs 2 of 3 HP
+    client.register_rule_set(&7u32, &custom_rules());
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &7u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    client.commit_garden(&2u32, &compute_commitment(&env, &[0u8; 25]), &None, &None);
+    client.request_sense(&2u32, &SenseKind::PeekAdjacent);
+    assert_eq!(client.get_session(&2u32).creature_hp, 1);
+}
+
+#[test]
+fn test_moon_cycles_each_turn() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    // The moon moves one phase per turn
+    let cycling = RuleSet {
+        moon_cycle_turns: 1,
+        ..default_rules()
+    };
+    client.register_rule_set(&1u32, &cycling);
+    let mut garden = [0u8; 25];
+    garden[7] = 3; // (2,1) Mandrake
+    garden[12] = 3; // (2,2) Mandrake
+    let session_id = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &1u32,
+        &CreatureKind::ShadowWolf,
+        &None,
+    );
+    let commitment = compute_commitment(&env, &garden);
+    client.commit_garden(&session_id, &commitment, &None, &None);
+
+    let start = client.get_session(&session_id);
+    assert!(start.moon_clock < 4);
+    let moon_at = |turn: u32| -> MoonPhase {
+        herbal_rules::MOON_CYCLE[((start.moon_clock + turn) % 4) as usize].into()
+    };
+    assert_eq!(start.moon_phase, moon_at(0));
+
+    // Each Mandrake hits under the moon of its own turn
+    let mut hp = start.creature_hp;
+    for turn in 1..=2u32 {
+        client.creature_move(&session_id, &2u32, &turn);
+        let session = client.get_session(&session_id);
+        assert_eq!(session.moon_clock, (start.moon_clock + turn) % 4);
+        assert_eq!(session.moon_phase, moon_at(turn));
+
+        let damage = match session.moon_phase {
+            MoonPhase::FullMoon => 2,
+            MoonPhase::NewMoon => 4,
+            MoonPhase::Balanced => 3,
+        };
+        let y = turn as u8;
+        let journal = build_journal(&env, &commitment, session_id, &player1, 2, y, true, 3, 3);
+        let journal = rebind_rules(&env, &journal, &session.rule_set_hash);
+        let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+        let result = client.reveal_cell(&session_id, &journal, &journal_hash, &dev_seal(&env));
+        assert_eq!(result.damage_dealt, damage);
+        hp -= damage;
+        assert_eq!(client.get_session(&session_id).creature_hp, hp);
+    }
+
+    // A no-path proof must follow the moon from the session's clock
+    let session = client.get_session(&session_id);
+    let clock = session.moon_clock as u8;
+    let state = (
+        2,
+        2,
+        CreatureKind::ShadowWolf,
+        session.moon_phase.clone(),
+        hp as u8,
+        clock,
+    );
+    let stale = (
+        2,
+        2,
+        CreatureKind::ShadowWolf,
+        session.moon_phase,
+        hp as u8,
+        (clock + 3) % 4,
+    );
+    let journal = build_no_path_journal(&env, &client, session_id, &commitment, stale);
+    let result = claim_no_path(&env, &client, session_id, &journal);
+    assert_eq!(result, Err(Error::CreatureStateMismatch));
+    let journal = build_no_path_journal(&env, &client, session_id, &commitment, state);
+    claim_no_path(&env, &client, session_id, &journal).unwrap();
+    assert_eq!(client.get_session(&session_id).phase, GamePhase::Finished);
+    // The archive records the moon of the first turn
+    assert_eq!(client.get_game_record(&session_id).moon_phase, moon_at(0));
+}
+
+#[test]
+fn test_larger_board_movement_and_win() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    // 7 columns, 9 rows: the Creature starts above column 3 and the house is row 8
+    let tall = RuleSet {
+        board_width: 7,
+        board_height: 9,
+        ..default_rules()
+    };
+    client.register_rule_set(&9u32, &tall);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &9u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    let garden = [0u8; 7 * 9];
+    let commitment = compute_commitment_of(&env, &garden);
+    client.commit_garden(&1u32, &commitment, &None, &None);
+    let rules_hash = client.get_session(&1u32).rule_set_hash;
+    assert_eq!(client.get_session(&1u32).creature_x, 3);
+
+    // Columns 0-6 exist, column 7 does not
+    assert_eq!(
+        client.try_creature_move(&1u32, &7u32, &1u32),
+        Err(Ok(Error::InvalidMove))
+    );
+
+    // Walk right to the edge, then straight down; row 4 is no longer the house
+    for (x, y) in [(4u32, 1u32), (5, 2), (6, 3), (6, 4), (6, 5), (6, 6), (6, 7)] {
+        client.creature_move(&1u32, &x, &y);
+        let journal = build_journal(
+            &env,
+            &commitment,
+            1,
+            &player1,
+            x as u8,
+            y as u8,
+            false,
+            0,
+            0,
+        );
+        let journal = rebind_rules(&env, &journal, &rules_hash);
+        let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+        client.reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+        assert_eq!(client.get_session(&1u32).phase, GamePhase::Playing);
+    }
+    assert_eq!(
+        client.get_session(&1u32).revealed_cells.last(),
+        Some(7 * 7 + 6)
+    );
+
+    client.creature_move(&1u32, &6u32, &8u32);
+    let journal = build_journal(&env, &commitment, 1, &player1, 6, 8, false, 0, 0);
+    let journal = rebind_rules(&env, &journal, &rules_hash);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    client.reveal_cell(&1u32, &journal, &journal_hash, &dev_seal(&env));
+
+    let session = client.get_session(&1u32);
+    assert_eq!(session.phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, 1), Some(false));
+}
+
+// ============================================================================
+// Creature Kind Tests
+// ============================================================================
+
+/// Start a Balanced-moon game as `kind` and commit `garden`
+fn start_creature_game(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    gardener: &Address,
+    creature: &Address,
+    kind: CreatureKind,
+    garden: &[u8; 25],
+) -> (u32, BytesN<32>) {
+    let session_id = next_session_id(env, client);
+    client.start_game(gardener, creature, &100i128, &100i128, &0u32, &kind, &None);
+    let commitment = compute_commitment(env, garden);
+    client.commit_garden(&session_id, &commitment, &None, &None);
+    (session_id, commitment)
+}
+
+/// Reveal (x, y) of `garden` for the pending move
+fn reveal_at(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    session_id: u32,
+    garden: &[u8; 25],
+    commitment: &BytesN<32>,
+    x: u8,
+    y: u8,
+) -> Result<crate::CellRevealResult, Error> {
+    let cell = garden[y as usize * 5 + x as usize];
+    let gardener = client.get_session(&session_id).gardener;
+    let damage = herbal_rules::base_damage(cell);
+    let journal = build_journal(
+        env,
+        commitment,
+        session_id,
+        &gardener,
+        x,
+        y,
+        cell > 0,
+        cell,
+        damage,
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    match client.try_reveal_cell(&session_id, &journal, &journal_hash, &dev_seal(env)) {
+        Ok(result) => Ok(result.unwrap()),
+        Err(err) => Err(err.unwrap()),
+    }
+}
+
+#[test]
+fn test_creature_kind_starting_hp() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+
+    for (kind, hp) in [
+        (CreatureKind::Ghost, 6),
+        (CreatureKind::MoonMoth, 4),
+        (CreatureKind::ShadowWolf, 9),
+    ] {
+        let (session_id, _) = start_creature_game(&env, &client, &player1, &player2, kind, &garden);
+        let session = client.get_session(&session_id);
+        assert_eq!(session.creature_kind, kind);
+        assert_eq!(session.creature_hp, hp);
+        // Free up the Balanced session id for the next kind
+        env.as_contract(&client.address, || {
+            env.storage()
+                .temporary()
+                .remove(&DataKey::Session(session_id));
+        });
+    }
+}
+
+#[test]
+fn test_ghost_cannot_skip_rows() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, _) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+
+    assert_eq!(
+        client.try_creature_move(&id, &2u32, &2u32),
+        Err(Ok(Error::InvalidMove))
+    );
+}
+
+#[test]
+fn test_moon_moth_flutter_reveals_every_traversed_cell() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 2; // (2,1) Mint
+    garden[15 + 2] = 1; // (2,3) Lavender
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::MoonMoth,
+        &garden,
+    );
+
+    client.creature_move(&id, &2u32, &1u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 2, 1).unwrap();
+    assert_eq!(client.get_session(&id).creature_hp, 2);
+
+    // Flutter (2,1) -> (2,3) over (2,2)
+    client.creature_move(&id, &2u32, &3u32);
+    let session = client.get_session(&id);
+    assert_eq!((session.creature_x, session.creature_y), (2, 3));
+    assert_eq!(session.pending_reveals, 2);
+
+    // The traversed cells come in order: (2,2) first, then (2,3)
+    let out_of_order = reveal_at(&env, &client, id, &garden, &commitment, 2, 3);
+    assert_eq!(out_of_order, Err(Error::InvalidCoordinates));
+    reveal_at(&env, &client, id, &garden, &commitment, 2, 2).unwrap();
+    let session = client.get_session(&id);
+    assert_eq!(session.phase, GamePhase::WaitingForProof);
+    assert_eq!(session.pending_reveals, 1);
+
+    let result = reveal_at(&env, &client, id, &garden, &commitment, 2, 3).unwrap();
+    assert_eq!(result.damage_dealt, 1);
+    let session = client.get_session(&id);
+    assert_eq!(session.phase, GamePhase::Playing);
+    assert_eq!(session.creature_hp, 1);
+    assert_eq!(
+        session.revealed_cells,
+        soroban_sdk::vec![&env, 7u32, 12, 17]
+    );
+}
+
+#[test]
+fn test_moon_moth_flutter_limits() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::MoonMoth,
+        &garden,
+    );
+
+    client.creature_move(&id, &2u32, &1u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 2, 1).unwrap();
+
+    // A Flutter goes straight ahead and at most 2 rows
+    assert_eq!(
+        client.try_creature_move(&id, &3u32, &3u32),
+        Err(Ok(Error::InvalidMove))
+    );
+    assert_eq!(
+        client.try_creature_move(&id, &2u32, &4u32),
+        Err(Ok(Error::InvalidMove))
+    );
+    // Single-row moves still allow a sideways step
+    client.creature_move(&id, &3u32, &2u32);
+}
+
+#[test]
+fn test_moon_moth_flutter_into_house_wins_after_both_reveals() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::MoonMoth,
+        &garden,
+    );
+
+    // Enter at row 2, then Flutter from row 2 into the house (row 4)
+    client.creature_move(&id, &0u32, &2u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 0, 1).unwrap();
+    reveal_at(&env, &client, id, &garden, &commitment, 0, 2).unwrap();
+    client.creature_move(&id, &0u32, &4u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 0, 3).unwrap();
+    assert_eq!(client.get_session(&id).phase, GamePhase::WaitingForProof);
+
+    reveal_at(&env, &client, id, &garden, &commitment, 0, 4).unwrap();
+    assert_eq!(client.get_session(&id).phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, id), Some(false));
+}
+
+#[test]
+fn test_moon_moth_dies_mid_flutter() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 1] = 3; // (1,1) Mandrake
+    garden[10 + 1] = 3; // (1,2) Mandrake
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::MoonMoth,
+        &garden,
+    );
+
+    client.creature_move(&id, &1u32, &2u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 1, 1).unwrap();
+    assert_eq!(client.get_session(&id).creature_hp, 1);
+
+    reveal_at(&env, &client, id, &garden, &commitment, 1, 2).unwrap();
+    let session = client.get_session(&id);
+    assert_eq!(session.creature_hp, 0);
+    assert_eq!(session.phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, id), Some(true));
+}
+
+#[test]
+fn test_shadow_wolf_moves_straight() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::ShadowWolf,
+        &garden,
+    );
+
+    // Entering the board may still pick any column
+    client.creature_move(&id, &4u32, &1u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 4, 1).unwrap();
+
+    assert_eq!(
+        client.try_creature_move(&id, &3u32, &2u32),
+        Err(Ok(Error::InvalidMove))
+    );
+    assert_eq!(
+        client.try_creature_move(&id, &4u32, &3u32),
+        Err(Ok(Error::InvalidMove))
+    );
+    client.creature_move(&id, &4u32, &2u32);
+}
+
+// ============================================================================
+// No Path Tests
+// ============================================================================
+
+/// Build a no-path journal bound to the session's rules
+/// Format: [commitment:32][x:1][y:1][creature_kind:1][moon_phase:1][creature_hp:1][moon_clock:1]
+///         [session_id:4][gardener_pubkey:32][rule_set_hash:32]
+fn build_no_path_journal(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    session_id: u32,
+    commitment: &BytesN<32>,
+    state: (u8, u8, CreatureKind, MoonPhase, u8, u8),
+) -> Bytes {
+    let (x, y, kind, moon_phase, hp, moon_clock) = state;
+    let session = client.get_session(&session_id);
+    let mut data = [0u8; 106];
+    data[0..32].copy_from_slice(&commitment.to_array());
+    data[32] = x;
+    data[33] = y;
+    data[34] = kind as u8;
+    data[35] = moon_phase as u8;
+    data[36] = hp;
+    data[37] = moon_clock;
+    data[38..42].copy_from_slice(&session_id.to_le_bytes());
+    data[42..74].copy_from_slice(&address_key(&session.gardener).to_array());
+    data[74..106].copy_from_slice(&session.rule_set_hash.to_array());
+    Bytes::from_slice(env, &data)
+}
+
+/// Submit a dev-mode no-path claim
+fn claim_no_path(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    session_id: u32,
+    journal: &Bytes,
+) -> Result<(), Error> {
+    let journal_hash: BytesN<32> = env.crypto().sha256(journal).into();
+    match client.try_claim_no_path(&session_id, journal, &journal_hash, &dev_seal(env)) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.unwrap()),
+    }
+}
+
+#[test]
+fn test_claim_no_path_gardener_wins() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 3; // (2,1) Mandrake
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::ShadowWolf,
+        &garden,
+    );
+    client.creature_move(&id, &2u32, &1u32);
+    reveal_at(&env, &client, id, &garden, &commitment, 2, 1).unwrap();
+
+    let state = (2, 1, CreatureKind::ShadowWolf, MoonPhase::Balanced, 6, 0);
+    let journal = build_no_path_journal(&env, &client, id, &commitment, state);
+    claim_no_path(&env, &client, id, &journal).unwrap();
+
+    let events = env.events().all().filter_by_contract(&client.address);
+    assert_eq!(
+        events.events().last(),
+        Some(
+            &GameFinished {
+                session_id: id,
+                winner: player1,
+                gardener_won: true,
+            }
+            .to_xdr(&env, &client.address)
+        )
+    );
+    assert_eq!(client.get_session(&id).phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, id), Some(true));
+}
+
+#[test]
+fn test_claim_no_path_must_match_creature_state() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+    let current = (2, 0, CreatureKind::Ghost, MoonPhase::Balanced, 6, 0);
+
+    for (state, error) in [
+        (
+            (1, 0, CreatureKind::Ghost, MoonPhase::Balanced, 6, 0),
+            Error::InvalidCoordinates,
+        ),
+        (
+            (2, 0, CreatureKind::MoonMoth, MoonPhase::Balanced, 6, 0),
+            Error::CreatureStateMismatch,
+        ),
+        (
+            (2, 0, CreatureKind::Ghost, MoonPhase::FullMoon, 6, 0),
+            Error::CreatureStateMismatch,
+        ),
+        (
+            (2, 0, CreatureKind::Ghost, MoonPhase::Balanced, 5, 0),
+            Error::CreatureStateMismatch,
+        ),
+        (
+            (2, 0, CreatureKind::Ghost, MoonPhase::Balanced, 6, 1),
+            Error::CreatureStateMismatch,
+        ),
+    ] {
+        let journal = build_no_path_journal(&env, &client, id, &commitment, state);
+        assert_eq!(claim_no_path(&env, &client, id, &journal), Err(error));
+    }
+
+    // Only on the Creature's turn
+    client.creature_move(&id, &2u32, &1u32);
+    let journal = build_no_path_journal(&env, &client, id, &commitment, current);
+    assert_eq!(
+        claim_no_path(&env, &client, id, &journal),
+        Err(Error::InvalidPhase)
+    );
+    assert_eq!(client.get_session(&id).phase, GamePhase::WaitingForProof);
+}
+
+#[test]
+fn test_claim_no_path_with_valid_seal() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+
+    let state = (2, 0, CreatureKind::Ghost, MoonPhase::Balanced, 6, 0);
+    let journal = build_no_path_journal(&env, &client, id, &commitment, state);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let image_id = BytesN::<32>::random(&env);
+    let seal = receipt_seal(&env, &image_id, &journal);
+
+    // No image ID registered for the no-path circuit yet
+    let result = client.try_claim_no_path(&id, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+
+    // The same verifier checks the no-path guest's receipts
+    client.set_no_path_image_id(&image_id);
+
+    client.claim_no_path(&id, &journal, &journal_hash, &seal);
+    assert_eq!(hub_result(&env, &client, id), Some(true));
+}
+
+// ============================================================================
+// Batch Reveal Tests
+// ============================================================================
+
+/// Build a batch reveal journal for `cells` of `garden`, bound to the standard rules
+/// Format: [commitment:32][count:1][count x (x, y, has_plant, plant_type, damage)]
+///         [session_id:4][gardener_pubkey:32][rule_set_hash:32]
+fn build_batch_journal(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    session_id: u32,
+    garden: &[u8; 25],
+    commitment: &BytesN<32>,
+    cells: &[(u8, u8)],
+) -> Bytes {
+    let gardener = client.get_session(&session_id).gardener;
+    let mut journal = Bytes::from_array(env, &commitment.to_array());
+    journal.push_back(cells.len() as u8);
+    for &(x, y) in cells {
+        let cell = garden[y as usize * 5 + x as usize];
+        let damage = herbal_rules::base_damage(cell);
+        journal.extend_from_array(&[x, y, (cell > 0) as u8, cell, damage]);
+    }
+    journal.extend_from_array(&session_id.to_le_bytes());
+    journal.extend_from_array(&address_key(&gardener).to_array());
+    journal.extend_from_array(&rules_hash(env, &herbal_rules::DEFAULT_RULES).to_array());
+    journal
+}
+
+/// Submit a dev-mode batch reveal
+fn reveal_batch(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    session_id: u32,
+    journal: &Bytes,
+) -> Result<soroban_sdk::Vec<crate::CellRevealResult>, Error> {
+    let journal_hash: BytesN<32> = env.crypto().sha256(journal).into();
+    match client.try_reveal_cells(&session_id, journal, &journal_hash, &dev_seal(env)) {
+        Ok(results) => Ok(results.unwrap()),
+        Err(err) => Err(err.unwrap()),
+    }
+}
+
+#[test]
+fn test_reveal_cells_settles_flutter_in_one_call() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 2; // (2,1) Mint
+    garden[15 + 2] = 1; // (2,3) Lavender
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::MoonMoth,
+        &garden,
+    );
+
+    client.creature_move(&id, &2u32, &1u32);
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(2, 1)]);
+    reveal_batch(&env, &client, id, &journal).unwrap();
+    client.creature_move(&id, &2u32, &3u32);
+
+    // Both traversed cells of the Flutter in one proof
+    let cells = [(2, 2), (2, 3)];
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &cells);
+    let results = reveal_batch(&env, &client, id, &journal).unwrap();
+    let events = game_events(&env, &client);
+    assert_eq!(results.len(), 2);
+    assert!(!results.get(0).unwrap().has_plant);
+    assert_eq!(results.get(1).unwrap().damage_dealt, 1);
+    assert_eq!(
+        events,
+        std::vec![
+            CellRevealed {
+                session_id: id,
+                x: 2,
+                y: 2,
+                has_plant: false,
+                plant_type: 0,
+                damage_dealt: 0,
+                creature_hp: 2,
+            }
+            .to_xdr(&env, &client.address),
+            CellRevealed {
+                session_id: id,
+                x: 2,
+                y: 3,
+                has_plant: true,
+                plant_type: 1,
+                damage_dealt: 1,
+                creature_hp: 1,
+            }
+            .to_xdr(&env, &client.address),
+        ]
+    );
+
+    let session = client.get_session(&id);
+    assert_eq!(session.phase, GamePhase::Playing);
+    assert_eq!(session.pending_reveals, 0);
+    assert_eq!(session.creature_hp, 1);
+    assert_eq!(
+        session.revealed_cells,
+        soroban_sdk::vec![&env, 7u32, 12, 17]
+    );
+}
+
+#[test]
+fn test_reveal_cells_must_follow_pending_move() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 3; // (2,1) Mandrake
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+    client.creature_move(&id, &2u32, &1u32);
+
+    for (cells, error) in [
+        (&[(2, 2)][..], Error::InvalidCoordinates),
+        // Only one cell is pending after a single-row move
+        (&[(2, 1), (2, 2)][..], Error::InvalidCoordinates),
+        (&[][..], Error::ProofVerificationFailed),
+    ] {
+        let journal = build_batch_journal(&env, &client, id, &garden, &commitment, cells);
+        assert_eq!(reveal_batch(&env, &client, id, &journal), Err(error));
+    }
+
+    // A count that disagrees with the journal length is malformed
+    let mut journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(2, 1)]);
+    journal.set(32, 2);
+    assert_eq!(
+        reveal_batch(&env, &client, id, &journal),
+        Err(Error::ProofVerificationFailed)
+    );
+
+    // A failed batch applies nothing
+    let session = client.get_session(&id);
+    assert_eq!(session.phase, GamePhase::WaitingForProof);
+    assert_eq!(session.creature_hp, 6);
+    assert!(session.revealed_cells.is_empty());
+}
+
+#[test]
+fn test_reveal_cells_cannot_continue_past_death() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 1] = 3; // (1,1) Mandrake
+    garden[10 + 1] = 3; // (1,2) Mandrake
+    garden[15 + 1] = 3; // (1,3) Mandrake
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::MoonMoth,
+        &garden,
+    );
+    client.creature_move(&id, &1u32, &1u32);
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(1, 1)]);
+    reveal_batch(&env, &client, id, &journal).unwrap();
+    client.creature_move(&id, &1u32, &3u32);
+
+    // The Moth dies on (1,2); (1,3) is never reached
+    let cells = [(1, 2), (1, 3)];
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &cells);
+    assert_eq!(
+        reveal_batch(&env, &client, id, &journal),
+        Err(Error::InvalidCoordinates)
+    );
+
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(1, 2)]);
+    reveal_batch(&env, &client, id, &journal).unwrap();
+    assert_eq!(client.get_session(&id).phase, GamePhase::Finished);
+    assert_eq!(hub_result(&env, &client, id), Some(true));
+}
+
+#[test]
+fn test_reveal_cells_with_valid_seal() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+    client.creature_move(&id, &2u32, &1u32);
+
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(2, 1)]);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let image_id = BytesN::<32>::random(&env);
+    let seal = receipt_seal(&env, &image_id, &journal);
+
+    // No image ID registered for the batch reveal circuit yet
+    let result = client.try_reveal_cells(&id, &journal, &journal_hash, &seal);
+    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+
+    // The same verifier checks the batch reveal guest's receipts
+    client.set_batch_reveal_image_id(&image_id);
+
+    client.reveal_cells(&id, &journal, &journal_hash, &seal);
+    assert_eq!(client.get_session(&id).phase, GamePhase::Playing);
+}
+
+// ============================================================================
+// Disclosure Tests
+// ============================================================================
+
+/// Start a 5x5 game committed to sha256(width || height || garden || salt)
+/// and let the Creature forfeit, returning the finished session's id and commitment
+fn finished_game(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    gardener: &Address,
+    creature: &Address,
+    garden: &[u8; 25],
+    salt: &[u8; 16],
+) -> (u32, BytesN<32>) {
+    let session_id = next_session_id(env, client);
+    client.start_game(
+        gardener,
+        creature,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    let mut preimage = std::vec![5u8, 5];
+    preimage.extend_from_slice(garden);
+    preimage.extend_from_slice(salt);
+    let commitment = compute_commitment_of(env, &preimage);
+    client.commit_garden(&session_id, &commitment, &None, &None);
+    advance_ledger(env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+    (session_id, commitment)
+}
+
+#[test]
+fn test_disclose_full_garden() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    // Sessions live for GAME_TTL_LEDGERS, not the test default
+    env.ledger().with_mut(|li| li.min_temp_entry_ttl = 16);
+    let mut garden = [0u8; 25];
+    garden[7] = 3; // (2,1) Mandrake
+    garden[16] = 1; // (1,3) Lavender
+    let salt = [5u8; 16];
+    let (id, _) = finished_game(&env, &client, &player1, &player2, &garden, &salt);
+
+    // The salt and the board dimensions are part of the preimage
+    let cells = Bytes::from_array(&env, &garden);
+    let wrong_salt = Disclosure::Garden(cells.clone(), BytesN::from_array(&env, &[6u8; 16]));
+    assert_eq!(
+        client.try_disclose(&id, &wrong_salt),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    let short = Disclosure::Garden(cells.slice(0..20), BytesN::from_array(&env, &salt));
+    assert_eq!(
+        client.try_disclose(&id, &short),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+
+    client.disclose(
+        &id,
+        &Disclosure::Garden(cells, BytesN::from_array(&env, &salt)),
+    );
+    assert_eq!(
+        game_events(&env, &client),
+        std::vec![GardenDisclosed {
+            session_id: id,
+            disclosed_cells: 25,
+        }
+        .to_xdr(&env, &client.address)]
+    );
+
+    let disclosed = client.get_game_record(&id).disclosed_cells;
+    assert_eq!(disclosed.len(), 25);
+    assert_eq!(disclosed.get(7), Some(3));
+    assert_eq!(disclosed.get(16), Some(1));
+    assert_eq!(disclosed.get(0), Some(0));
+
+    // The disclosure outlives the session
+    advance_ledger(&env, GAME_TTL_LEDGERS + 1);
+    assert_eq!(client.try_get_session(&id), Err(Ok(Error::SessionNotFound)));
+    assert_eq!(client.get_game_record(&id).disclosed_cells, disclosed);
+}
+
+#[test]
+fn test_disclose_chosen_cells_with_proof() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[7] = 3; // (2,1) Mandrake
+    garden[16] = 1; // (1,3) Lavender
+    let (id, commitment) = finished_game(&env, &client, &player1, &player2, &garden, &[0u8; 16]);
+
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(2, 1)]);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    client.disclose(
+        &id,
+        &Disclosure::Cells(journal, journal_hash, dev_seal(&env)),
+    );
+
+    // A later disclosure adds to the earlier one
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(1, 3), (0, 0)]);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    client.disclose(
+        &id,
+        &Disclosure::Cells(journal, journal_hash, dev_seal(&env)),
+    );
+
+    let disclosed = client.get_game_record(&id).disclosed_cells;
+    assert_eq!(
+        disclosed,
+        soroban_sdk::map![&env, (0u32, 0u32), (7, 3), (16, 1)]
+    );
+
+    // The proof must be for the committed garden
+    let other = BytesN::from_array(&env, &[9u8; 32]);
+    let journal = build_batch_journal(&env, &client, id, &garden, &other, &[(0, 1)]);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let disclosure = Disclosure::Cells(journal, journal_hash, dev_seal(&env));
+    assert_eq!(
+        client.try_disclose(&id, &disclosure),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+}
+
+#[test]
+fn test_disclose_only_after_game_finished() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let garden = [0u8; 25];
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+
+    let journal = build_batch_journal(&env, &client, id, &garden, &commitment, &[(2, 1)]);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let disclosure = Disclosure::Cells(journal, journal_hash, dev_seal(&env));
+    assert_eq!(
+        client.try_disclose(&id, &disclosure),
+        Err(Ok(Error::InvalidPhase))
+    );
+    assert_eq!(
+        client.try_get_game_record(&id),
+        Err(Ok(Error::GameRecordNotFound))
+    );
+}
+
+// ============================================================================
+// Commitment Proof Tests
+// ============================================================================
+
+/// Build a garden validity journal bound to the standard rules
+/// Format: [commitment:32][session_id:4][gardener_pubkey:32][rule_set_hash:32]
+fn build_commitment_journal(
+    env: &Env,
+    commitment: &BytesN<32>,
+    session_id: u32,
+    gardener: &Address,
+) -> Bytes {
+    let mut data = [0u8; 100];
+    data[0..32].copy_from_slice(&commitment.to_array());
+    data[32..36].copy_from_slice(&session_id.to_le_bytes());
+    data[36..68].copy_from_slice(&address_key(gardener).to_array());
+    data[68..100].copy_from_slice(&rules_hash(env, &herbal_rules::DEFAULT_RULES).to_array());
+    Bytes::from_slice(env, &data)
+}
+
+/// Wrap a journal in a dev-mode CommitmentProof
+fn commitment_proof(env: &Env, journal: Bytes) -> Option<CommitmentProof> {
+    Some(CommitmentProof {
+        journal_hash: env.crypto().sha256(&journal).into(),
+        journal_bytes: journal,
+        seal: dev_seal(env),
+    })
+}
+
+#[test]
+fn test_commit_garden_with_validity_proof() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let commitment = compute_commitment(&env, &[0u8; 25]);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+
+    let journal = build_commitment_journal(&env, &commitment, 1, &player1);
+    client.commit_garden(&1u32, &commitment, &commitment_proof(&env, journal), &None);
+
+    let session = client.get_session(&1u32);
+    assert_eq!(session.phase, GamePhase::Playing);
+    assert!(session.garden_proven);
+
+    // Without a proof the commitment is taken on trust
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    client.commit_garden(&2u32, &commitment, &None, &None);
+    assert!(!client.get_session(&2u32).garden_proven);
+}
+
+#[test]
+fn test_commit_garden_rejects_unopenable_commitment() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let commitment = compute_commitment(&env, &[0u8; 25]);
+    let other = BytesN::from_array(&env, &[9u8; 32]);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+
+    let short = build_commitment_journal(&env, &commitment, 1, &player1).slice(0..99);
+    for (journal, error) in [
+        (
+            build_commitment_journal(&env, &other, 1, &player1),
+            Error::CommitmentMismatch,
+        ),
+        (
+            build_commitment_journal(&env, &commitment, 2, &player1),
+            Error::SessionMismatch,
+        ),
+        (
+            build_commitment_journal(&env, &commitment, 1, &player2),
+            Error::GardenerMismatch,
+        ),
+        (short, Error::ProofVerificationFailed),
+    ] {
+        let result =
+            client.try_commit_garden(&1u32, &commitment, &commitment_proof(&env, journal), &None);
+        assert_eq!(result, Err(Ok(error)));
+    }
+    assert_eq!(
+        client.get_session(&1u32).phase,
+        GamePhase::WaitingForCommitment
+    );
+}
+
+#[test]
+fn test_commit_garden_proof_with_valid_seal() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let commitment = compute_commitment(&env, &[0u8; 25]);
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+
+    let journal = build_commitment_journal(&env, &commitment, 1, &player1);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    let image_id = BytesN::<32>::random(&env);
+    let proof = Some(CommitmentProof {
+        seal: receipt_seal(&env, &image_id, &journal),
+        journal_bytes: journal,
+        journal_hash,
+    });
+
+    // No image ID registered for the garden validity circuit yet
+    let result = client.try_commit_garden(&1u32, &commitment, &proof, &None);
+    assert_eq!(result, Err(Ok(Error::NotInitialized)));
+
+    // The same verifier checks the garden validity guest's receipts
+    client.set_commitment_image_id(&image_id);
+
+    client.commit_garden(&1u32, &commitment, &proof, &None);
+    assert!(client.get_session(&1u32).garden_proven);
+}
+
+// ============================================================================
+// Player Stats Tests
+// ============================================================================
+
+#[test]
+fn test_player_stats_recorded_when_game_finishes() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let mut garden = [0u8; 25];
+    garden[5 + 2] = 3; // (2,1) Mandrake
+    garden[10 + 2] = 3; // (2,2) Mandrake
+    let (id, commitment) = start_creature_game(
+        &env,
+        &client,
+        &player1,
+        &player2,
+        CreatureKind::Ghost,
+        &garden,
+    );
+
+    for y in 1..=2 {
+        assert_eq!(client.get_player_stats(&player1).gardener_games, 0);
+        client.creature_move(&id, &2u32, &(y as u32));
+        reveal_at(&env, &client, id, &garden, &commitment, 2, y).unwrap();
+    }
+    assert_eq!(client.get_session(&id).phase, GamePhase::Finished);
+
+    let gardener = client.get_player_stats(&player1);
+    assert_eq!(gardener.gardener_games, 1);
+    assert_eq!((gardener.gardener_wins, gardener.gardener_losses), (1, 0));
+    assert_eq!(gardener.creature_games, 0);
+    assert_eq!(gardener.damage_dealt, 6);
+    assert_eq!(gardener.total_turns, 2);
+    assert_eq!(gardener.gardener_rating, 1216);
+    assert_eq!(gardener.creature_rating, 1200);
+
+    let creature = client.get_player_stats(&player2);
+    assert_eq!(creature.creature_games, 1);
+    assert_eq!((creature.creature_wins, creature.creature_losses), (0, 1));
+    assert_eq!(creature.damage_dealt, 0);
+    assert_eq!(creature.total_turns, 2);
+    assert_eq!(client.get_rating(&player2, &PlayerRole::Creature), 1184);
+    assert_eq!(client.get_rating(&player2, &PlayerRole::Gardener), 1200);
+
+    // Players who never finished a game start from the initial rating
+    let newcomer = client.get_player_stats(&Address::generate(&env));
+    assert_eq!(newcomer.gardener_games + newcomer.creature_games, 0);
+    assert_eq!(
+        (newcomer.gardener_rating, newcomer.creature_rating),
+        (1200, 1200)
+    );
+}
+
+#[test]
+fn test_player_stats_rate_each_role_separately() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    start_and_commit(&env, &client, &player1, &player2, &[0u8; 25]);
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&1u32);
+
+    // Roles swapped: player2 gardens and stalls, player1 wins as Creature
+    client.start_game(
+        &player2,
+        &player1,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&2u32);
+
+    let stats = client.get_player_stats(&player1);
+    assert_eq!((stats.gardener_games, stats.gardener_wins), (1, 1));
+    assert_eq!((stats.creature_games, stats.creature_wins), (1, 1));
+    assert_eq!((stats.gardener_rating, stats.creature_rating), (1216, 1216));
+    let stats = client.get_player_stats(&player2);
+    assert_eq!((stats.gardener_losses, stats.creature_losses), (1, 1));
+    assert_eq!((stats.gardener_rating, stats.creature_rating), (1184, 1184));
+}
+
+#[test]
+fn test_elo_update() {
+    assert_eq!(HerbalMoonlight::elo_update(1200, 1200, true), (1216, 1184));
+    // The favourite gains little for a win and loses a lot for a loss
+    assert_eq!(HerbalMoonlight::elo_update(1400, 1200, true), (1407, 1193));
+    assert_eq!(HerbalMoonlight::elo_update(1400, 1200, false), (1376, 1224));
+    // Expected scores are interpolated between table steps and capped at 800 points
+    assert_eq!(HerbalMoonlight::elo_update(1225, 1200, false), (1208, 1217));
+    assert_eq!(HerbalMoonlight::elo_update(2200, 1200, false), (2169, 1231));
+    // Ratings never go below zero
+    assert_eq!(HerbalMoonlight::elo_update(10, 30, false), (0, 45));
+}
+
+// ============================================================================
+// Moon Draw Tests
+// ============================================================================
+
+/// A moon seed and its sha256 commitment
+fn moon_seed(env: &Env, fill: u8) -> (BytesN<32>, BytesN<32>) {
+    let seed = BytesN::from_array(env, &[fill; 32]);
+    let hash = env.crypto().sha256(&Bytes::from(seed.clone())).into();
+    (seed, hash)
+}
+
+/// Start a game whose moon is drawn by commit-reveal and commit an empty garden
+fn start_moon_draw(
+    env: &Env,
+    client: &HerbalMoonlightClient,
+    gardener: &Address,
+    creature: &Address,
+) -> u32 {
+    let (_, gardener_hash) = moon_seed(env, 0x11);
+    let (_, creature_hash) = moon_seed(env, 0x22);
+    let session_id = client.start_game(
+        gardener,
+        creature,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &Some(creature_hash),
+    );
+    let commitment = compute_commitment(env, &[0u8; 25]);
+    client.commit_garden(&session_id, &commitment, &None, &Some(gardener_hash));
+    session_id
+}
+
+#[test]
+fn test_moon_fixed_after_both_seeds_revealed() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let session_id = start_moon_draw(&env, &client, &player1, &player2);
+
+    let session = client.get_session(&session_id);
+    assert!(matches!(session.moon, MoonState::Drawing(_)));
+    assert_eq!(session.moon_phase, MoonPhase::Balanced);
+    assert_eq!(session.creature_hp, 6);
+    assert_eq!(
+        client.try_creature_move(&session_id, &2u32, &1u32),
+        Err(Ok(Error::MoonNotFixed))
+    );
+    let result = client.try_request_sense(&session_id, &SenseKind::PeekAdjacent);
+    assert_eq!(result, Err(Ok(Error::MoonNotFixed)));
+
+    let (gardener_seed, _) = moon_seed(&env, 0x11);
+    let (creature_seed, _) = moon_seed(&env, 0x22);
+    client.reveal_moon_seed(&session_id, &player1, &gardener_seed);
+    let result = client.try_reveal_moon_seed(&session_id, &player1, &gardener_seed);
+    assert_eq!(result, Err(Ok(Error::MoonSeedAlreadyRevealed)));
+    let result = client.try_reveal_moon_seed(&session_id, &player2, &gardener_seed);
+    assert_eq!(result, Err(Ok(Error::MoonSeedMismatch)));
+    let result = client.try_reveal_moon_seed(&session_id, &Address::generate(&env), &creature_seed);
+    assert_eq!(result, Err(Ok(Error::NotAPlayer)));
+    let MoonState::Drawing(mut draw) = client.get_session(&session_id).moon else {
+        panic!("moon drawn before both seeds were revealed");
+    };
+
+    advance_ledger(&env, 10);
+    client.reveal_moon_seed(&session_id, &player2, &creature_seed);
+    let events = game_events(&env, &client);
+    let session = client.get_session(&session_id);
+    let event = MoonFixed {
+        session_id,
+        moon_phase: session.moon_phase.clone(),
+        creature_hp: session.creature_hp,
+    };
+    assert_eq!(events, [event.to_xdr(&env, &client.address)]);
+
+    // The draw depends only on what was fixed before the reveals, not on when
+    // the last seed landed
+    assert_eq!(session.moon, MoonState::Fixed);
+    draw.creature_seed = Some(creature_seed.clone());
+    let seed_bytes = HerbalMoonlight::moon_draw_seed(&env, session_id, &draw);
+    let drawn = HerbalMoonlight::roll_moon(&env, &seed_bytes, &default_rules()).0;
+    assert_eq!(session.moon_phase, drawn);
+    let bonus = if session.moon_phase == MoonPhase::FullMoon {
+        2
+    } else {
+        0
+    };
+    assert_eq!(session.creature_hp, 6 + bonus);
+    // The Creature's clock starts once the moon is fixed
+    assert_eq!(session.last_action_ledger, env.ledger().sequence());
+    let result = client.try_reveal_moon_seed(&session_id, &player2, &creature_seed);
+    assert_eq!(result, Err(Ok(Error::InvalidPhase)));
+
+    client.creature_move(&session_id, &2u32, &1u32);
+}
+
+#[test]
+fn test_gardener_moon_seed_follows_creature() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let (_, hash) = moon_seed(&env, 0x11);
+    let commitment = compute_commitment(&env, &[0u8; 25]);
+
+    // The Creature committed a seed: the Gardener must commit one too
+    let (_, creature_hash) = moon_seed(&env, 0x22);
+    let drawn = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &Some(creature_hash.clone()),
+    );
+    assert_eq!(
+        client.get_session(&drawn).moon,
+        MoonState::AwaitingGardenerSeed(creature_hash)
+    );
+    let result = client.try_commit_garden(&drawn, &commitment, &None, &None);
+    assert_eq!(result, Err(Ok(Error::MoonSeedMismatch)));
+
+    // Without a Creature seed the base moon applies and takes no Gardener seed
+    let session_id = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    let result = client.try_commit_garden(&session_id, &commitment, &None, &Some(hash));
+    assert_eq!(result, Err(Ok(Error::MoonSeedMismatch)));
+    client.commit_garden(&session_id, &commitment, &None, &None);
+    assert_eq!(client.get_session(&session_id).moon, MoonState::Fixed);
+    let (seed, _) = moon_seed(&env, 0x11);
+    let result = client.try_reveal_moon_seed(&session_id, &player1, &seed);
+    assert_eq!(result, Err(Ok(Error::InvalidPhase)));
+    assert_eq!(
+        client.try_settle_moon(&session_id),
+        Err(Ok(Error::InvalidPhase))
+    );
+}
+
+#[test]
+fn test_settle_moon_after_reveal_deadline() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let session_id = start_moon_draw(&env, &client, &player1, &player2);
+    let (creature_seed, _) = moon_seed(&env, 0x22);
+    client.reveal_moon_seed(&session_id, &player2, &creature_seed);
+
+    // The Gardener withholds their seed; the stalled Creature cannot be timed out
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS);
+    assert_eq!(
+        client.try_settle_moon(&session_id),
+        Err(Ok(Error::TimeoutNotReached))
+    );
+    advance_ledger(&env, 1);
+    assert_eq!(
+        client.try_claim_timeout(&session_id),
+        Err(Ok(Error::MoonNotFixed))
+    );
+
+    client.settle_moon(&session_id);
+    let session = client.get_session(&session_id);
+    assert_eq!(session.moon, MoonState::Fixed);
+    assert_eq!(session.last_action_ledger, env.ledger().sequence());
+    assert_eq!(
+        client.try_settle_moon(&session_id),
+        Err(Ok(Error::InvalidPhase))
+    );
+    client.creature_move(&session_id, &2u32, &1u32);
+}
+
+// ============================================================================
+// Lobby Tests
+// ============================================================================
+
+/// Post a 10-point Ghost challenge under the standard rules
+fn post_challenge(client: &HerbalMoonlightClient, creator: &Address, role: PlayerRole) -> u32 {
+    client.create_challenge(creator, &role, &10i128, &0u32, &CreatureKind::Ghost)
+}
+
+#[test]
+fn test_accept_challenge_starts_game() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let challenge_id = client.create_challenge(
+        &player1,
+        &PlayerRole::Gardener,
+        &50i128,
+        &0u32,
+        &CreatureKind::MoonMoth,
+    );
+    assert_eq!(env.auths()[0].0, player1);
+    assert_eq!(client.get_open_challenges(&0u32, &20u32).len(), 1);
+
+    let session_id = client.accept_challenge(&challenge_id, &player2);
+    // Only the accepting player signs the second transaction
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, player2);
+    let event = ChallengeAccepted {
+        challenge_id,
+        opponent: player2.clone(),
+        session_id,
+    };
+    assert_eq!(
+        game_events(&env, &client).last(),
+        Some(&event.to_xdr(&env, &client.address))
+    );
+
+    let session = client.get_session(&session_id);
+    assert_eq!(session.gardener, player1);
+    assert_eq!(session.creature, player2);
+    assert_eq!((session.gardener_points, session.creature_points), (50, 50));
+    assert_eq!(session.creature_kind, CreatureKind::MoonMoth);
+    assert_eq!(session.phase, GamePhase::WaitingForCommitment);
+
+    // The challenge is used up
+    assert_eq!(client.get_open_challenges(&0u32, &20u32).len(), 0);
+    assert_eq!(
+        client.try_get_challenge(&challenge_id),
+        Err(Ok(Error::ChallengeNotFound))
+    );
+    let result = client.try_accept_challenge(&challenge_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::ChallengeNotFound)));
+}
+
+#[test]
+fn test_challenge_creator_can_play_creature() {
+    let (_env, client, _admin, player1, player2) = setup_test();
+    let challenge_id = post_challenge(&client, &player1, PlayerRole::Creature);
+    let session_id = client.accept_challenge(&challenge_id, &player2);
+
+    let session = client.get_session(&session_id);
+    assert_eq!(session.gardener, player2);
+    assert_eq!(session.creature, player1);
+    assert_eq!(session.creature_kind, CreatureKind::Ghost);
+}
+
+#[test]
+fn test_challenge_validation() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let result = client.try_create_challenge(
+        &player1,
+        &PlayerRole::Gardener,
+        &10i128,
+        &7u32,
+        &CreatureKind::Ghost,
+    );
+    assert_eq!(result, Err(Ok(Error::RuleSetNotFound)));
+
+    let challenge_id = post_challenge(&client, &player1, PlayerRole::Gardener);
+    let result = client.try_accept_challenge(&challenge_id, &player1);
+    assert_eq!(result, Err(Ok(Error::SelfPlayNotAllowed)));
+
+    // Unaccepted challenges expire after ~1 day
+    let expires_ledger = client.get_challenge(&challenge_id).expires_ledger;
+    assert_eq!(
+        expires_ledger,
+        env.ledger().sequence() + CHALLENGE_EXPIRY_LEDGERS
+    );
+    advance_ledger(&env, CHALLENGE_EXPIRY_LEDGERS + 1);
+    let result = client.try_accept_challenge(&challenge_id, &player2);
+    assert_eq!(result, Err(Ok(Error::ChallengeExpired)));
+    assert_eq!(client.get_open_challenges(&0u32, &20u32).len(), 0);
+
+    // Expired challenges drop out of the lobby
+    let next_id = post_challenge(&client, &player2, PlayerRole::Creature);
+    assert_eq!(next_id, challenge_id + 1);
+    let open = client.get_open_challenges(&0u32, &20u32);
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().creator, player2);
+}
+
+#[test]
+fn test_cancel_challenge() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let first = post_challenge(&client, &player1, PlayerRole::Gardener);
+    let second = post_challenge(&client, &player1, PlayerRole::Creature);
+
+    client.cancel_challenge(&first);
+    assert_eq!(env.auths()[0].0, player1);
+    let open = client.get_open_challenges(&0u32, &20u32);
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().challenge_id, second);
+    assert_eq!(
+        client.try_accept_challenge(&first, &player2),
+        Err(Ok(Error::ChallengeNotFound))
+    );
+    assert_eq!(
+        client.try_cancel_challenge(&first),
+        Err(Ok(Error::ChallengeNotFound))
+    );
+}
+
+#[test]
+fn test_open_challenges_paged_newest_first() {
+    let (_env, client, _admin, player1, player2) = setup_test();
+    for _ in 0..25 {
+        post_challenge(&client, &player1, PlayerRole::Gardener);
+    }
+    client.accept_challenge(&23u32, &player2);
+    assert_eq!(client.get_challenge_count(), 25);
+
+    // A page scans at most 20 ids and skips the accepted one
+    let page = client.get_open_challenges(&0u32, &50u32);
+    assert_eq!(page.len(), 19);
+    assert_eq!(page.get(0).unwrap().challenge_id, 24);
+    assert_eq!(page.get(1).unwrap().challenge_id, 22);
+    assert_eq!(page.get(18).unwrap().challenge_id, 5);
+
+    let page = client.get_open_challenges(&20u32, &20u32);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().challenge_id, 4);
+    assert_eq!(page.get(4).unwrap().challenge_id, 0);
+    assert_eq!(client.get_open_challenges(&25u32, &20u32).len(), 0);
+}
+
+#[test]
+fn test_challenges_and_start_game_share_session_ids() {
+    let (_env, client, _admin, player1, player2) = setup_test();
+    let challenge_id = post_challenge(&client, &player1, PlayerRole::Gardener);
+    let started = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    assert_eq!(started, 1);
+    assert_eq!(client.accept_challenge(&challenge_id, &player2), 2);
+}
+
+// ============================================================================
+// Match Tests
+// ============================================================================
+
+/// Start a match under the standard rules with a Ghost Creature
+fn start_ghost_match(
+    client: &HerbalMoonlightClient,
+    player1: &Address,
+    player2: &Address,
+    best_of: u32,
+) -> u32 {
+    client.start_match(
+        player1,
+        player2,
+        &10i128,
+        &best_of,
+        &0u32,
+        &CreatureKind::Ghost,
+    )
+}
+
+/// Let the Gardener of the match's current round stall, so the Creature wins on timeout
+fn forfeit_round(env: &Env, client: &HerbalMoonlightClient, match_id: u32) -> u32 {
+    let session_id = client.get_match(&match_id).session_id;
+    advance_ledger(env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+    session_id
+}
+
+#[test]
+fn test_match_swaps_roles_and_reports_once() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let match_id = start_ghost_match(&client, &player1, &player2, 3);
+    assert_eq!(env.auths().len(), 2);
+
+    let m = client.get_match(&match_id);
+    assert_eq!((m.round, m.player1_wins, m.player2_wins), (1, 0, 0));
+    assert!(!m.finished);
+    let first = client.get_session(&m.session_id);
+    assert_eq!(
+        (first.gardener, first.creature),
+        (player1.clone(), player2.clone())
+    );
+    assert_eq!(first.match_id, Some(match_id));
+    assert_eq!((first.gardener_points, first.creature_points), (10, 10));
+
+    // Round 1: player2 wins as Creature; the next round swaps roles
+    let round1 = forfeit_round(&env, &client, match_id);
+    let m = client.get_match(&match_id);
+    assert_eq!((m.round, m.player1_wins, m.player2_wins), (2, 0, 1));
+    assert_ne!(m.session_id, round1);
+    let second = client.get_session(&m.session_id);
+    assert_eq!(
+        (second.gardener, second.creature),
+        (player2.clone(), player1.clone())
+    );
+    assert_eq!(second.phase, GamePhase::WaitingForCommitment);
+    assert_eq!(hub_result(&env, &client, match_id), None);
+
+    // Round 2: player1 wins as Creature; round 3 is back to the original roles
+    let round2 = forfeit_round(&env, &client, match_id);
+    let m = client.get_match(&match_id);
+    assert_eq!((m.round, m.player1_wins, m.player2_wins), (3, 1, 1));
+    assert_eq!(client.get_session(&m.session_id).gardener, player1);
+    let round3 = forfeit_round(&env, &client, match_id);
+
+    // Only the match outcome reaches the hub
+    let m = client.get_match(&match_id);
+    assert!(m.finished);
+    assert_eq!((m.round, m.player1_wins, m.player2_wins), (3, 1, 2));
+    assert_eq!(m.session_id, round3);
+    assert_eq!(hub_result(&env, &client, match_id), Some(false));
+    for session_id in [round1, round2, round3] {
+        assert_eq!(hub_result(&env, &client, session_id), None);
+    }
+
+    // Every round still counts toward the players' stats
+    let stats = client.get_player_stats(&player2);
+    assert_eq!((stats.gardener_games, stats.creature_games), (1, 2));
+    assert_eq!((stats.gardener_losses, stats.creature_wins), (1, 2));
+    assert_eq!(
+        client.try_claim_timeout(&round3),
+        Err(Ok(Error::GameAlreadyFinished))
+    );
+}
+
+#[test]
+fn test_match_ends_early_on_majority() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let match_id = start_ghost_match(&client, &player1, &player2, 3);
+
+    // player2 wins round 1 as Creature, then round 2 as Gardener when player1 stalls
+    forfeit_round(&env, &client, match_id);
+    let session_id = client.get_match(&match_id).session_id;
+    client.commit_garden(
+        &session_id,
+        &compute_commitment(&env, &[0u8; 25]),
+        &None,
+        &None,
+    );
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+
+    let event = MatchFinished {
+        match_id,
+        winner: player2.clone(),
+        player1_wins: 0,
+        player2_wins: 2,
+    };
+    assert_eq!(
+        game_events(&env, &client).last(),
+        Some(&event.to_xdr(&env, &client.address))
+    );
+
+    let m = client.get_match(&match_id);
+    assert!(m.finished);
+    assert_eq!((m.round, m.session_id), (2, session_id));
+    assert_eq!(hub_result(&env, &client, match_id), Some(false));
+}
+
+#[test]
+fn test_start_match_validation() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    for best_of in [0u32, 2, 11] {
+        let result = client.try_start_match(
+            &player1,
+            &player2,
+            &10i128,
+            &best_of,
+            &0u32,
+            &CreatureKind::Ghost,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidMatchLength)));
+    }
+    let result = client.try_start_match(
+        &player1,
+        &player1,
+        &10i128,
+        &3u32,
+        &0u32,
+        &CreatureKind::Ghost,
+    );
+    assert_eq!(result, Err(Ok(Error::SelfPlayNotAllowed)));
+    let result = client.try_start_match(
+        &player1,
+        &player2,
+        &10i128,
+        &3u32,
+        &7u32,
+        &CreatureKind::Ghost,
+    );
+    assert_eq!(result, Err(Ok(Error::RuleSetNotFound)));
+    assert_eq!(client.try_get_match(&1u32), Err(Ok(Error::MatchNotFound)));
+
+    // A single-round match is an ordinary game reported once on the hub
+    let match_id = start_ghost_match(&client, &player1, &player2, 1);
+    let session_id = client.get_match(&match_id).session_id;
+    assert_eq!(session_id, match_id + 1);
+    forfeit_round(&env, &client, match_id);
+    assert!(client.get_match(&match_id).finished);
+    assert_eq!(hub_result(&env, &client, match_id), Some(false));
+}
+
+// ============================================================================
+// Wager Tests
+// ============================================================================
+
+/// Register a Stellar Asset Contract and give each player 1000 units
+fn wager_token(env: &Env, players: [&Address; 2]) -> Address {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = StellarAssetClient::new(env, &token);
+    for player in players {
+        admin.mint(player, &1000i128);
+    }
+    token
+}
+
+/// Start a Ghost game under the standard rules where each player commits 10
+/// points and stakes 100
+fn start_wager(
+    client: &HerbalMoonlightClient,
+    gardener: &Address,
+    creature: &Address,
+    token: &Address,
+) -> u32 {
+    let wager = Wager {
+        token: token.clone(),
+        amount: 100,
+    };
+    let kind = CreatureKind::Ghost;
+    client.start_wager_game(gardener, creature, &10i128, &wager, &0u32, &kind, &None)
+}
+
+#[test]
+fn test_wager_paid_to_winner_minus_fee() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let token = wager_token(&env, [&player1, &player2]);
+    let balance = TokenClient::new(&env, &token);
+    let treasury = Address::generate(&env);
+    client.set_wager_fee(&treasury, &250u32);
+
+    let session_id = start_wager(&client, &player1, &player2, &token);
+    assert_eq!(
+        (balance.balance(&player1), balance.balance(&player2)),
+        (900, 900)
+    );
+    assert_eq!(balance.balance(&client.address), 200);
+    let session = client.get_session(&session_id);
+    assert_eq!(session.wager_token, Some(token.clone()));
+    assert_eq!((session.wager_amount, session.wager_fee_bps), (100, 250));
+    assert_eq!(session.wager_treasury, Some(treasury.clone()));
+    // Hub points are committed separately from the wager
+    assert_eq!((session.gardener_points, session.creature_points), (10, 10));
+
+    // A later fee or treasury change does not apply to the running game
+    let new_treasury = Address::generate(&env);
+    client.set_wager_fee(&new_treasury, &1000u32);
+
+    // The Gardener never commits: the Creature wins the pot by forfeit
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+    let paid = WagerPaid {
+        session_id,
+        winner: player2.clone(),
+        payout: 195,
+        fee: 5,
+    };
+    assert!(game_events(&env, &client).contains(&paid.to_xdr(&env, &client.address)));
+
+    assert_eq!(balance.balance(&player2), 1095);
+    assert_eq!(balance.balance(&player1), 900);
+    assert_eq!(balance.balance(&treasury), 5);
+    assert_eq!(balance.balance(&new_treasury), 0);
+    assert_eq!(balance.balance(&client.address), 0);
+    assert_eq!(hub_result(&env, &client, session_id), Some(false));
+}
+
+#[test]
+fn test_wager_without_fee_pays_whole_pot() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let token = wager_token(&env, [&player1, &player2]);
+    let balance = TokenClient::new(&env, &token);
+
+    let session_id = start_wager(&client, &player1, &player2, &token);
+    client.commit_garden(
+        &session_id,
+        &compute_commitment(&env, &[0u8; 25]),
+        &None,
+        &None,
+    );
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+
+    assert_eq!(balance.balance(&player1), 1100);
+    assert_eq!(balance.balance(&player2), 900);
+    assert_eq!(balance.balance(&client.address), 0);
+}
+
+#[test]
+fn test_cancel_game_refunds_wagers() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let token = wager_token(&env, [&player1, &player2]);
+    let balance = TokenClient::new(&env, &token);
+
+    let session_id = start_wager(&client, &player1, &player2, &token);
+    client.commit_garden(
+        &session_id,
+        &compute_commitment(&env, &[0u8; 25]),
+        &None,
+        &None,
+    );
+    client.cancel_game(&session_id);
+    // Both players sign the cancellation
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|a| a.0).collect();
+    assert_eq!(signers, std::vec![player1.clone(), player2.clone()]);
+    let event = GameCancelled { session_id };
+    assert_eq!(
+        game_events(&env, &client).last(),
+        Some(&event.to_xdr(&env, &client.address))
+    );
+
+    assert_eq!(
+        (balance.balance(&player1), balance.balance(&player2)),
+        (1000, 1000)
+    );
+    assert_eq!(balance.balance(&client.address), 0);
+    let session = client.get_session(&session_id);
+    assert_eq!(session.phase, GamePhase::Cancelled);
+    assert_eq!(client.get_phase(&session_id), GamePhase::Cancelled);
+    assert_eq!(client.get_winner(&session_id), None);
+
+    // No result: the hub is not told of a winner, nothing is recorded, and
+    // the game cannot be resumed
+    assert_eq!(hub_result(&env, &client, session_id), None);
+    assert_eq!(client.get_player_stats(&player1).gardener_games, 0);
+    assert_eq!(
+        client.try_claim_timeout(&session_id),
+        Err(Ok(Error::GameAlreadyFinished))
+    );
+    assert_eq!(
+        client.try_cancel_game(&session_id),
+        Err(Ok(Error::GameAlreadyFinished))
+    );
+}
+
+#[test]
+fn test_wager_validation() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let token = wager_token(&env, [&player1, &player2]);
+
+    let kind = CreatureKind::Ghost;
+    let wager = Wager {
+        token: token.clone(),
+        amount: 0,
+    };
+    let result = client.try_start_wager_game(&player1, &player2, &10, &wager, &0, &kind, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidWager)));
+    let wager = Wager {
+        token: token.clone(),
+        amount: 100,
+    };
+    let result = client.try_start_wager_game(&player1, &player1, &10, &wager, &0, &kind, &None);
+    assert_eq!(result, Err(Ok(Error::SelfPlayNotAllowed)));
+
+    let result = client.try_set_wager_fee(&Address::generate(&env), &1001u32);
+    assert_eq!(result, Err(Ok(Error::InvalidWagerFee)));
+    assert_eq!(client.get_wager_fee(), None);
+
+    // A pot or fee that would overflow is rejected before any deposit
+    let wager = Wager {
+        token: token.clone(),
+        amount: i128::MAX / 2 + 1,
+    };
+    let result = client.try_start_wager_game(&player1, &player2, &10, &wager, &0, &kind, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidWager)));
+    client.set_wager_fee(&Address::generate(&env), &1000u32);
+    let wager = Wager {
+        token,
+        amount: i128::MAX / 4,
+    };
+    let result = client.try_start_wager_game(&player1, &player2, &10, &wager, &0, &kind, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidWager)));
+
+    // Match rounds are settled by their match
+    let match_id = client.start_match(
+        &player1,
+        &player2,
+        &10i128,
+        &3u32,
+        &0u32,
+        &CreatureKind::Ghost,
+    );
+    let round = client.get_match(&match_id).session_id;
+    assert_eq!(client.try_cancel_game(&round), Err(Ok(Error::InvalidPhase)));
+}
+
+// ============================================================================
+// Resign and Cancel Tests
+// ============================================================================
+
+#[test]
+fn test_resign_awards_opponent() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let token = wager_token(&env, [&player1, &player2]);
+    let balance = TokenClient::new(&env, &token);
+    let session_id = start_wager(&client, &player1, &player2, &token);
+    client.commit_garden(
+        &session_id,
+        &compute_commitment(&env, &[0u8; 25]),
+        &None,
+        &None,
+    );
+
+    let result = client.try_resign(&session_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::NotAPlayer)));
+
+    // The Creature resigns: the Gardener wins as if by any other path
+    client.resign(&session_id, &player2);
+    assert_eq!(env.auths()[0].0, player2);
+    let resigned = PlayerResigned {
+        session_id,
+        player: player2.clone(),
+    };
+    let finished = GameFinished {
+        session_id,
+        winner: player1.clone(),
+        gardener_won: true,
+    };
+    let events = game_events(&env, &client);
+    assert_eq!(events[0], resigned.to_xdr(&env, &client.address));
+    assert_eq!(events[1], finished.to_xdr(&env, &client.address));
+
+    assert_eq!(client.get_winner(&session_id), Some(player1.clone()));
+    assert_eq!(hub_result(&env, &client, session_id), Some(true));
+    assert_eq!(balance.balance(&player1), 1100);
+    assert_eq!(client.get_player_stats(&player2).creature_losses, 1);
+    let result = client.try_resign(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyFinished)));
+}
+
+#[test]
+fn test_resign_scores_match_round() {
+    let (_env, client, _admin, player1, player2) = setup_test();
+    let match_id = client.start_match(
+        &player1,
+        &player2,
+        &10i128,
+        &3u32,
+        &0u32,
+        &CreatureKind::Ghost,
+    );
+    let round = client.get_match(&match_id).session_id;
+
+    client.resign(&round, &player1);
+    let m = client.get_match(&match_id);
+    assert_eq!((m.round, m.player1_wins, m.player2_wins), (2, 0, 1));
+}
+
+#[test]
+fn test_propose_and_accept_cancel() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let token = wager_token(&env, [&player1, &player2]);
+    let balance = TokenClient::new(&env, &token);
+    let session_id = start_wager(&client, &player1, &player2, &token);
+
+    let result = client.try_accept_cancel(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::NoCancelProposal)));
+
+    client.propose_cancel(&session_id, &player1);
+    let event = CancelProposed {
+        session_id,
+        player: player1.clone(),
+    };
+    assert_eq!(
+        game_events(&env, &client).last(),
+        Some(&event.to_xdr(&env, &client.address))
+    );
+    assert_eq!(
+        client.get_session(&session_id).cancel_proposed_by,
+        Some(player1.clone())
+    );
+
+    // The proposer cannot accept their own proposal
+    let result = client.try_accept_cancel(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::NoCancelProposal)));
+
+    // Each player signs only their own transaction
+    client.accept_cancel(&session_id, &player2);
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, player2);
+    let event = GameCancelled { session_id };
+    assert_eq!(
+        game_events(&env, &client).last(),
+        Some(&event.to_xdr(&env, &client.address))
+    );
+
+    let session = client.get_session(&session_id);
+    assert_eq!(session.phase, GamePhase::Cancelled);
+    assert_eq!(session.cancel_proposed_by, None);
+    assert_eq!(
+        (balance.balance(&player1), balance.balance(&player2)),
+        (1000, 1000)
+    );
+    assert_eq!(hub_result(&env, &client, session_id), None);
+
+    let commitment = compute_commitment(&env, &[0u8; 25]);
+    let result = client.try_commit_garden(&session_id, &commitment, &None, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidPhase)));
+    let result = client.try_propose_cancel(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyFinished)));
+    let result = client.try_resign(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyFinished)));
+}
+
+#[test]
+fn test_cancel_proposal_lapses_after_action() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let session_id = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    let result = client.try_propose_cancel(&session_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::NotAPlayer)));
+
+    // The Gardener acts in the same ledger, so the old proposal cannot be accepted
+    client.propose_cancel(&session_id, &player2);
+    client.commit_garden(
+        &session_id,
+        &compute_commitment(&env, &[0u8; 25]),
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_session(&session_id).cancel_proposed_by, None);
+    let result = client.try_accept_cancel(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::NoCancelProposal)));
+    assert_eq!(client.get_session(&session_id).phase, GamePhase::Playing);
+
+    // Revealing a moon seed withdraws a proposal too
+    let session_id = start_moon_draw(&env, &client, &player1, &player2);
+    client.propose_cancel(&session_id, &player1);
+    client.reveal_moon_seed(&session_id, &player2, &moon_seed(&env, 0x22).0);
+    let result = client.try_accept_cancel(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::NoCancelProposal)));
+}
+
+// ============================================================================
+// Archive Tests
+// ============================================================================
+
+#[test]
+fn test_finished_game_is_archived() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    assert_eq!(
+        client.try_get_game_record(&1u32),
+        Err(Ok(Error::GameRecordNotFound))
+    );
+
+    let mut garden = [0u8; 25];
+    garden[8] = 1; // (3,1) Lavender
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    let first = do_turn(&env, &client, 1, 3, 1, &garden, &commitment);
+    do_turn(&env, &client, 1, 2, 2, &garden, &commitment);
+    do_turn(&env, &client, 1, 2, 3, &garden, &commitment);
+    let last = do_turn(&env, &client, 1, 1, 4, &garden, &commitment);
+
+    let session = client.get_session(&1u32);
+    let record = client.get_game_record(&1u32);
+    assert_eq!(record.session_id, 1);
+    assert_eq!((&record.gardener, &record.creature), (&player1, &player2));
+    assert_eq!(record.creature_kind, session.creature_kind);
+    assert_eq!(record.rule_set_id, 0);
+    assert_eq!(record.moon_phase, session.moon_phase);
+    assert_eq!(record.path, soroban_sdk::vec![&env, 8u32, 12, 17, 21]);
+    assert_eq!(record.reveals.len(), 4);
+    assert_eq!(record.reveals.first(), Some(first));
+    assert_eq!(record.reveals.last(), Some(last));
+    assert!(record.reveals.first().unwrap().damage_dealt > 0);
+    assert_eq!(record.winner, Some(player2.clone()));
+    assert_eq!(record.turn_count, 4);
+    assert_eq!(record.ended_ledger, env.ledger().sequence());
+
+    // Both players' histories list the game
+    for player in [&player1, &player2] {
+        assert_eq!(client.get_player_game_count(player), 1);
+        let history = client.get_player_history(player, &0u32, &10u32);
+        assert_eq!(history, soroban_sdk::vec![&env, record.clone()]);
+    }
+}
+
+#[test]
+fn test_cancelled_game_is_archived_without_winner() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let session_id = client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    client.commit_garden(
+        &session_id,
+        &compute_commitment(&env, &[0u8; 25]),
+        &None,
+        &None,
+    );
+    client.cancel_game(&session_id);
+
+    let record = client.get_game_record(&session_id);
+    assert_eq!(record.winner, None);
+    assert_eq!(record.turn_count, 0);
+    assert!(record.path.is_empty() && record.reveals.is_empty());
+    assert_eq!(client.get_player_game_count(&player1), 1);
+}
+
+#[test]
+fn test_player_history_is_paginated() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    // Three games of player1, decided by the Gardener never committing
+    let opponents = [&player2, &player3, &player2];
+    let mut sessions = std::vec::Vec::new();
+    for (game, opponent) in opponents.into_iter().enumerate() {
+        let (gardener, creature) = if game == 1 {
+            (opponent, &player1)
+        } else {
+            (&player1, opponent)
+        };
+        let kind = CreatureKind::Ghost;
+        let session_id =
+            client.start_game(gardener, creature, &100i128, &100i128, &0u32, &kind, &None);
+        advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+        client.claim_timeout(&session_id);
+        sessions.push(session_id);
+    }
+
+    let page = |player: &Address, offset: u32, limit: u32| -> std::vec::Vec<u32> {
+        let history = client.get_player_history(player, &offset, &limit);
+        history.iter().map(|record| record.session_id).collect()
+    };
+    assert_eq!(client.get_player_game_count(&player1), 3);
+    // Newest first
+    assert_eq!(page(&player1, 0, 2), [sessions[2], sessions[1]]);
+    assert_eq!(page(&player1, 2, 2), [sessions[0]]);
+    assert!(page(&player1, 3, 2).is_empty());
+    assert_eq!(
+        page(&player1, 0, 100),
+        [sessions[2], sessions[1], sessions[0]]
+    );
+    assert_eq!(page(&player3, 0, 10), [sessions[1]]);
+    assert!(page(&Address::generate(&env), 0, 10).is_empty());
+
+    // player1 lost as Gardener and won as Creature
+    let history = client.get_player_history(&player1, &0u32, &3u32);
+    let winners: std::vec::Vec<_> = history.iter().map(|record| record.winner).collect();
+    assert_eq!(
+        winners,
+        [Some(player2.clone()), Some(player1.clone()), Some(player2)]
+    );
+}
+
+// ============================================================================
+// Event Tests
+// ============================================================================
+
+#[test]
+fn test_full_game_event_stream() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let mut garden = [0u8; 25];
+    garden[7] = 3; // (2,1) Mandrake
+    garden[12] = 3; // (2,2) Mandrake
+
+    // env.events() only holds the last invocation, so collect after each call
+    let mut events = std::vec::Vec::new();
+    client.start_game(
+        &player1,
+        &player2,
+        &100i128,
+        &100i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &None,
+    );
+    events.extend(game_events(&env, &client));
+    let commitment = compute_commitment(&env, &garden);
+    client.commit_garden(&session_id, &commitment, &None, &None);
+    events.extend(game_events(&env, &client));
+    for y in 1..=2u32 {
+        client.creature_move(&session_id, &2u32, &y);
+        events.extend(game_events(&env, &client));
+        let cell = garden[(y * 5 + 2) as usize];
+        let journal = build_journal(
+            &env,
+            &commitment,
+            session_id,
+            &player1,
+            2,
+            y as u8,
+            true,
+            cell,
+            3,
+        );
+        let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+        client.reveal_cell(&session_id, &journal, &journal_hash, &dev_seal(&env));
+        events.extend(game_events(&env, &client));
+    }
+
+    let addr = &client.address;
+    let expected = std::vec![
+        SessionCreated {
+            session_id,
+            gardener: player1.clone(),
+            creature: player2.clone(),
+            creature_kind: CreatureKind::Ghost,
+            rule_set_id: 0,
+            moon_phase: MoonPhase::Balanced,
+            creature_hp: 6,
+        }
+        .to_xdr(&env, addr),
+        GardenCommitted {
+            session_id,
+            garden_commitment: commitment.clone(),
+        }
+        .to_xdr(&env, addr),
+        CreatureMoved {
+            session_id,
+            x: 2,
+            y: 1,
+            turn_number: 1,
+        }
+        .to_xdr(&env, addr),
+        CellRevealed {
+            session_id,
+            x: 2,
+            y: 1,
+            has_plant: true,
+            plant_type: 3,
+            damage_dealt: 3,
+            creature_hp: 3,
+        }
+        .to_xdr(&env, addr),
+        CreatureMoved {
+            session_id,
+            x: 2,
+            y: 2,
+            turn_number: 2,
+        }
+        .to_xdr(&env, addr),
+        CellRevealed {
+            session_id,
+            x: 2,
+            y: 2,
+            has_plant: true,
+            plant_type: 3,
+            damage_dealt: 3,
+            creature_hp: 0,
+        }
+        .to_xdr(&env, addr),
+        GameFinished {
+            session_id,
+            winner: player1.clone(),
+            gardener_won: true,
+        }
+        .to_xdr(&env, addr),
+    ];
+    assert_eq!(events, expected);
+}
+
+#[test]
+fn test_sense_and_timeout_events() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let session_id = next_session_id(&env, &client);
+    let garden = [0u8; 25];
+    let commitment = start_and_commit(&env, &client, &player1, &player2, &garden);
+    let addr = &client.address;
+
+    client.request_sense(&session_id, &SenseKind::SmellAhead);
+    assert_eq!(
+        game_events(&env, &client),
+        std::vec![SenseRequested {
+            session_id,
+            kind: SenseKind::SmellAhead,
+            creature_hp: 5,
+        }
+        .to_xdr(&env, addr)]
+    );
+
+    let journal = build_sense_journal(
+        &env,
+        &commitment,
+        session_id,
+        &player1,
+        SenseKind::SmellAhead,
+        2,
+        0,
+        [0, 0],
+    );
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+    client.reveal_sense(&session_id, &journal, &journal_hash, &dev_seal(&env));
+    let result = SenseResult {
+        kind: SenseKind::SmellAhead,
+        x: 2,
+        y: 0,
+        left_has_plant: false,
+        right_has_plant: false,
+        plants_ahead: 0,
+    };
+    assert_eq!(
+        game_events(&env, &client),
+        std::vec![SenseRevealed { session_id, result }.to_xdr(&env, addr)]
+    );
+
+    // Creature stalls; the Gardener wins by forfeit
+    advance_ledger(&env, TURN_TIMEOUT_LEDGERS + 1);
+    client.claim_timeout(&session_id);
+    assert_eq!(
+        game_events(&env, &client),
+        std::vec![GameFinished {
+            session_id,
+            winner: player1.clone(),
+            gardener_won: true,
+        }
+        .to_xdr(&env, addr)]
+    );
+}
+
+#[test]
+fn test_failed_call_publishes_no_events() {
+    let (env, client, _admin, player1, player2) = setup_test();
+
+    let garden = [0u8; 25];
+    start_and_commit(&env, &client, &player1, &player2, &garden);
+
+    let result = client.try_creature_move(&1u32, &2u32, &3u32);
+    assert_eq!(result, Err(Ok(Error::InvalidMove)));
+    assert!(game_events(&env, &client).is_empty());
+}
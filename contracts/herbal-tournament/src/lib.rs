@@ -0,0 +1,752 @@
+#![no_std]
+
+//! # Herbal Moonlight Tournaments
+//!
+//! Runs single-elimination or Swiss tournaments on top of the Herbal Moonlight
+//! game contract. Players register, the organizer starts the event, and each
+//! round's pairings are played as ordinary game sessions started through the
+//! game contract. Results are read back from the finished sessions and
+//! winners advance automatically.
+//!
+//! **Game Hub Integration:**
+//! Every pairing is a normal Herbal Moonlight session, so the game contract
+//! registers and reports it on the Game Hub as usual.
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address,
+    BytesN, Env, Vec,
+};
+
+// ============================================================================
+// Herbal Moonlight Client Interface
+// ============================================================================
+
+#[contractclient(name = "HerbalMoonlightClient")]
+pub trait HerbalMoonlight {
+    #[allow(clippy::too_many_arguments)]
+    fn start_game(
+        env: Env,
+        gardener: Address,
+        creature: Address,
+        gardener_points: i128,
+        creature_points: i128,
+        rule_set_id: u32,
+        creature_kind: CreatureKind,
+        creature_seed_hash: Option<BytesN<32>>,
+    ) -> u32;
+
+    fn get_winner(env: Env, session_id: u32) -> Option<Address>;
+
+    fn get_phase(env: Env, session_id: u32) -> GamePhase;
+}
+
+// ============================================================================
+// Enums
+// ============================================================================
+
+/// Mirrors `herbal_moonlight::CreatureKind`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CreatureKind {
+    Ghost = 0,
+    MoonMoth = 1,
+    ShadowWolf = 2,
+}
+
+/// Mirrors `herbal_moonlight::GamePhase`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GamePhase {
+    WaitingForCommitment = 0,
+    WaitingForProof = 1,
+    Playing = 2,
+    Finished = 3,
+    WaitingForSenseProof = 4,
+    Cancelled = 5,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Losers are out; winners of adjacent pairings meet in the next round.
+    /// Round 1 is padded to a power of two with byes for the top seeds.
+    SingleElimination,
+    /// Everyone plays the given number of rounds against opponents with
+    /// similar scores; most wins takes the tournament
+    Swiss(u32),
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TournamentPhase {
+    Registration = 0,
+    Running = 1,
+    Finished = 2,
+}
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entrant {
+    pub player: Address,
+    /// Games won, byes included
+    pub wins: u32,
+    /// Everyone this player has been paired with, in round order
+    pub opponents: Vec<Address>,
+    pub had_bye: bool,
+}
+
+/// One game of a round. The first player is the Gardener; a pairing without
+/// a Creature is a bye and is won by the Gardener without playing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pairing {
+    pub gardener: Address,
+    pub creature: Option<Address>,
+    /// Game session, once `start_pairing` has created it
+    pub session_id: Option<u32>,
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tournament {
+    pub tournament_id: u32,
+    pub organizer: Address,
+    pub format: Format,
+    /// Points each player commits in every game
+    pub points: i128,
+    pub rule_set_id: u32,
+    pub creature_kind: CreatureKind,
+    pub max_entrants: u32,
+    pub phase: TournamentPhase,
+    /// In seed order (order of registration)
+    pub entrants: Vec<Entrant>,
+    /// Current round, from 1 (0 while registering)
+    pub round: u32,
+    /// Pairings of the current round
+    pub pairings: Vec<Pairing>,
+    pub winner: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Game,
+    NextTournamentId,
+    Tournament(u32),
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentCreated {
+    #[topic]
+    pub tournament_id: u32,
+    pub organizer: Address,
+    pub format: Format,
+    pub max_entrants: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerRegistered {
+    #[topic]
+    pub tournament_id: u32,
+    pub player: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundStarted {
+    #[topic]
+    pub tournament_id: u32,
+    pub round: u32,
+    pub pairings: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairingStarted {
+    #[topic]
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub session_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairingDecided {
+    #[topic]
+    pub tournament_id: u32,
+    pub round: u32,
+    pub index: u32,
+    pub winner: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentFinished {
+    #[topic]
+    pub tournament_id: u32,
+    pub winner: Address,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 1,
+    TournamentNotFound = 2,
+    InvalidPhase = 3,
+    InvalidFormat = 4,
+    InvalidEntrantLimit = 5,
+    AlreadyRegistered = 6,
+    TournamentFull = 7,
+    NotEnoughEntrants = 8,
+    PairingNotFound = 9,
+    PairingAlreadyStarted = 10,
+    PairingNotStarted = 11,
+    PairingAlreadyDecided = 12,
+    GameNotFinished = 13,
+    /// The pairing's game session has expired; start the pairing again
+    GameNotFound = 14,
+    NotInPairing = 15,
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+const TOURNAMENT_TTL_LEDGERS: u32 = 518_400; // 30 days
+const MAX_ENTRANTS: u32 = 64;
+const MAX_SWISS_ROUNDS: u32 = 10;
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct HerbalTournament;
+
+#[contractimpl]
+impl HerbalTournament {
+    /// Initialize the contract with the game contract it runs sessions on
+    ///
+    /// # Arguments
+    /// * `game` - Address of the Herbal Moonlight contract
+    pub fn __constructor(env: Env, game: Address) {
+        env.storage().instance().set(&DataKey::Game, &game);
+    }
+
+    /// Open a tournament for registration
+    ///
+    /// # Arguments
+    /// * `organizer` - Address that starts the tournament once players have registered
+    /// * `format` - Single elimination, or Swiss with a number of rounds
+    /// * `points` - Points each player commits in every game
+    /// * `rule_set_id` - Rule set of the game contract every game is played under
+    /// * `creature_kind` - Creature type the Creature plays in every game
+    /// * `max_entrants` - Registration cap (2 to 64)
+    ///
+    /// # Returns
+    /// * The tournament id
+    pub fn create_tournament(
+        env: Env,
+        organizer: Address,
+        format: Format,
+        points: i128,
+        rule_set_id: u32,
+        creature_kind: CreatureKind,
+        max_entrants: u32,
+    ) -> Result<u32, Error> {
+        organizer.require_auth();
+
+        if let Format::Swiss(rounds) = format {
+            if rounds == 0 || rounds > MAX_SWISS_ROUNDS {
+                return Err(Error::InvalidFormat);
+            }
+        }
+        if !(2..=MAX_ENTRANTS).contains(&max_entrants) {
+            return Err(Error::InvalidEntrantLimit);
+        }
+
+        let storage = env.storage().instance();
+        let tournament_id: u32 = storage.get(&DataKey::NextTournamentId).unwrap_or(0);
+        storage.set(&DataKey::NextTournamentId, &(tournament_id + 1));
+
+        let tournament = Tournament {
+            tournament_id,
+            organizer: organizer.clone(),
+            format,
+            points,
+            rule_set_id,
+            creature_kind,
+            max_entrants,
+            phase: TournamentPhase::Registration,
+            entrants: Vec::new(&env),
+            round: 0,
+            pairings: Vec::new(&env),
+            winner: None,
+        };
+        Self::save(&env, &tournament);
+
+        TournamentCreated {
+            tournament_id,
+            organizer,
+            format,
+            max_entrants,
+        }
+        .publish(&env);
+
+        Ok(tournament_id)
+    }
+
+    /// Register a player; seeds follow the order of registration
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to enter
+    /// * `player` - Address of the registering player
+    pub fn register(env: Env, tournament_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut tournament = Self::load(&env, tournament_id)?;
+        if tournament.phase != TournamentPhase::Registration {
+            return Err(Error::InvalidPhase);
+        }
+        if tournament.entrants.iter().any(|e| e.player == player) {
+            return Err(Error::AlreadyRegistered);
+        }
+        if tournament.entrants.len() >= tournament.max_entrants {
+            return Err(Error::TournamentFull);
+        }
+
+        tournament.entrants.push_back(Entrant {
+            player: player.clone(),
+            wins: 0,
+            opponents: Vec::new(&env),
+            had_bye: false,
+        });
+        Self::save(&env, &tournament);
+
+        PlayerRegistered {
+            tournament_id,
+            player,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Close registration and pair the first round (organizer only)
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament to start
+    pub fn start_tournament(env: Env, tournament_id: u32) -> Result<(), Error> {
+        let mut tournament = Self::load(&env, tournament_id)?;
+        tournament.organizer.require_auth();
+
+        if tournament.phase != TournamentPhase::Registration {
+            return Err(Error::InvalidPhase);
+        }
+        if tournament.entrants.len() < 2 {
+            return Err(Error::NotEnoughEntrants);
+        }
+
+        tournament.phase = TournamentPhase::Running;
+        let seeds = Vec::from_iter(&env, 0..tournament.entrants.len());
+        Self::start_round(&env, &mut tournament, seeds);
+        Self::save(&env, &tournament);
+        Ok(())
+    }
+
+    /// Start the game of a pairing on the game contract
+    ///
+    /// Both players must authorize the nested `start_game` call, exactly as
+    /// if they were starting the game themselves. A pairing whose game was
+    /// cancelled, or whose session expired, can be started again with a new
+    /// game.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `index` - Index of the pairing in the current round
+    ///
+    /// # Returns
+    /// * The session id of the new game
+    pub fn start_pairing(env: Env, tournament_id: u32, index: u32) -> Result<u32, Error> {
+        let mut tournament = Self::load(&env, tournament_id)?;
+        if tournament.phase != TournamentPhase::Running {
+            return Err(Error::InvalidPhase);
+        }
+        let mut pairing = tournament
+            .pairings
+            .get(index)
+            .ok_or(Error::PairingNotFound)?;
+        if pairing.winner.is_some() {
+            return Err(Error::PairingAlreadyDecided);
+        }
+        // Byes are always decided, so there is a Creature
+        let creature = pairing
+            .creature
+            .clone()
+            .ok_or(Error::PairingAlreadyDecided)?;
+        if let Some(session_id) = pairing.session_id {
+            let phase = Self::game(&env)?.try_get_phase(&session_id);
+            if matches!(phase, Ok(Ok(phase)) if phase != GamePhase::Cancelled) {
+                return Err(Error::PairingAlreadyStarted);
+            }
+        }
+
+        let session_id = Self::game(&env)?.start_game(
+            &pairing.gardener,
+            &creature,
+            &tournament.points,
+            &tournament.points,
+            &tournament.rule_set_id,
+            &tournament.creature_kind,
+            &None,
+        );
+        pairing.session_id = Some(session_id);
+        tournament.pairings.set(index, pairing);
+        Self::save(&env, &tournament);
+
+        PairingStarted {
+            tournament_id,
+            round: tournament.round,
+            index,
+            session_id,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// Record the winner of a finished pairing game. Anyone can call this;
+    /// the result is read from the game contract. Once every pairing of the
+    /// round is decided the next round is paired, or the tournament finishes.
+    /// A game whose session has expired fails with `GameNotFound`; restart
+    /// the pairing with `start_pairing` or have the organizer adjudicate it.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `index` - Index of the pairing in the current round
+    pub fn report_result(env: Env, tournament_id: u32, index: u32) -> Result<(), Error> {
+        let mut tournament = Self::load(&env, tournament_id)?;
+        if tournament.phase != TournamentPhase::Running {
+            return Err(Error::InvalidPhase);
+        }
+        let pairing = tournament
+            .pairings
+            .get(index)
+            .ok_or(Error::PairingNotFound)?;
+        if pairing.winner.is_some() {
+            return Err(Error::PairingAlreadyDecided);
+        }
+        let session_id = pairing.session_id.ok_or(Error::PairingNotStarted)?;
+        let winner = match Self::game(&env)?.try_get_winner(&session_id) {
+            Ok(Ok(winner)) => winner.ok_or(Error::GameNotFinished)?,
+            _ => return Err(Error::GameNotFound),
+        };
+
+        Self::decide(&env, &mut tournament, index, pairing, winner);
+        Ok(())
+    }
+
+    /// Award a pairing that cannot be played out to one of its players
+    /// (organizer only), e.g. when the other never shows up. A game that
+    /// already has a winner must be reported with `report_result` instead.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    /// * `index` - Index of the pairing in the current round
+    /// * `winner` - The player advancing
+    pub fn forfeit_pairing(
+        env: Env,
+        tournament_id: u32,
+        index: u32,
+        winner: Address,
+    ) -> Result<(), Error> {
+        let mut tournament = Self::load(&env, tournament_id)?;
+        tournament.organizer.require_auth();
+
+        if tournament.phase != TournamentPhase::Running {
+            return Err(Error::InvalidPhase);
+        }
+        let pairing = tournament
+            .pairings
+            .get(index)
+            .ok_or(Error::PairingNotFound)?;
+        if pairing.winner.is_some() {
+            return Err(Error::PairingAlreadyDecided);
+        }
+        if winner != pairing.gardener && Some(&winner) != pairing.creature.as_ref() {
+            return Err(Error::NotInPairing);
+        }
+        if let Some(session_id) = pairing.session_id {
+            if let Ok(Ok(Some(_))) = Self::game(&env)?.try_get_winner(&session_id) {
+                return Err(Error::PairingAlreadyDecided);
+            }
+        }
+
+        Self::decide(&env, &mut tournament, index, pairing, winner);
+        Ok(())
+    }
+
+    /// Get the current tournament state
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Result<Tournament, Error> {
+        Self::load(&env, tournament_id)
+    }
+
+    /// Entrants ordered by wins, ties broken by seed
+    ///
+    /// # Arguments
+    /// * `tournament_id` - The tournament
+    pub fn get_standings(env: Env, tournament_id: u32) -> Result<Vec<Address>, Error> {
+        let tournament = Self::load(&env, tournament_id)?;
+        let mut standings = Vec::new(&env);
+        for seed in Self::ranked(&env, &tournament).iter() {
+            standings.push_back(tournament.entrants.get_unchecked(seed).player);
+        }
+        Ok(standings)
+    }
+}
+
+// ============================================================================
+// Internal Helpers
+// ============================================================================
+
+impl HerbalTournament {
+    fn game(env: &Env) -> Result<HerbalMoonlightClient<'_>, Error> {
+        let game: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Game)
+            .ok_or(Error::NotInitialized)?;
+        Ok(HerbalMoonlightClient::new(env, &game))
+    }
+
+    fn load(env: &Env, tournament_id: u32) -> Result<Tournament, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Tournament(tournament_id))
+            .ok_or(Error::TournamentNotFound)
+    }
+
+    fn save(env: &Env, tournament: &Tournament) {
+        let key = DataKey::Tournament(tournament.tournament_id);
+        env.storage().temporary().set(&key, tournament);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, TOURNAMENT_TTL_LEDGERS, TOURNAMENT_TTL_LEDGERS);
+    }
+
+    /// Record the winner of a pairing, then pair the next round (or finish)
+    /// once it was the last undecided one, and save
+    fn decide(
+        env: &Env,
+        tournament: &mut Tournament,
+        index: u32,
+        mut pairing: Pairing,
+        winner: Address,
+    ) {
+        Self::credit_win(tournament, &winner);
+        pairing.winner = Some(winner.clone());
+        tournament.pairings.set(index, pairing);
+
+        PairingDecided {
+            tournament_id: tournament.tournament_id,
+            round: tournament.round,
+            index,
+            winner,
+        }
+        .publish(env);
+
+        if tournament.pairings.iter().all(|p| p.winner.is_some()) {
+            Self::advance(env, tournament);
+        }
+        Self::save(env, tournament);
+    }
+
+    /// Pair the next round once every pairing of the current one is decided,
+    /// or finish the tournament
+    fn advance(env: &Env, tournament: &mut Tournament) {
+        match tournament.format {
+            Format::SingleElimination => {
+                let mut winners = Vec::new(env);
+                for pairing in tournament.pairings.iter() {
+                    let winner = pairing.winner.unwrap();
+                    winners.push_back(Self::seed_of(tournament, &winner));
+                }
+                if winners.len() == 1 {
+                    Self::finish(env, tournament, winners.get_unchecked(0));
+                } else {
+                    Self::start_round(env, tournament, winners);
+                }
+            }
+            Format::Swiss(rounds) => {
+                let ranked = Self::ranked(env, tournament);
+                if tournament.round >= rounds {
+                    Self::finish(env, tournament, ranked.get_unchecked(0));
+                } else {
+                    Self::start_round(env, tournament, ranked);
+                }
+            }
+        }
+    }
+
+    /// Pair `seeds` (entrant indices) for the next round.
+    ///
+    /// Single elimination pairs neighbours in bracket order. Its first round
+    /// is padded to the next power of two with byes for the top seeds, so
+    /// every later round is even. Swiss takes `seeds` ranked by score: the
+    /// lowest-ranked player who has not had a bye sits out, and everyone else
+    /// meets the highest-ranked player left they have not played yet (a
+    /// rematch only when nobody else is left).
+    fn start_round(env: &Env, tournament: &mut Tournament, seeds: Vec<u32>) {
+        tournament.round += 1;
+        tournament.pairings = Vec::new(env);
+
+        let mut pool = seeds;
+        match tournament.format {
+            Format::SingleElimination if tournament.round == 1 => {
+                let byes = pool.len().next_power_of_two() - pool.len();
+                for _ in 0..byes {
+                    let seed = pool.pop_front_unchecked();
+                    Self::pair(tournament, seed, None);
+                }
+            }
+            Format::SingleElimination => {}
+            Format::Swiss(_) if pool.len() % 2 == 1 => {
+                let position = (0..pool.len())
+                    .rev()
+                    .find(|&p| {
+                        !tournament
+                            .entrants
+                            .get_unchecked(pool.get_unchecked(p))
+                            .had_bye
+                    })
+                    .unwrap_or(pool.len() - 1);
+                let seed = pool.get_unchecked(position);
+                pool.remove(position);
+                Self::pair(tournament, seed, None);
+            }
+            Format::Swiss(_) => {}
+        }
+
+        while let Some(seed) = pool.pop_front() {
+            let opponents = tournament.entrants.get_unchecked(seed).opponents;
+            let position = match tournament.format {
+                Format::SingleElimination => 0,
+                Format::Swiss(_) => (0..pool.len())
+                    .find(|&p| {
+                        let entrant = tournament.entrants.get_unchecked(pool.get_unchecked(p));
+                        !opponents.contains(&entrant.player)
+                    })
+                    .unwrap_or(0),
+            };
+            let opponent = pool.get_unchecked(position);
+            pool.remove(position);
+            Self::pair(tournament, seed, Some(opponent));
+        }
+
+        RoundStarted {
+            tournament_id: tournament.tournament_id,
+            round: tournament.round,
+            pairings: tournament.pairings.len(),
+        }
+        .publish(env);
+    }
+
+    /// Add a pairing of two entrants, or a bye that is won straight away
+    fn pair(tournament: &mut Tournament, gardener: u32, creature: Option<u32>) {
+        let mut first = tournament.entrants.get_unchecked(gardener);
+        let pairing = match creature {
+            Some(creature) => {
+                let mut second = tournament.entrants.get_unchecked(creature);
+                first.opponents.push_back(second.player.clone());
+                second.opponents.push_back(first.player.clone());
+                tournament.entrants.set(creature, second.clone());
+                Pairing {
+                    gardener: first.player.clone(),
+                    creature: Some(second.player),
+                    session_id: None,
+                    winner: None,
+                }
+            }
+            None => {
+                first.had_bye = true;
+                first.wins += 1;
+                Pairing {
+                    gardener: first.player.clone(),
+                    creature: None,
+                    session_id: None,
+                    winner: Some(first.player.clone()),
+                }
+            }
+        };
+        tournament.entrants.set(gardener, first);
+        tournament.pairings.push_back(pairing);
+    }
+
+    fn credit_win(tournament: &mut Tournament, winner: &Address) {
+        let seed = Self::seed_of(tournament, winner);
+        let mut entrant = tournament.entrants.get_unchecked(seed);
+        entrant.wins += 1;
+        tournament.entrants.set(seed, entrant);
+    }
+
+    fn finish(env: &Env, tournament: &mut Tournament, seed: u32) {
+        let winner = tournament.entrants.get_unchecked(seed).player;
+        tournament.phase = TournamentPhase::Finished;
+        tournament.winner = Some(winner.clone());
+
+        TournamentFinished {
+            tournament_id: tournament.tournament_id,
+            winner,
+        }
+        .publish(env);
+    }
+
+    fn seed_of(tournament: &Tournament, player: &Address) -> u32 {
+        tournament
+            .entrants
+            .iter()
+            .position(|e| e.player == *player)
+            .unwrap() as u32
+    }
+
+    /// Entrant indices ordered by wins (descending), ties broken by seed
+    fn ranked(env: &Env, tournament: &Tournament) -> Vec<u32> {
+        let mut ranked: Vec<u32> = Vec::new(env);
+        for (seed, entrant) in tournament.entrants.iter().enumerate() {
+            // Insert after every entrant with at least as many wins
+            let position = ranked
+                .iter()
+                .position(|other| tournament.entrants.get_unchecked(other).wins < entrant.wins)
+                .unwrap_or(ranked.len() as usize);
+            ranked.insert(position as u32, seed as u32);
+        }
+        ranked
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,561 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{
+    CreatureKind, Error, Format, HerbalTournament, HerbalTournamentClient, PairingDecided,
+    RoundStarted, TournamentFinished, TournamentPhase,
+};
+use herbal_moonlight::HerbalMoonlightClient;
+use soroban_sdk::testutils::{Address as _, BytesN as _, Events as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, xdr, Address, BytesN, Env, Event, Vec};
+
+// ============================================================================
+// Mock GameHub for Unit Testing
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+    }
+
+    /// Records the outcome so tests can assert who the hub was told won
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage().instance().set(&session_id, &player1_won);
+    }
+}
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+/// Mirrors the game contract's turn timeout
+const TURN_TIMEOUT_LEDGERS: u32 = 720;
+
+struct Setup {
+    env: Env,
+    tournament: HerbalTournamentClient<'static>,
+    game: HerbalMoonlightClient<'static>,
+    hub: Address,
+    organizer: Address,
+}
+
+fn setup_test() -> Setup {
+    let env = Env::default();
+    // Players authorize the game contract's start_game nested in start_pairing
+    env.mock_all_auths_allowing_non_root_auth();
+
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let hub = env.register(MockGameHub, ());
+    // Games here are decided by timeout, so the verifier is never called
+    let verifier = Address::generate(&env);
+    let game_id = env.register(
+        herbal_moonlight::HerbalMoonlight,
+        (
+            &Address::generate(&env),
+            &hub,
+            &verifier,
+            &BytesN::<32>::random(&env),
+        ),
+    );
+    let tournament_id = env.register(HerbalTournament, (&game_id,));
+
+    Setup {
+        tournament: HerbalTournamentClient::new(&env, &tournament_id),
+        game: HerbalMoonlightClient::new(&env, &game_id),
+        hub,
+        organizer: Address::generate(&env),
+        env,
+    }
+}
+
+/// Create a 10-point Ghost tournament under the standard rules and register `count` players
+fn open_tournament(s: &Setup, format: Format, count: u32) -> (u32, std::vec::Vec<Address>) {
+    let id = s.tournament.create_tournament(
+        &s.organizer,
+        &format,
+        &10i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &16u32,
+    );
+    let players: std::vec::Vec<Address> = (0..count).map(|_| Address::generate(&s.env)).collect();
+    for player in &players {
+        s.tournament.register(&id, player);
+    }
+    (id, players)
+}
+
+/// Play a pairing's game so that `winner` wins it: the Creature wins if the
+/// Gardener never commits, the Gardener wins if the Creature never moves
+fn play_pairing(s: &Setup, id: u32, index: u32, winner: &Address) {
+    let session_id = s.tournament.start_pairing(&id, &index);
+    play_started(s, id, index, session_id, winner);
+}
+
+/// Play an already started pairing game (see `play_pairing`) and report it
+fn play_started(s: &Setup, id: u32, index: u32, session_id: u32, winner: &Address) {
+    let session = s.game.get_session(&session_id);
+    if session.gardener == *winner {
+        s.game.commit_garden(
+            &session_id,
+            &BytesN::from_array(&s.env, &[7u8; 32]),
+            &None,
+            &None,
+        );
+    }
+    s.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += TURN_TIMEOUT_LEDGERS + 1);
+    s.game.claim_timeout(&session_id);
+    s.tournament.report_result(&id, &index);
+}
+
+/// Gardener and Creature of every pairing in the current round (None for a bye)
+fn pairings(s: &Setup, id: u32) -> std::vec::Vec<(Address, Option<Address>)> {
+    let t = s.tournament.get_tournament(&id);
+    t.pairings
+        .iter()
+        .map(|p| (p.gardener, p.creature))
+        .collect()
+}
+
+/// Outcome reported to the mock hub for a session: Some(true) if the Gardener won
+fn hub_result(s: &Setup, session_id: u32) -> Option<bool> {
+    s.env
+        .as_contract(&s.hub, || s.env.storage().instance().get(&session_id))
+}
+
+fn tournament_events(s: &Setup) -> std::vec::Vec<xdr::ContractEvent> {
+    s.env
+        .events()
+        .all()
+        .filter_by_contract(&s.tournament.address)
+        .events()
+        .to_vec()
+}
+
+// ============================================================================
+// Registration Tests
+// ============================================================================
+
+#[test]
+fn test_create_and_register() {
+    let s = setup_test();
+    let (id, players) = open_tournament(&s, Format::SingleElimination, 3);
+    assert_eq!(s.env.auths()[0].0, players[2]);
+
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.phase, TournamentPhase::Registration);
+    assert_eq!(t.round, 0);
+    assert_eq!(t.entrants.len(), 3);
+    assert_eq!(t.entrants.get(1).unwrap().player, players[1]);
+
+    let result = s.tournament.try_register(&id, &players[0]);
+    assert_eq!(result, Err(Ok(Error::AlreadyRegistered)));
+    let result = s.tournament.try_register(&7u32, &players[0]);
+    assert_eq!(result, Err(Ok(Error::TournamentNotFound)));
+}
+
+#[test]
+fn test_tournament_validation() {
+    let s = setup_test();
+    for (format, max_entrants, error) in [
+        (Format::Swiss(0), 8u32, Error::InvalidFormat),
+        (Format::Swiss(11), 8, Error::InvalidFormat),
+        (Format::SingleElimination, 1, Error::InvalidEntrantLimit),
+        (Format::SingleElimination, 65, Error::InvalidEntrantLimit),
+    ] {
+        let result = s.tournament.try_create_tournament(
+            &s.organizer,
+            &format,
+            &10i128,
+            &0u32,
+            &CreatureKind::Ghost,
+            &max_entrants,
+        );
+        assert_eq!(result, Err(Ok(error)));
+    }
+
+    let id = s.tournament.create_tournament(
+        &s.organizer,
+        &Format::SingleElimination,
+        &10i128,
+        &0u32,
+        &CreatureKind::Ghost,
+        &2u32,
+    );
+    let first = Address::generate(&s.env);
+    s.tournament.register(&id, &first);
+    let result = s.tournament.try_start_tournament(&id);
+    assert_eq!(result, Err(Ok(Error::NotEnoughEntrants)));
+
+    s.tournament.register(&id, &Address::generate(&s.env));
+    let result = s.tournament.try_register(&id, &Address::generate(&s.env));
+    assert_eq!(result, Err(Ok(Error::TournamentFull)));
+
+    s.tournament.start_tournament(&id);
+    assert_eq!(s.env.auths()[0].0, s.organizer);
+    let result = s.tournament.try_register(&id, &Address::generate(&s.env));
+    assert_eq!(result, Err(Ok(Error::InvalidPhase)));
+    assert_eq!(
+        s.tournament.try_start_tournament(&id),
+        Err(Ok(Error::InvalidPhase))
+    );
+}
+
+// ============================================================================
+// Pairing Tests
+// ============================================================================
+
+#[test]
+fn test_pairing_lifecycle() {
+    let s = setup_test();
+    let (id, players) = open_tournament(&s, Format::SingleElimination, 2);
+    s.tournament.start_tournament(&id);
+
+    let result = s.tournament.try_report_result(&id, &0u32);
+    assert_eq!(result, Err(Ok(Error::PairingNotStarted)));
+    assert_eq!(
+        s.tournament.try_start_pairing(&id, &1u32),
+        Err(Ok(Error::PairingNotFound))
+    );
+
+    // The pairing's game is an ordinary session between the two players
+    let session_id = s.tournament.start_pairing(&id, &0u32);
+    let session = s.game.get_session(&session_id);
+    assert_eq!(
+        (session.gardener, session.creature),
+        (players[0].clone(), players[1].clone())
+    );
+    assert_eq!((session.gardener_points, session.creature_points), (10, 10));
+    assert_eq!(
+        s.tournament.try_start_pairing(&id, &0u32),
+        Err(Ok(Error::PairingAlreadyStarted))
+    );
+    assert_eq!(
+        s.tournament.try_report_result(&id, &0u32),
+        Err(Ok(Error::GameNotFinished))
+    );
+
+    s.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += TURN_TIMEOUT_LEDGERS + 1);
+    s.game.claim_timeout(&session_id);
+    s.tournament.report_result(&id, &0u32);
+
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.phase, TournamentPhase::Finished);
+    assert_eq!(t.winner, Some(players[1].clone()));
+    assert_eq!(hub_result(&s, session_id), Some(false));
+    assert_eq!(
+        s.tournament.try_report_result(&id, &0u32),
+        Err(Ok(Error::InvalidPhase))
+    );
+}
+
+// ============================================================================
+// Simulated Tournaments
+// ============================================================================
+
+#[test]
+fn test_single_elimination_tournament() {
+    let s = setup_test();
+    let (id, p) = open_tournament(&s, Format::SingleElimination, 5);
+    s.tournament.start_tournament(&id);
+
+    // Round 1 is padded to 8: the top three seeds have byes, the rest meet
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![
+            (p[0].clone(), None),
+            (p[1].clone(), None),
+            (p[2].clone(), None),
+            (p[3].clone(), Some(p[4].clone())),
+        ]
+    );
+    assert_eq!(
+        s.tournament.try_start_pairing(&id, &0u32),
+        Err(Ok(Error::PairingAlreadyDecided))
+    );
+    play_pairing(&s, id, 3, &p[4]);
+
+    // Round 2: four players left, so nobody has a bye again
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.round, 2);
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![
+            (p[0].clone(), Some(p[1].clone())),
+            (p[2].clone(), Some(p[4].clone()))
+        ]
+    );
+    play_pairing(&s, id, 0, &p[1]);
+    play_pairing(&s, id, 1, &p[4]);
+
+    // Final
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![(p[1].clone(), Some(p[4].clone()))]
+    );
+    play_pairing(&s, id, 0, &p[4]);
+
+    let event = TournamentFinished {
+        tournament_id: id,
+        winner: p[4].clone(),
+    };
+    assert_eq!(
+        tournament_events(&s).last(),
+        Some(&event.to_xdr(&s.env, &s.tournament.address))
+    );
+
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.phase, TournamentPhase::Finished);
+    assert_eq!(t.round, 3);
+    assert_eq!(t.winner, Some(p[4].clone()));
+    assert_eq!(t.entrants.get(4).unwrap().wins, 3);
+    let byes: std::vec::Vec<bool> = t.entrants.iter().map(|e| e.had_bye).collect();
+    assert_eq!(byes, std::vec![true, true, true, false, false]);
+
+    // Every game was reported to the Game Hub by the game contract
+    for session_id in 1..=4u32 {
+        assert!(hub_result(&s, session_id).is_some());
+    }
+    let stats = s.game.get_player_stats(&p[4]);
+    assert_eq!((stats.gardener_wins, stats.creature_wins), (0, 3));
+}
+
+#[test]
+fn test_swiss_tournament() {
+    let s = setup_test();
+    let (id, p) = open_tournament(&s, Format::Swiss(3), 4);
+    s.tournament.start_tournament(&id);
+
+    // Round 1 pairs neighbours in seed order
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![
+            (p[0].clone(), Some(p[1].clone())),
+            (p[2].clone(), Some(p[3].clone()))
+        ]
+    );
+    play_pairing(&s, id, 0, &p[0]);
+    play_pairing(&s, id, 1, &p[3]);
+
+    // Round 2: winners meet winners, losers meet losers
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![
+            (p[0].clone(), Some(p[3].clone())),
+            (p[1].clone(), Some(p[2].clone()))
+        ]
+    );
+    play_pairing(&s, id, 0, &p[3]);
+
+    // The last result of the round pairs the next one
+    let mut events = std::vec::Vec::new();
+    play_pairing(&s, id, 1, &p[1]);
+    events.extend(tournament_events(&s));
+    let decided = PairingDecided {
+        tournament_id: id,
+        round: 2,
+        index: 1,
+        winner: p[1].clone(),
+    };
+    let started = RoundStarted {
+        tournament_id: id,
+        round: 3,
+        pairings: 2,
+    };
+    assert_eq!(
+        events,
+        std::vec![
+            decided.to_xdr(&s.env, &s.tournament.address),
+            started.to_xdr(&s.env, &s.tournament.address),
+        ]
+    );
+
+    // Round 3: p3 (2 wins) has already met p0 (1 win), so it plays p1 instead
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![
+            (p[3].clone(), Some(p[1].clone())),
+            (p[0].clone(), Some(p[2].clone()))
+        ]
+    );
+    play_pairing(&s, id, 0, &p[3]);
+    play_pairing(&s, id, 1, &p[0]);
+
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.phase, TournamentPhase::Finished);
+    assert_eq!(t.winner, Some(p[3].clone()));
+    let standings: Vec<Address> = s.tournament.get_standings(&id);
+    assert_eq!(
+        standings,
+        Vec::from_array(
+            &s.env,
+            [p[3].clone(), p[0].clone(), p[1].clone(), p[2].clone()]
+        )
+    );
+}
+
+#[test]
+fn test_swiss_byes_rotate() {
+    let s = setup_test();
+    let (id, p) = open_tournament(&s, Format::Swiss(2), 3);
+    s.tournament.start_tournament(&id);
+
+    // The lowest-ranked player sits out and scores a win
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![(p[2].clone(), None), (p[0].clone(), Some(p[1].clone()))]
+    );
+    play_pairing(&s, id, 1, &p[0]);
+
+    // p2 already had a bye, so the next one goes to p1
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![(p[1].clone(), None), (p[0].clone(), Some(p[2].clone()))]
+    );
+    play_pairing(&s, id, 1, &p[2]);
+
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.winner, Some(p[2].clone()));
+    let wins: std::vec::Vec<u32> = t.entrants.iter().map(|e| e.wins).collect();
+    assert_eq!(wins, std::vec![1, 1, 2]);
+}
+
+// ============================================================================
+// Stalled Pairings
+// ============================================================================
+
+#[test]
+fn test_cancelled_pairing_restarts() {
+    let s = setup_test();
+    let (id, p) = open_tournament(&s, Format::SingleElimination, 2);
+    s.tournament.start_tournament(&id);
+
+    // The players abort their game: it will never have a winner
+    let cancelled = s.tournament.start_pairing(&id, &0u32);
+    s.game.cancel_game(&cancelled);
+    assert_eq!(
+        s.tournament.try_report_result(&id, &0u32),
+        Err(Ok(Error::GameNotFinished))
+    );
+
+    // The pairing is played again as a new game
+    let session_id = s.tournament.start_pairing(&id, &0u32);
+    assert_ne!(session_id, cancelled);
+    assert_eq!(
+        s.tournament.try_start_pairing(&id, &0u32),
+        Err(Ok(Error::PairingAlreadyStarted))
+    );
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.pairings.get(0).unwrap().session_id, Some(session_id));
+
+    s.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += TURN_TIMEOUT_LEDGERS + 1);
+    s.game.claim_timeout(&session_id);
+    s.tournament.report_result(&id, &0u32);
+    assert_eq!(s.tournament.get_tournament(&id).winner, Some(p[1].clone()));
+}
+
+#[test]
+fn test_expired_pairing_restarts() {
+    let s = setup_test();
+    let (id, p) = open_tournament(&s, Format::SingleElimination, 2);
+    s.tournament.start_tournament(&id);
+
+    // The session's storage expires before anyone reports it
+    let expired = s.tournament.start_pairing(&id, &0u32);
+    s.env.as_contract(&s.game.address, || {
+        s.env
+            .storage()
+            .temporary()
+            .remove(&herbal_moonlight::DataKey::Session(expired))
+    });
+    assert_eq!(
+        s.tournament.try_report_result(&id, &0u32),
+        Err(Ok(Error::GameNotFound))
+    );
+
+    let session_id = s.tournament.start_pairing(&id, &0u32);
+    assert_ne!(session_id, expired);
+    play_started(&s, id, 0, session_id, &p[0]);
+    assert_eq!(s.tournament.get_tournament(&id).winner, Some(p[0].clone()));
+}
+
+#[test]
+fn test_organizer_forfeit() {
+    let s = setup_test();
+    let (id, p) = open_tournament(&s, Format::SingleElimination, 4);
+    s.tournament.start_tournament(&id);
+
+    // p0 never signs for their game: the organizer advances p1
+    let result = s.tournament.try_forfeit_pairing(&id, &0u32, &p[2]);
+    assert_eq!(result, Err(Ok(Error::NotInPairing)));
+    s.tournament.forfeit_pairing(&id, &0u32, &p[1]);
+    assert_eq!(s.env.auths()[0].0, s.organizer);
+    let event = PairingDecided {
+        tournament_id: id,
+        round: 1,
+        index: 0,
+        winner: p[1].clone(),
+    };
+    assert_eq!(
+        tournament_events(&s),
+        std::vec![event.to_xdr(&s.env, &s.tournament.address)]
+    );
+    let result = s.tournament.try_forfeit_pairing(&id, &0u32, &p[0]);
+    assert_eq!(result, Err(Ok(Error::PairingAlreadyDecided)));
+    assert_eq!(
+        s.tournament.try_start_pairing(&id, &0u32),
+        Err(Ok(Error::PairingAlreadyDecided))
+    );
+
+    // A game with a winner is reported, not adjudicated
+    let session_id = s.tournament.start_pairing(&id, &1u32);
+    s.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += TURN_TIMEOUT_LEDGERS + 1);
+    s.game.claim_timeout(&session_id);
+    let result = s.tournament.try_forfeit_pairing(&id, &1u32, &p[2]);
+    assert_eq!(result, Err(Ok(Error::PairingAlreadyDecided)));
+    s.tournament.report_result(&id, &1u32);
+
+    // The forfeit winner advances like any other
+    assert_eq!(
+        pairings(&s, id),
+        std::vec![(p[1].clone(), Some(p[3].clone()))]
+    );
+    s.tournament.forfeit_pairing(&id, &0u32, &p[3]);
+    let t = s.tournament.get_tournament(&id);
+    assert_eq!(t.phase, TournamentPhase::Finished);
+    assert_eq!(t.winner, Some(p[3].clone()));
+    assert_eq!(
+        s.tournament.try_forfeit_pairing(&id, &0u32, &p[3]),
+        Err(Ok(Error::InvalidPhase))
+    );
+}
@@ -65,11 +65,7 @@ impl MockGameHub {
     /// # Arguments
     /// * `session_id` - The game session being ended
     /// * `player1_won` - True if player1 won, false if player2 won
-    pub fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool,
-    ) {
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
         // No auth required for mock
         GameEnded {
             session_id,
@@ -86,7 +82,7 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
@@ -31,19 +31,6 @@ import {
 } from './gardenUtils';
 import { LandingScreen, WoodPanel, WoodButton, GameNavbar } from './LandingScreen';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
-
 // ====================================================================
 // Garden persistence in localStorage (critical for page refreshes)
 // ====================================================================
@@ -189,8 +176,8 @@ export function HerbalMoonlightGame({
   const POINTS_DECIMALS = 7;
   const { getContractSigner, walletType, switchPlayer, getCurrentDevPlayer, isConnecting: walletSwitching } = useWallet();
 
-  // Session state — sessionId starts as 0 (no active session) and is
-  // generated lazily when the user actually creates or imports a game.
+  // Session state — sessionId starts as 0 (no active session) and is set
+  // once the contract allocates it or the user loads an existing game.
   const [sessionId, setSessionId] = useState<number>(0);
   const [gameState, setGameState] = useState<GameSession | null>(null);
   const [uiPhase, setUiPhase] = useState<UIPhase>('create');
@@ -201,7 +188,6 @@ export function HerbalMoonlightGame({
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
   const [exportedAuthEntryXDR, setExportedAuthEntryXDR] = useState<string | null>(null);
   const [importAuthEntryXDR, setImportAuthEntryXDR] = useState('');
-  const [importSessionId, setImportSessionId] = useState('');
   const [importPlayer1, setImportPlayer1] = useState('');
   const [importPlayer1Points, setImportPlayer1Points] = useState('');
   const [importPlayer2Points, setImportPlayer2Points] = useState(DEFAULT_POINTS);
@@ -239,9 +225,8 @@ export function HerbalMoonlightGame({
   const autoRevealTurnRef = useRef<number | null>(null);
   const isBusy = loading || quickstartLoading;
 
-  // Polling refs — prevents concurrent requests & leaked intervals
+  // Polling ref — prevents concurrent requests
   const pollingInFlight = useRef(false);
-  const preparePollRef = useRef<ReturnType<typeof setInterval> | null>(null);
 
   const quickstartAvailable = walletType === 'dev'
     && DevWalletService.isDevModeAvailable()
@@ -250,13 +235,6 @@ export function HerbalMoonlightGame({
 
   useEffect(() => { setPlayer1Address(userAddress); }, [userAddress]);
 
-  // Clean up prepare-transaction poll on unmount
-  useEffect(() => {
-    return () => {
-      if (preparePollRef.current) clearInterval(preparePollRef.current);
-    };
-  }, []);
-
   // Determine player roles — must come before the auto-reveal useEffect so
   // TypeScript can see the declarations (block-scoped consts are not hoisted).
   const isGardener = gameState?.gardener === userAddress;
@@ -403,11 +381,6 @@ export function HerbalMoonlightGame({
 
   const handleStartNewGame = () => {
     if (gameState?.phase === GamePhase.Finished) onGameComplete();
-    // Clear leaked prepare poll
-    if (preparePollRef.current) {
-      clearInterval(preparePollRef.current);
-      preparePollRef.current = null;
-    }
     clearGardenFromStorage(sessionId);
     actionLock.current = false;
     setUiPhase('create');
@@ -426,7 +399,6 @@ export function HerbalMoonlightGame({
     setCreateMode('create');
     setExportedAuthEntryXDR(null);
     setImportAuthEntryXDR('');
-    setImportSessionId('');
     setImportPlayer1('');
     setImportPlayer1Points('');
     setImportPlayer2Points(DEFAULT_POINTS);
@@ -449,41 +421,18 @@ export function HerbalMoonlightGame({
         const p1Points = parsePoints(player1Points);
         if (!p1Points || p1Points <= 0n) throw new Error('Enter a valid points amount');
 
-        // Generate session ID lazily at creation time
-        const newSessionId = sessionId || createRandomSessionId();
-        if (!sessionId) setSessionId(newSessionId);
-
         const signer = getContractSigner();
         const placeholderCreature = await getFundedSimulationSourceAddress([player1Address, userAddress]);
 
         const authEntryXDR = await service.prepareStartGame(
-          newSessionId, player1Address, placeholderCreature,
+          player1Address, placeholderCreature,
           p1Points, p1Points, signer
         );
 
+        // The contract allocates the session id when the Creature submits,
+        // so the Creature sends it back and the Gardener loads the game
         setExportedAuthEntryXDR(authEntryXDR);
         setSuccess('Auth entry signed! Send it to the Creature player.');
-
-        // Poll for game creation — stored in ref for cleanup
-        if (preparePollRef.current) clearInterval(preparePollRef.current);
-        const pollInterval = setInterval(async () => {
-          const game = await service.getSession(newSessionId);
-          if (game) {
-            clearInterval(pollInterval);
-            preparePollRef.current = null;
-            setGameState(game);
-            setExportedAuthEntryXDR(null);
-            setSuccess('Game created! Creature has joined.');
-            setUiPhase('garden-setup');
-            onStandingsRefresh();
-            setTimeout(() => setSuccess(null), 2000);
-          }
-        }, 3000);
-        preparePollRef.current = pollInterval;
-        setTimeout(() => {
-          clearInterval(pollInterval);
-          if (preparePollRef.current === pollInterval) preparePollRef.current = null;
-        }, 300000);
       } catch (err) {
         setError(err instanceof Error ? err.message : 'Failed to prepare transaction');
       } finally {
@@ -506,7 +455,6 @@ export function HerbalMoonlightGame({
         if (!p2Points || p2Points <= 0n) throw new Error('Invalid points amount');
 
         const gameParams = service.parseAuthEntry(importAuthEntryXDR.trim());
-        setImportSessionId(gameParams.sessionId.toString());
         setImportPlayer1(gameParams.gardener);
         setImportPlayer1Points((Number(gameParams.gardenerPoints) / 10_000_000).toString());
 
@@ -518,12 +466,12 @@ export function HerbalMoonlightGame({
         const fullySignedTxXDR = await service.importAndSignAuthEntry(
           importAuthEntryXDR.trim(), userAddress, p2Points, signer
         );
-        await service.finalizeStartGame(fullySignedTxXDR, userAddress, signer);
+        const newSessionId = await service.finalizeStartGame(fullySignedTxXDR, userAddress, signer);
 
-        setSessionId(gameParams.sessionId);
-        setSuccess('Game created! Waiting for Gardener to set up garden...');
+        setSessionId(newSessionId);
+        setSuccess(`Game #${newSessionId} created! Send the session ID to the Gardener.`);
         setUiPhase('garden-setup');
-        await fetchGameState(gameParams.sessionId);
+        await fetchGameState(newSessionId);
         onStandingsRefresh();
         setTimeout(() => setSuccess(null), 2000);
       } catch (err) {
@@ -611,19 +559,18 @@ export function HerbalMoonlightGame({
         if (!gardenerSigner || !creatureSigner) throw new Error('Failed to init dev wallets');
         if (gardenerAddr === creatureAddr) throw new Error('Two different dev wallets required');
 
-        const qsSessionId = createRandomSessionId();
-        setSessionId(qsSessionId);
         setPlayer1Address(gardenerAddr);
 
         const placeholderCreature = await getFundedSimulationSourceAddress([gardenerAddr, creatureAddr]);
 
         const authEntryXDR = await service.prepareStartGame(
-          qsSessionId, gardenerAddr, placeholderCreature, p1Points, p1Points, gardenerSigner
+          gardenerAddr, placeholderCreature, p1Points, p1Points, gardenerSigner
         );
         const fullySignedTxXDR = await service.importAndSignAuthEntry(
           authEntryXDR, creatureAddr, p1Points, creatureSigner
         );
-        await service.finalizeStartGame(fullySignedTxXDR, creatureAddr, creatureSigner);
+        const qsSessionId = await service.finalizeStartGame(fullySignedTxXDR, creatureAddr, creatureSigner);
+        setSessionId(qsSessionId);
 
         const game = await service.getSession(qsSessionId);
         setGameState(game);
@@ -760,7 +707,9 @@ export function HerbalMoonlightGame({
         const x = gameState.creature_x;
         const y = gameState.creature_y;
 
-        const journalBytes = buildJournal(gardenCommitment, x, y, garden);
+        const journalBytes = buildJournal(
+          gardenCommitment, x, y, garden, sessionId, gameState.gardener, gameState.rule_set_hash
+        );
         setZkProofStep(2); // step 2: coordinates encoded
 
         await new Promise(r => setTimeout(r, 350));
@@ -1216,7 +1165,7 @@ export function HerbalMoonlightGame({
               {authEntryCopied ? 'Copied!' : 'Copy Auth Entry'}
             </WoodButton>
             <p style={{ fontSize: '0.68rem', textAlign: 'center', color: 'rgba(170,130,80,0.6)', fontFamily: 'var(--font-mono)', margin: 0 }}>
-              Session #{sessionId} &mdash; Send to the Creature player. Polling&hellip;
+              Send to the Creature player, then load the game with the session ID they send back.
             </p>
           </div>
         )}
@@ -1244,7 +1193,6 @@ export function HerbalMoonlightGame({
                 setImportAuthEntryXDR(e.target.value);
                 try {
                   const parsed = service.parseAuthEntry(e.target.value.trim());
-                  setImportSessionId(parsed.sessionId.toString());
                   setImportPlayer1(parsed.gardener);
                   setImportPlayer1Points((Number(parsed.gardenerPoints) / 10_000_000).toString());
                 } catch { /* ignore while typing */ }
@@ -1258,9 +1206,8 @@ export function HerbalMoonlightGame({
                 resize: 'none',
               }}
             />
-            {importSessionId && (
+            {importPlayer1 && (
               <div style={{ fontSize: '0.68rem', color: 'rgba(170,135,90,0.75)', display: 'grid', gridTemplateColumns: '1fr 1fr', gap: '0.25rem' }}>
-                <span>Session: <strong style={{ color: 'rgba(228,195,135,0.9)' }}>{importSessionId}</strong></span>
                 <span>Points: <strong style={{ color: 'rgba(228,195,135,0.9)' }}>{importPlayer1Points}</strong></span>
                 <span style={{ gridColumn: '1 / -1' }}>
                   Gardener: <span style={{ fontFamily: 'var(--font-mono)' }}>{importPlayer1.slice(0, 10)}…{importPlayer1.slice(-4)}</span>
@@ -1,4 +1,5 @@
 import { Buffer } from 'buffer';
+import { Address } from '@stellar/stellar-sdk';
 
 // Plant types matching the contract
 export const PLANT_TYPES = {
@@ -77,27 +78,35 @@ export async function computeGardenCommitment(garden: GardenLayout): Promise<Buf
   return Buffer.from(hashBuffer);
 }
 
-// Build a 73-byte journal for dev mode cell reveal
-// Format: [commitment:32][x:1][y:1][has_plant:1][plant_type:1][damage:1][padding:36]
+// Build a 105-byte journal for dev mode cell reveal
+// Format: [commitment:32][x:1][y:1][has_plant:1][plant_type:1][damage:1][session_id:4][gardener_pubkey:32]
+//         [rule_set_hash:32]
+// session_id (little-endian), gardener_pubkey and the session's rule_set_hash bind the reveal to one game.
 export function buildJournal(
   commitment: Buffer,
   x: number,
   y: number,
-  garden: GardenLayout
+  garden: GardenLayout,
+  sessionId: number,
+  gardener: string,
+  ruleSetHash: Buffer
 ): Buffer {
   const cellIndex = y * GRID_SIZE + x;
   const plantType = garden[cellIndex] || 0;
   const hasPlant = plantType > 0;
   const damage = hasPlant ? (PLANT_DAMAGE[plantType] || 0) : 0;
 
-  const journal = Buffer.alloc(73);
+  const journal = Buffer.alloc(105);
   commitment.copy(journal, 0, 0, 32);
   journal[32] = x;
   journal[33] = y;
   journal[34] = hasPlant ? 1 : 0;
   journal[35] = plantType;
   journal[36] = damage;
-  // Bytes 37-72 remain zero (padding)
+  journal.writeUInt32LE(sessionId, 37);
+  // Raw ed25519 key (G...) or contract hash (C...) of the gardener
+  Address.fromString(gardener).toBuffer().copy(journal, 41, 0, 32);
+  ruleSetHash.copy(journal, 73, 0, 32);
 
   return journal;
 }
@@ -8,6 +8,14 @@ import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
 
 type ClientOptions = contract.ClientOptions;
 
+// Rule set registered by the contract constructor (standard rules)
+export const DEFAULT_RULE_SET_ID = 0;
+
+// Creature kinds (contract CreatureKind discriminants)
+export const CREATURE_KIND_GHOST = 0;
+export const CREATURE_KIND_MOON_MOTH = 1;
+export const CREATURE_KIND_SHADOW_WOLF = 2;
+
 export class HerbalMoonlightService {
   private baseClient: HerbalMoonlightClient;
   private contractId: string;
@@ -55,13 +63,13 @@ export class HerbalMoonlightService {
   // ====================================================================
 
   async prepareStartGame(
-    sessionId: number,
     gardener: string,
     creature: string,
     gardenerPoints: bigint,
     creaturePoints: bigint,
     gardenerSigner: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    ruleSetId: number = DEFAULT_RULE_SET_ID
   ): Promise<string> {
     const buildClient = new HerbalMoonlightClient({
       contractId: this.contractId,
@@ -71,11 +79,15 @@ export class HerbalMoonlightService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: sessionId,
       gardener,
       creature,
       gardener_points: gardenerPoints,
       creature_points: creaturePoints,
+      rule_set_id: ruleSetId,
+      // Not covered by the gardener's auth; the creature picks its kind on import
+      creature_kind: CREATURE_KIND_GHOST,
+      // No commit-reveal draw: the game plays under the base moon
+      creature_seed_hash: undefined,
     }, DEFAULT_METHOD_OPTIONS);
 
     if (!tx.simulationData?.result?.auth) {
@@ -135,9 +147,9 @@ export class HerbalMoonlightService {
   }
 
   parseAuthEntry(authEntryXdr: string): {
-    sessionId: number;
     gardener: string;
     gardenerPoints: bigint;
+    ruleSetId: number;
     functionName: string;
   } {
     try {
@@ -161,10 +173,10 @@ export class HerbalMoonlightService {
         throw new Error(`Expected 2 arguments, got ${args.length}`);
       }
 
-      const sessionId = args[0].u32();
-      const gardenerPoints = args[1].i128().lo().toBigInt();
+      const gardenerPoints = args[0].i128().lo().toBigInt();
+      const ruleSetId = args[1].u32();
 
-      return { sessionId, gardener, gardenerPoints, functionName };
+      return { gardener, gardenerPoints, ruleSetId, functionName };
     } catch (err: any) {
       throw new Error(`Failed to parse auth entry: ${err.message}`);
     }
@@ -175,7 +187,8 @@ export class HerbalMoonlightService {
     creatureAddress: string,
     creaturePoints: bigint,
     creatureSigner: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    creatureKind: number = CREATURE_KIND_GHOST
   ): Promise<string> {
     const gameParams = this.parseAuthEntry(gardenerSignedAuthEntryXdr);
 
@@ -191,11 +204,13 @@ export class HerbalMoonlightService {
     });
 
     const tx = await buildClient.start_game({
-      session_id: gameParams.sessionId,
       gardener: gameParams.gardener,
       creature: creatureAddress,
       gardener_points: gameParams.gardenerPoints,
       creature_points: creaturePoints,
+      rule_set_id: gameParams.ruleSetId,
+      creature_kind: creatureKind,
+      creature_seed_hash: undefined,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntilLedgerSeq = await calculateValidUntilLedger(
@@ -222,12 +237,13 @@ export class HerbalMoonlightService {
     return creatureTx.toXDR();
   }
 
+  /** Submits the fully signed start_game transaction and returns the session id the contract allocated */
   async finalizeStartGame(
     txXdr: string,
     signerAddress: string,
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
-  ) {
+  ): Promise<number> {
     const client = this.createSigningClient(signerAddress, signer);
     const tx = client.txFromXDR(txXdr);
     await tx.simulate();
@@ -242,7 +258,12 @@ export class HerbalMoonlightService {
       DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
       validUntilLedgerSeq
     );
-    return sentTx.result;
+    const raw = sentTx.result as unknown;
+    if (raw && typeof raw === 'object' && typeof (raw as any).isOk === 'function') {
+      if (!(raw as any).isOk()) throw new Error('start_game failed');
+      return Number((raw as any).unwrap());
+    }
+    return Number(raw);
   }
 
   // ====================================================================
@@ -260,6 +281,8 @@ export class HerbalMoonlightService {
     const tx = await client.commit_garden({
       session_id: sessionId,
       garden_commitment: gardenCommitment,
+      validity_proof: undefined,
+      moon_seed_hash: undefined,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntilLedgerSeq = await calculateValidUntilLedger(
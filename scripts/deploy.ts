@@ -312,12 +312,27 @@ for (const contract of contracts) {
       // groth16-verifier constructor only takes --admin (no game_hub needed)
       deployResult =
         await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
+    } else if (contract.packageName === 'herbal-tournament') {
+      // herbal-tournament constructor only takes the game contract it runs sessions on
+      // (herbal-moonlight sorts first, so a fresh deployment is already recorded here)
+      const gameId = deployed['herbal-moonlight'];
+      if (!gameId) {
+        console.error("❌ Error: herbal-tournament needs a deployed herbal-moonlight");
+        console.error("\nDeploy both: 'bun run deploy herbal-moonlight herbal-tournament'");
+        process.exit(1);
+      }
+      deployResult =
+        await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --game ${gameId}`.text();
     } else {
       // Standard game contract constructor: admin, game_hub
       deployResult =
         await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game_hub ${mockGameHubId}`.text();
     }
     const contractId = deployResult.trim();
+    if (contract.packageName === 'herbal-moonlight') {
+      // No real verifier yet: accept the frontend's empty (dev mode) seals
+      await $`stellar contract invoke --id ${contractId} --source-account ${adminSecret} --network ${NETWORK} -- set_dev_mode --enabled true`.text();
+    }
     deployed[contract.packageName] = contractId;
     console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
   } catch (error) {
@@ -0,0 +1,156 @@
+//! Groth16 prover for tests.
+//!
+//! Real RISC Zero receipts can only be produced with RISC Zero's Groth16
+//! prover toolchain. This crate instead runs its own Groth16 setup
+//! (arkworks) for a circuit with the same five public inputs as the RISC Zero
+//! receipt circuit. Once its verification key is installed, `verify` checks
+//! the proofs exactly as it checks RISC Zero receipts: same claim digest,
+//! same public input split, same seal layout. Its seals do not verify under
+//! the production RISC Zero key.
+
+use ark_bn254::{Bn254, Fq, Fr as ScalarField, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, ProvingKey};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError, Variable};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use groth16_verifier::{
+    Groth16Proof, Groth16VerifierContract, VerificationKey, PUBLIC_INPUT_COUNT,
+};
+use soroban_sdk::{crypto::bn254::Fr, Bytes, BytesN, Env, Vec};
+
+/// Length of a RISC Zero Groth16 seal: [a:64][b:128][c:64]
+pub const SEAL_LEN: usize = 256;
+
+/// Circuit whose only statement is its public inputs
+struct ReceiptCircuit {
+    inputs: [ScalarField; PUBLIC_INPUT_COUNT as usize],
+}
+
+impl ConstraintSynthesizer<ScalarField> for ReceiptCircuit {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<ScalarField>,
+    ) -> Result<(), SynthesisError> {
+        for input in self.inputs {
+            let var = cs.new_input_variable(|| Ok(input))?;
+            cs.enforce_constraint(lc!() + var, lc!() + Variable::One, lc!() + var)?;
+        }
+        Ok(())
+    }
+}
+
+/// Groth16 setup over the receipt statement, standing in for the RISC Zero
+/// ceremony. The control root and BN254 control ID are copied into the
+/// verification key unchanged.
+pub struct ReceiptProver {
+    pk: ProvingKey<Bn254>,
+    control_root: [u8; 32],
+    bn254_control_id: [u8; 32],
+    rng: core::cell::RefCell<StdRng>,
+}
+
+impl ReceiptProver {
+    /// Deterministic setup from `seed`
+    pub fn new(seed: u64, control_root: [u8; 32], bn254_control_id: [u8; 32]) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let circuit = ReceiptCircuit {
+            inputs: [ScalarField::from(0u64); PUBLIC_INPUT_COUNT as usize],
+        };
+        let (pk, _) = Groth16::<Bn254>::circuit_specific_setup(circuit, &mut rng)
+            .expect("receipt circuit setup");
+        Self {
+            pk,
+            control_root,
+            bn254_control_id,
+            rng: core::cell::RefCell::new(rng),
+        }
+    }
+
+    /// Verification key to install with `init_vk`
+    pub fn verification_key(&self, env: &Env) -> VerificationKey {
+        let vk = &self.pk.vk;
+        let mut ic = Vec::new(env);
+        for point in vk.gamma_abc_g1.iter() {
+            ic.push_back(g1_bytes(env, point));
+        }
+        VerificationKey {
+            alpha_g1: g1_bytes(env, &vk.alpha_g1),
+            beta_g2: g2_bytes(env, &vk.beta_g2),
+            gamma_g2: g2_bytes(env, &vk.gamma_g2),
+            delta_g2: g2_bytes(env, &vk.delta_g2),
+            ic,
+            control_root: BytesN::from_array(env, &self.control_root),
+            bn254_control_id: BytesN::from_array(env, &self.bn254_control_id),
+        }
+    }
+
+    /// Seal of a receipt for the guest `image_id` halting with `journal`,
+    /// laid out like RISC Zero's `Groth16Seal::to_vec`
+    pub fn seal(&self, env: &Env, image_id: &BytesN<32>, journal: &Bytes) -> Bytes {
+        let journal_digest: BytesN<32> = env.crypto().sha256(journal).into();
+        let claim = Groth16VerifierContract::receipt_claim_digest(env, image_id, &journal_digest);
+        let vk = self.verification_key(env);
+        let inputs = Groth16VerifierContract::receipt_public_inputs(env, &vk, &claim);
+        let proof = self.prove(env, &inputs);
+
+        let mut seal = Bytes::new(env);
+        seal.append(&Bytes::from(proof.a));
+        seal.append(&Bytes::from(proof.b));
+        seal.append(&Bytes::from(proof.c));
+        seal
+    }
+
+    /// Proof for raw public inputs
+    pub fn prove(&self, env: &Env, inputs: &Vec<Fr>) -> Groth16Proof {
+        assert_eq!(inputs.len(), PUBLIC_INPUT_COUNT);
+        let mut scalars = [ScalarField::from(0u64); PUBLIC_INPUT_COUNT as usize];
+        for (scalar, input) in scalars.iter_mut().zip(inputs.iter()) {
+            *scalar = ScalarField::from_be_bytes_mod_order(&input.to_bytes().to_array());
+        }
+
+        let circuit = ReceiptCircuit { inputs: scalars };
+        let proof = Groth16::<Bn254>::prove(&self.pk, circuit, &mut *self.rng.borrow_mut())
+            .expect("receipt proof");
+        Groth16Proof {
+            a: g1_bytes(env, &proof.a),
+            b: g2_bytes(env, &proof.b),
+            c: g1_bytes(env, &proof.c),
+        }
+    }
+}
+
+/// Split a seal into proof points
+pub fn proof_from_seal(env: &Env, seal: &Bytes) -> Groth16Proof {
+    assert_eq!(seal.len() as usize, SEAL_LEN);
+    let mut bytes = [0u8; SEAL_LEN];
+    seal.copy_into_slice(&mut bytes);
+    Groth16Proof {
+        a: BytesN::from_array(env, bytes[0..64].try_into().unwrap()),
+        b: BytesN::from_array(env, bytes[64..192].try_into().unwrap()),
+        c: BytesN::from_array(env, bytes[192..256].try_into().unwrap()),
+    }
+}
+
+fn fq_bytes(value: &Fq) -> [u8; 32] {
+    value.into_bigint().to_bytes_be().try_into().unwrap()
+}
+
+/// G1 point as x || y, big-endian
+fn g1_bytes(env: &Env, point: &G1Affine) -> BytesN<64> {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&fq_bytes(&point.x));
+    bytes[32..].copy_from_slice(&fq_bytes(&point.y));
+    BytesN::from_array(env, &bytes)
+}
+
+/// G2 point as x.c1 || x.c0 || y.c1 || y.c0, big-endian
+fn g2_bytes(env: &Env, point: &G2Affine) -> BytesN<128> {
+    let mut bytes = [0u8; 128];
+    bytes[..32].copy_from_slice(&fq_bytes(&point.x.c1));
+    bytes[32..64].copy_from_slice(&fq_bytes(&point.x.c0));
+    bytes[64..96].copy_from_slice(&fq_bytes(&point.y.c1));
+    bytes[96..].copy_from_slice(&fq_bytes(&point.y.c0));
+    BytesN::from_array(env, &bytes)
+}
@@ -0,0 +1,84 @@
+//! Round trips through the verifier contract under the stand-in key. These
+//! only exercise the prover itself: its seals are not RISC Zero receipts.
+
+use groth16_verifier::{Groth16VerifierContract, Groth16VerifierContractClient, VerifierError};
+use receipt_prover::{proof_from_seal, ReceiptProver};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+const SEED: u64 = 0x4842_4d4c;
+
+fn deploy(env: &Env, prover: &ReceiptProver) -> Groth16VerifierContractClient<'static> {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    let contract_id = env.register(Groth16VerifierContract, (admin.clone(),));
+    let client = Groth16VerifierContractClient::new(env, &contract_id);
+    client.init_vk(&admin, &prover.verification_key(env));
+    client
+}
+
+fn journal(env: &Env) -> Bytes {
+    Bytes::from_array(env, &core::array::from_fn::<u8, 73, _>(|i| i as u8))
+}
+
+#[test]
+fn test_seal_verifies_under_own_key() {
+    let env = Env::default();
+    let prover = ReceiptProver::new(SEED, [0x11; 32], [0x22; 32]);
+    let client = deploy(&env, &prover);
+
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let seal = prover.seal(&env, &image_id, &journal(&env));
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal(&env)).into();
+    assert!(client.verify(&proof_from_seal(&env, &seal), &image_id, &journal_hash));
+}
+
+#[test]
+fn test_seal_rejected_for_flipped_journal_byte() {
+    let env = Env::default();
+    let prover = ReceiptProver::new(SEED, [0x11; 32], [0x22; 32]);
+    let client = deploy(&env, &prover);
+
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let seal = prover.seal(&env, &image_id, &journal(&env));
+    let mut journal = journal(&env);
+    journal.set(10, journal.get_unchecked(10) ^ 0x01);
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal).into();
+
+    let result = client.try_verify(&proof_from_seal(&env, &seal), &image_id, &journal_hash);
+    assert_eq!(result, Err(Ok(VerifierError::PairingCheckFailed)));
+}
+
+#[test]
+fn test_seal_rejected_for_other_image() {
+    let env = Env::default();
+    let prover = ReceiptProver::new(SEED, [0x11; 32], [0x22; 32]);
+    let client = deploy(&env, &prover);
+
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let other_image_id = BytesN::from_array(&env, &[0xCDu8; 32]);
+    let seal = prover.seal(&env, &image_id, &journal(&env));
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal(&env)).into();
+
+    let result = client.try_verify(
+        &proof_from_seal(&env, &seal),
+        &other_image_id,
+        &journal_hash,
+    );
+    assert_eq!(result, Err(Ok(VerifierError::PairingCheckFailed)));
+}
+
+#[test]
+fn test_seal_rejected_under_other_key() {
+    let env = Env::default();
+    let prover = ReceiptProver::new(SEED, [0x11; 32], [0x22; 32]);
+    let other = ReceiptProver::new(SEED + 1, [0x11; 32], [0x22; 32]);
+    let client = deploy(&env, &other);
+
+    let image_id = BytesN::from_array(&env, &[0xABu8; 32]);
+    let seal = prover.seal(&env, &image_id, &journal(&env));
+    let journal_hash: BytesN<32> = env.crypto().sha256(&journal(&env)).into();
+
+    let result = client.try_verify(&proof_from_seal(&env, &seal), &image_id, &journal_hash);
+    assert_eq!(result, Err(Ok(VerifierError::PairingCheckFailed)));
+}
@@ -1,6 +1,7 @@
 //! # Herbal Moonlight Proof Generator (Host)
 //!
-//! This crate provides the API to generate ZK proofs for cell reveals.
+//! This crate provides the API to generate ZK proofs for cell reveals,
+//! batch reveals, Spirit Sense answers, no-path claims and garden validity.
 //! It runs on the Gardener's machine and produces proofs
 //! that can be verified on-chain.
 //!
@@ -16,10 +17,10 @@
 //!
 //! ```ignore
 //! use herbal_host::generate_cell_reveal_proof;
-//! use herbal_shared::GardenLayout;
+//! use herbal_shared::{GardenLayout, DEFAULT_RULES};
 //!
 //! let garden = GardenLayout::new(cells, salt);
-//! let result = generate_cell_reveal_proof(&garden, 2, 1, 42, pubkey)?;
+//! let result = generate_cell_reveal_proof(&garden, 2, 1, 42, pubkey, &DEFAULT_RULES)?;
 //!
 //! // Send result.seal, result.journal_bytes, result.journal_hash to the contract
 //! ```
@@ -28,11 +29,16 @@ use anyhow::{anyhow, Result};
 use sha2::{Digest, Sha256};
 
 use herbal_shared::{
-    compute_garden_commitment, CellRevealOutput, GardenLayout, PlantType, JOURNAL_LEN,
+    batch_journal_len, compute_garden_commitment, compute_rules_hash, BatchRevealOutput,
+    CellRevealOutput, CreatureKind, GardenLayout, GardenValidityOutput, MoonPhase, NoPathOutput,
+    RevealedCell, RuleSet, SenseKind, SenseOutput, SenseReading, GARDEN_VALIDITY_JOURNAL_LEN,
+    JOURNAL_LEN, MAX_BATCH_CELLS, NO_PATH_JOURNAL_LEN, SENSE_JOURNAL_LEN,
 };
 
 #[cfg(not(feature = "dev"))]
-use herbal_shared::CellRevealInput;
+use herbal_shared::{
+    BatchRevealInput, CellRevealInput, GardenValidityInput, NoPathInput, SenseInput,
+};
 
 // ============================================================================
 // Proof Result
@@ -61,6 +67,94 @@ pub struct ProofResult {
     pub is_dev_mode: bool,
 }
 
+/// Result of generating a Spirit Sense proof
+#[derive(Debug, Clone)]
+pub struct SenseProofResult {
+    /// Public output decoded from the journal
+    pub output: SenseOutput,
+
+    /// Raw journal bytes (for sending to contract)
+    pub journal_bytes: Vec<u8>,
+
+    /// SHA256 hash of the journal (for verification)
+    pub journal_hash: [u8; 32],
+
+    /// Groth16 proof seal (empty in dev mode)
+    pub seal: Vec<u8>,
+
+    /// Image ID of the sense circuit (must match the contract's SenseImageId)
+    pub image_id: [u8; 32],
+
+    /// Whether this is a dev mode proof (no cryptographic security)
+    pub is_dev_mode: bool,
+}
+
+/// Result of generating a no-path proof
+#[derive(Debug, Clone)]
+pub struct NoPathProofResult {
+    /// Public output decoded from the journal
+    pub output: NoPathOutput,
+
+    /// Raw journal bytes (for sending to contract)
+    pub journal_bytes: Vec<u8>,
+
+    /// SHA256 hash of the journal (for verification)
+    pub journal_hash: [u8; 32],
+
+    /// Groth16 proof seal (empty in dev mode)
+    pub seal: Vec<u8>,
+
+    /// Image ID of the no-path circuit (must match the contract's NoPathImageId)
+    pub image_id: [u8; 32],
+
+    /// Whether this is a dev mode proof (no cryptographic security)
+    pub is_dev_mode: bool,
+}
+
+/// Result of generating a batch reveal proof
+#[derive(Debug, Clone)]
+pub struct BatchRevealProofResult {
+    /// Public output decoded from the journal
+    pub output: BatchRevealOutput,
+
+    /// Raw journal bytes (for sending to contract)
+    pub journal_bytes: Vec<u8>,
+
+    /// SHA256 hash of the journal (for verification)
+    pub journal_hash: [u8; 32],
+
+    /// Groth16 proof seal (empty in dev mode)
+    pub seal: Vec<u8>,
+
+    /// Image ID of the batch reveal circuit (must match the contract's BatchRevealImageId)
+    pub image_id: [u8; 32],
+
+    /// Whether this is a dev mode proof (no cryptographic security)
+    pub is_dev_mode: bool,
+}
+
+/// Result of generating a garden validity proof
+#[derive(Debug, Clone)]
+pub struct GardenValidityProofResult {
+    /// Public output decoded from the journal
+    pub output: GardenValidityOutput,
+
+    /// Raw journal bytes (for sending to contract)
+    pub journal_bytes: Vec<u8>,
+
+    /// SHA256 hash of the journal (for verification)
+    pub journal_hash: [u8; 32],
+
+    /// Groth16 proof seal (empty in dev mode)
+    pub seal: Vec<u8>,
+
+    /// Image ID of the garden validity circuit (must match the contract's CommitmentImageId)
+    pub image_id: [u8; 32],
+
+    /// Whether this is a dev mode proof (no cryptographic security)
+    pub is_dev_mode: bool,
+}
+
 // ============================================================================
 // Production Mode - Real ZK Proofs
 // ============================================================================
@@ -72,8 +166,8 @@ pub fn generate_cell_reveal_proof(
     y: u8,
     session_id: u32,
     gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
 ) -> Result<ProofResult> {
-    use risc0_zkvm::{default_prover, ExecutorEnv, InnerReceipt, ProverOpts};
     use herbal_methods::CELL_REVEAL_ELF;
 
     // Compute the expected commitment
@@ -87,34 +181,13 @@ pub fn generate_cell_reveal_proof(
         expected_commitment,
         session_id,
         gardener_pubkey,
+        rules: *rules,
     };
 
-    // Configure the executor environment
-    let env = ExecutorEnv::builder().write(&input)?.build()?;
-
-    // Get prover and options
-    let prover = default_prover();
-
-    // Use Groth16 for on-chain verification
-    // This requires Docker to be running
-    let opts = ProverOpts::groth16();
-
     println!("Generating ZK proof... (this may take 1-2 minutes)");
 
-    // Execute the guest and generate the proof
-    let prove_info = prover.prove_with_opts(env, CELL_REVEAL_ELF, &opts)?;
-
-    let receipt = prove_info.receipt;
-
-    // Verify we got a Groth16 proof
-    if !matches!(&receipt.inner, InnerReceipt::Groth16(_)) {
-        return Err(anyhow!(
-            "Expected Groth16 receipt. Ensure Docker is running for Groth16 proving."
-        ));
-    }
-
-    // Extract journal bytes
-    let journal_bytes = receipt.journal.bytes.clone();
+    // Execute the guest and generate the Groth16 proof
+    let (journal_bytes, seal) = prove_groth16(CELL_REVEAL_ELF, &input)?;
 
     if journal_bytes.len() != JOURNAL_LEN {
         return Err(anyhow!(
@@ -128,12 +201,6 @@ pub fn generate_cell_reveal_proof(
     let output = CellRevealOutput::from_bytes(&journal_bytes)
         .ok_or_else(|| anyhow!("Failed to decode journal output"))?;
 
-    // Extract the seal
-    let seal = match &receipt.inner {
-        InnerReceipt::Groth16(inner) => inner.seal.clone(),
-        _ => return Err(anyhow!("Not a Groth16 receipt")),
-    };
-
     // Compute journal hash
     let journal_hash = sha256(&journal_bytes);
 
@@ -150,6 +217,238 @@ pub fn generate_cell_reveal_proof(
     })
 }
 
+/// Generate a Spirit Sense proof from the Creature's position (x, y)
+#[cfg(not(feature = "dev"))]
+pub fn generate_sense_proof(
+    garden: &GardenLayout,
+    kind: SenseKind,
+    x: u8,
+    y: u8,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<SenseProofResult> {
+    use herbal_methods::{PEEK_ADJACENT_ELF, SMELL_AHEAD_ELF};
+
+    let input = SenseInput {
+        garden: garden.clone(),
+        x,
+        y,
+        expected_commitment: compute_garden_commitment(garden),
+        session_id,
+        gardener_pubkey,
+        rules: *rules,
+    };
+
+    let elf = match kind {
+        SenseKind::PeekAdjacent => PEEK_ADJACENT_ELF,
+        SenseKind::SmellAhead => SMELL_AHEAD_ELF,
+    };
+
+    println!("Generating Spirit Sense proof... (this may take 1-2 minutes)");
+
+    let (journal_bytes, seal) = prove_groth16(elf, &input)?;
+
+    let output = SenseOutput::from_bytes(&journal_bytes)
+        .ok_or_else(|| anyhow!("Failed to decode sense journal output"))?;
+    if output.reading.kind() != kind {
+        return Err(anyhow!("Sense circuit answered the wrong sense"));
+    }
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(SenseProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal,
+        image_id: get_sense_image_id(kind),
+        is_dev_mode: false,
+    })
+}
+
+/// Generate a proof that a `creature_kind` Creature at (x, y) with
+/// `creature_hp` left dies on every path to the house (`moon_clock` is the
+/// session's, and only matters on a rule set whose moon cycles)
+#[cfg(not(feature = "dev"))]
+#[allow(clippy::too_many_arguments)]
+pub fn generate_no_path_proof(
+    garden: &GardenLayout,
+    x: u8,
+    y: u8,
+    creature_kind: CreatureKind,
+    moon_phase: MoonPhase,
+    creature_hp: u8,
+    moon_clock: u8,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<NoPathProofResult> {
+    use herbal_methods::NO_PATH_ELF;
+
+    // Fail fast instead of waiting for the guest to panic
+    let hp = creature_hp as u32;
+    if garden.has_safe_path(
+        x,
+        y,
+        hp,
+        creature_kind,
+        moon_phase,
+        moon_clock as u32,
+        rules,
+    ) {
+        return Err(anyhow!("The Creature still has a path to the house"));
+    }
+
+    let input = NoPathInput {
+        garden: garden.clone(),
+        x,
+        y,
+        creature_kind,
+        moon_phase,
+        creature_hp,
+        moon_clock,
+        expected_commitment: compute_garden_commitment(garden),
+        session_id,
+        gardener_pubkey,
+        rules: *rules,
+    };
+
+    println!("Generating no-path proof... (this may take 1-2 minutes)");
+
+    let (journal_bytes, seal) = prove_groth16(NO_PATH_ELF, &input)?;
+
+    let output = NoPathOutput::from_bytes(&journal_bytes)
+        .ok_or_else(|| anyhow!("Failed to decode no-path journal output"))?;
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(NoPathProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal,
+        image_id: get_no_path_image_id(),
+        is_dev_mode: false,
+    })
+}
+
+/// Generate one proof revealing every cell in `cells`, in order
+#[cfg(not(feature = "dev"))]
+pub fn generate_batch_reveal_proof(
+    garden: &GardenLayout,
+    cells: &[(u8, u8)],
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<BatchRevealProofResult> {
+    use herbal_methods::BATCH_REVEAL_ELF;
+
+    // Fail fast instead of waiting for the guest to panic
+    check_batch(cells, rules)?;
+
+    let input = BatchRevealInput {
+        garden: garden.clone(),
+        cells: cells.to_vec(),
+        expected_commitment: compute_garden_commitment(garden),
+        session_id,
+        gardener_pubkey,
+        rules: *rules,
+    };
+
+    println!(
+        "Generating batch reveal proof for {} cells... (this may take 1-2 minutes)",
+        cells.len()
+    );
+
+    let (journal_bytes, seal) = prove_groth16(BATCH_REVEAL_ELF, &input)?;
+
+    let output = BatchRevealOutput::from_bytes(&journal_bytes)
+        .ok_or_else(|| anyhow!("Failed to decode batch reveal journal output"))?;
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(BatchRevealProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal,
+        image_id: get_batch_reveal_image_id(),
+        is_dev_mode: false,
+    })
+}
+
+/// Generate a proof that the garden's commitment opens to a valid garden,
+/// to submit with `commit_garden`
+#[cfg(not(feature = "dev"))]
+pub fn generate_garden_validity_proof(
+    garden: &GardenLayout,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<GardenValidityProofResult> {
+    use herbal_methods::GARDEN_VALIDITY_ELF;
+
+    // Fail fast instead of waiting for the guest to panic
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow!("{:?}", e))?;
+
+    let input = GardenValidityInput {
+        garden: garden.clone(),
+        session_id,
+        gardener_pubkey,
+        rules: *rules,
+    };
+
+    println!("Generating garden validity proof... (this may take 1-2 minutes)");
+
+    let (journal_bytes, seal) = prove_groth16(GARDEN_VALIDITY_ELF, &input)?;
+
+    let output = GardenValidityOutput::from_bytes(&journal_bytes)
+        .ok_or_else(|| anyhow!("Failed to decode garden validity journal output"))?;
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(GardenValidityProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal,
+        image_id: get_garden_validity_image_id(),
+        is_dev_mode: false,
+    })
+}
+
+/// Run a guest with Groth16 proving, returning (journal bytes, seal)
+///
+/// Requires Docker to be running.
+#[cfg(not(feature = "dev"))]
+fn prove_groth16<T: serde::Serialize>(elf: &[u8], input: &T) -> Result<(Vec<u8>, Vec<u8>)> {
+    use risc0_zkvm::{default_prover, ExecutorEnv, InnerReceipt, ProverOpts};
+
+    // Configure the executor environment
+    let env = ExecutorEnv::builder().write(input)?.build()?;
+
+    // Use Groth16 for on-chain verification
+    let opts = ProverOpts::groth16();
+
+    // Execute the guest and generate the proof
+    let receipt = default_prover().prove_with_opts(env, elf, &opts)?.receipt;
+
+    // Extract the seal (only Groth16 receipts can be verified on-chain)
+    let seal = match &receipt.inner {
+        InnerReceipt::Groth16(inner) => inner.seal.clone(),
+        _ => {
+            return Err(anyhow!(
+                "Expected Groth16 receipt. Ensure Docker is running for Groth16 proving."
+            ))
+        }
+    };
+
+    Ok((receipt.journal.bytes, seal))
+}
+
 // ============================================================================
 // Dev Mode - Mock Proofs (No Docker Required)
 // ============================================================================
@@ -161,8 +460,9 @@ pub fn generate_cell_reveal_proof(
     y: u8,
     session_id: u32,
     gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
 ) -> Result<ProofResult> {
-    generate_cell_reveal_proof_dev(garden, x, y, session_id, gardener_pubkey)
+    generate_cell_reveal_proof_dev(garden, x, y, session_id, gardener_pubkey, rules)
 }
 
 /// Generate a mock proof for development
@@ -178,44 +478,39 @@ pub fn generate_cell_reveal_proof_dev(
     y: u8,
     session_id: u32,
     gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
 ) -> Result<ProofResult> {
     println!("=== DEV MODE ===");
     println!("Generating mock proof (no ZK execution)");
     println!();
 
     // Validate inputs (same as guest would do)
-    if x >= 5 || y >= 5 {
+    if x as u32 >= rules.board_width || y as u32 >= rules.board_height {
         return Err(anyhow!("Invalid coordinates: ({}, {})", x, y));
     }
 
-    // Validate garden
-    garden.validate().map_err(|e| anyhow!("{:?}", e))?;
+    // Validate garden under the session's rules
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow!("{:?}", e))?;
 
     // Compute commitment
     let commitment = compute_garden_commitment(garden);
 
     // Extract cell content (same logic as guest)
-    let cell_index = (y as usize) * 5 + (x as usize);
-    let cell_value = garden.cells[cell_index];
-    let plant_type = PlantType::from_u8(cell_value).unwrap_or(PlantType::Empty);
-
-    let (has_plant, plant_type_u8, damage) = match plant_type {
-        PlantType::Empty => (false, 0u8, 0u8),
-        PlantType::Lavender => (true, 1u8, 1u8),
-        PlantType::Mint => (true, 2u8, 2u8),
-        PlantType::Mandrake => (true, 3u8, 3u8),
-    };
+    let plant = garden.get_cell(x, y);
 
     // Build the output
     let output = CellRevealOutput {
         garden_commitment: commitment,
         x,
         y,
-        has_plant,
-        plant_type: plant_type_u8,
-        damage,
+        has_plant: plant.is_plant(),
+        plant_type: plant as u8,
+        damage: plant.damage(),
         session_id,
         gardener_pubkey,
+        rules_hash: compute_rules_hash(rules),
     };
 
     // Serialize to journal bytes
@@ -249,17 +544,360 @@ pub fn generate_cell_reveal_proof_dev(
     })
 }
 
+#[cfg(feature = "dev")]
+pub fn generate_sense_proof(
+    garden: &GardenLayout,
+    kind: SenseKind,
+    x: u8,
+    y: u8,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<SenseProofResult> {
+    generate_sense_proof_dev(garden, kind, x, y, session_id, gardener_pubkey, rules)
+}
+
+/// Generate a mock Spirit Sense proof for development
+///
+/// Computes the same reading as the peek-adjacent / smell-ahead guests.
+///
+/// **WARNING**: NOT cryptographically secure! Only use for development.
+pub fn generate_sense_proof_dev(
+    garden: &GardenLayout,
+    kind: SenseKind,
+    x: u8,
+    y: u8,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<SenseProofResult> {
+    println!("=== DEV MODE ===");
+    println!("Generating mock sense proof (no ZK execution)");
+    println!();
+
+    // Validate inputs (same as guest would do)
+    if x as u32 >= rules.board_width || y as u32 >= rules.board_height {
+        return Err(anyhow!("Invalid coordinates: ({}, {})", x, y));
+    }
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow!("{:?}", e))?;
+
+    let reading = match kind {
+        SenseKind::PeekAdjacent => {
+            let (left, right) = garden.peek_adjacent(x, y);
+            SenseReading::Adjacent { left, right }
+        }
+        SenseKind::SmellAhead => SenseReading::Ahead {
+            plants: garden.plants_ahead(y),
+        },
+    };
+
+    let output = SenseOutput {
+        garden_commitment: compute_garden_commitment(garden),
+        x,
+        y,
+        reading,
+        session_id,
+        gardener_pubkey,
+        rules_hash: compute_rules_hash(rules),
+    };
+
+    let journal_bytes = output.to_bytes().to_vec();
+    if journal_bytes.len() != SENSE_JOURNAL_LEN {
+        return Err(anyhow!(
+            "Journal length mismatch: expected {}, got {}",
+            SENSE_JOURNAL_LEN,
+            journal_bytes.len()
+        ));
+    }
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(SenseProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal: Vec::new(),
+        image_id: get_sense_image_id(kind),
+        is_dev_mode: true,
+    })
+}
+
+#[cfg(feature = "dev")]
+#[allow(clippy::too_many_arguments)]
+pub fn generate_no_path_proof(
+    garden: &GardenLayout,
+    x: u8,
+    y: u8,
+    creature_kind: CreatureKind,
+    moon_phase: MoonPhase,
+    creature_hp: u8,
+    moon_clock: u8,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<NoPathProofResult> {
+    generate_no_path_proof_dev(
+        garden,
+        x,
+        y,
+        creature_kind,
+        moon_phase,
+        creature_hp,
+        moon_clock,
+        session_id,
+        gardener_pubkey,
+        rules,
+    )
+}
+
+/// Generate a mock no-path proof for development
+///
+/// Runs the same path search as the no-path guest.
+///
+/// **WARNING**: NOT cryptographically secure! Only use for development.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_no_path_proof_dev(
+    garden: &GardenLayout,
+    x: u8,
+    y: u8,
+    creature_kind: CreatureKind,
+    moon_phase: MoonPhase,
+    creature_hp: u8,
+    moon_clock: u8,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<NoPathProofResult> {
+    println!("=== DEV MODE ===");
+    println!("Generating mock no-path proof (no ZK execution)");
+    println!();
+
+    // Validate inputs (same as guest would do)
+    if x as u32 >= rules.board_width || y as u32 >= rules.board_height {
+        return Err(anyhow!("Invalid coordinates: ({}, {})", x, y));
+    }
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow!("{:?}", e))?;
+
+    let hp = creature_hp as u32;
+    if garden.has_safe_path(
+        x,
+        y,
+        hp,
+        creature_kind,
+        moon_phase,
+        moon_clock as u32,
+        rules,
+    ) {
+        return Err(anyhow!("The Creature still has a path to the house"));
+    }
+
+    let output = NoPathOutput {
+        garden_commitment: compute_garden_commitment(garden),
+        x,
+        y,
+        creature_kind,
+        moon_phase,
+        creature_hp,
+        moon_clock,
+        session_id,
+        gardener_pubkey,
+        rules_hash: compute_rules_hash(rules),
+    };
+
+    let journal_bytes = output.to_bytes().to_vec();
+    if journal_bytes.len() != NO_PATH_JOURNAL_LEN {
+        return Err(anyhow!(
+            "Journal length mismatch: expected {}, got {}",
+            NO_PATH_JOURNAL_LEN,
+            journal_bytes.len()
+        ));
+    }
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(NoPathProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal: Vec::new(),
+        image_id: get_no_path_image_id(),
+        is_dev_mode: true,
+    })
+}
+
+#[cfg(feature = "dev")]
+pub fn generate_batch_reveal_proof(
+    garden: &GardenLayout,
+    cells: &[(u8, u8)],
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<BatchRevealProofResult> {
+    generate_batch_reveal_proof_dev(garden, cells, session_id, gardener_pubkey, rules)
+}
+
+/// Generate a mock batch reveal proof for development
+///
+/// **WARNING**: NOT cryptographically secure! Only use for development.
+pub fn generate_batch_reveal_proof_dev(
+    garden: &GardenLayout,
+    cells: &[(u8, u8)],
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<BatchRevealProofResult> {
+    println!("=== DEV MODE ===");
+    println!("Generating mock batch reveal proof (no ZK execution)");
+    println!();
+
+    // Validate inputs (same as guest would do)
+    check_batch(cells, rules)?;
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow!("{:?}", e))?;
+
+    let output = BatchRevealOutput {
+        garden_commitment: compute_garden_commitment(garden),
+        cells: cells
+            .iter()
+            .map(|&(x, y)| RevealedCell::from_garden(garden, x, y))
+            .collect(),
+        session_id,
+        gardener_pubkey,
+        rules_hash: compute_rules_hash(rules),
+    };
+
+    let journal_bytes = output.to_bytes();
+    if journal_bytes.len() != batch_journal_len(cells.len()) {
+        return Err(anyhow!(
+            "Journal length mismatch: expected {}, got {}",
+            batch_journal_len(cells.len()),
+            journal_bytes.len()
+        ));
+    }
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(BatchRevealProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal: Vec::new(),
+        image_id: get_batch_reveal_image_id(),
+        is_dev_mode: true,
+    })
+}
+
+#[cfg(feature = "dev")]
+pub fn generate_garden_validity_proof(
+    garden: &GardenLayout,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<GardenValidityProofResult> {
+    generate_garden_validity_proof_dev(garden, session_id, gardener_pubkey, rules)
+}
+
+/// Generate a mock garden validity proof for development
+///
+/// **WARNING**: NOT cryptographically secure! Only use for development.
+pub fn generate_garden_validity_proof_dev(
+    garden: &GardenLayout,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+    rules: &RuleSet,
+) -> Result<GardenValidityProofResult> {
+    println!("=== DEV MODE ===");
+    println!("Generating mock garden validity proof (no ZK execution)");
+    println!();
+
+    // Validate garden under the session's rules (same as guest would do)
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow!("{:?}", e))?;
+
+    let output = GardenValidityOutput {
+        garden_commitment: compute_garden_commitment(garden),
+        session_id,
+        gardener_pubkey,
+        rules_hash: compute_rules_hash(rules),
+    };
+
+    let journal_bytes = output.to_bytes().to_vec();
+    if journal_bytes.len() != GARDEN_VALIDITY_JOURNAL_LEN {
+        return Err(anyhow!(
+            "Journal length mismatch: expected {}, got {}",
+            GARDEN_VALIDITY_JOURNAL_LEN,
+            journal_bytes.len()
+        ));
+    }
+
+    let journal_hash = sha256(&journal_bytes);
+
+    Ok(GardenValidityProofResult {
+        output,
+        journal_bytes,
+        journal_hash,
+        seal: Vec::new(),
+        image_id: get_garden_validity_image_id(),
+        is_dev_mode: true,
+    })
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
 /// Get the image ID of the cell reveal circuit
 pub fn get_image_id() -> [u8; 32] {
-    use herbal_methods::CELL_REVEAL_ID;
+    image_id_bytes(herbal_methods::CELL_REVEAL_ID)
+}
+
+/// Get the image ID of a Spirit Sense circuit
+pub fn get_sense_image_id(kind: SenseKind) -> [u8; 32] {
+    match kind {
+        SenseKind::PeekAdjacent => image_id_bytes(herbal_methods::PEEK_ADJACENT_ID),
+        SenseKind::SmellAhead => image_id_bytes(herbal_methods::SMELL_AHEAD_ID),
+    }
+}
 
+/// Get the image ID of the no-path circuit
+pub fn get_no_path_image_id() -> [u8; 32] {
+    image_id_bytes(herbal_methods::NO_PATH_ID)
+}
+
+/// Get the image ID of the batch reveal circuit
+pub fn get_batch_reveal_image_id() -> [u8; 32] {
+    image_id_bytes(herbal_methods::BATCH_REVEAL_ID)
+}
+
+/// Get the image ID of the garden validity circuit
+pub fn get_garden_validity_image_id() -> [u8; 32] {
+    image_id_bytes(herbal_methods::GARDEN_VALIDITY_ID)
+}
+
+/// Check a batch has 1..=MAX_BATCH_CELLS cells, all on the board
+fn check_batch(cells: &[(u8, u8)], rules: &RuleSet) -> Result<()> {
+    if cells.is_empty() || cells.len() > MAX_BATCH_CELLS {
+        return Err(anyhow!("A batch must reveal 1..={} cells", MAX_BATCH_CELLS));
+    }
+    for &(x, y) in cells {
+        if x as u32 >= rules.board_width || y as u32 >= rules.board_height {
+            return Err(anyhow!("Invalid coordinates: ({}, {})", x, y));
+        }
+    }
+    Ok(())
+}
+
+/// Convert an image ID as exported by the methods crate to bytes
+fn image_id_bytes(image_id: [u32; 8]) -> [u8; 32] {
     #[cfg(not(feature = "dev"))]
     {
-        let digest: risc0_zkvm::sha::Digest = CELL_REVEAL_ID.into();
+        let digest: risc0_zkvm::sha::Digest = image_id.into();
         let mut id = [0u8; 32];
         id.copy_from_slice(digest.as_bytes());
         id
@@ -267,10 +905,10 @@ pub fn get_image_id() -> [u8; 32] {
 
     #[cfg(feature = "dev")]
     {
-        // In dev mode, CELL_REVEAL_ID is already [u32; 8]
+        // In dev mode, the mock IDs are [u32; 8]
         // Convert to [u8; 32]
         let mut id = [0u8; 32];
-        for (i, word) in CELL_REVEAL_ID.iter().enumerate() {
+        for (i, word) in image_id.iter().enumerate() {
             let bytes = word.to_le_bytes();
             id[i * 4..(i + 1) * 4].copy_from_slice(&bytes);
         }
@@ -295,7 +933,7 @@ fn sha256(data: &[u8]) -> [u8; 32] {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use herbal_shared::{GRID_CELLS, SALT_LEN};
+    use herbal_shared::{PlantType, DEFAULT_RULES, GRID_CELLS, SALT_LEN};
 
     fn create_test_garden() -> GardenLayout {
         let mut cells = [0u8; GRID_CELLS];
@@ -321,7 +959,8 @@ mod tests {
         let pubkey = [42u8; 32];
 
         // Test revealing empty cell
-        let result = generate_cell_reveal_proof_dev(&garden, 1, 0, 123, pubkey).unwrap();
+        let result =
+            generate_cell_reveal_proof_dev(&garden, 1, 0, 123, pubkey, &DEFAULT_RULES).unwrap();
         assert!(!result.output.has_plant);
         assert_eq!(result.output.x, 1);
         assert_eq!(result.output.y, 0);
@@ -329,7 +968,8 @@ mod tests {
         assert!(result.seal.is_empty()); // Dev mode has empty seal
 
         // Test revealing cell with plant
-        let result = generate_cell_reveal_proof_dev(&garden, 0, 0, 456, pubkey).unwrap();
+        let result =
+            generate_cell_reveal_proof_dev(&garden, 0, 0, 456, pubkey, &DEFAULT_RULES).unwrap();
         assert!(result.output.has_plant);
         assert_eq!(result.output.plant_type, 1); // Lavender
         assert_eq!(result.output.damage, 1);
@@ -340,10 +980,231 @@ mod tests {
         let garden = create_test_garden();
         let pubkey = [42u8; 32];
 
-        let result = generate_cell_reveal_proof_dev(&garden, 0, 0, 789, pubkey).unwrap();
+        let result =
+            generate_cell_reveal_proof_dev(&garden, 0, 0, 789, pubkey, &DEFAULT_RULES).unwrap();
 
         // Verify journal hash matches
         let computed_hash = sha256(&result.journal_bytes);
         assert_eq!(result.journal_hash, computed_hash);
     }
+
+    #[test]
+    fn test_dev_proof_matches_rules() {
+        // The dev prover must produce the journal the guest would for every plant
+        for rule in herbal_rules::PLANTS.iter() {
+            let mut cells = [0u8; GRID_CELLS];
+            cells[0] = rule.id;
+            let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+
+            let result =
+                generate_cell_reveal_proof_dev(&garden, 0, 0, 1, [0u8; 32], &DEFAULT_RULES)
+                    .unwrap();
+            assert!(result.output.has_plant);
+            assert_eq!(result.output.plant_type, rule.id);
+            assert_eq!(result.output.damage, rule.base_damage);
+        }
+    }
+
+    #[test]
+    fn test_dev_sense_proof_generation() {
+        let garden = create_test_garden();
+        let pubkey = [42u8; 32];
+
+        // (1,0) sits between Lavender (0,0) and Mint (2,0)
+        let result = generate_sense_proof_dev(
+            &garden,
+            SenseKind::PeekAdjacent,
+            1,
+            0,
+            7,
+            pubkey,
+            &DEFAULT_RULES,
+        )
+        .unwrap();
+        assert_eq!(
+            result.output.reading,
+            SenseReading::Adjacent {
+                left: true,
+                right: true
+            }
+        );
+        assert_eq!(result.journal_bytes.len(), SENSE_JOURNAL_LEN);
+        assert_eq!(result.journal_hash, sha256(&result.journal_bytes));
+        assert!(result.seal.is_empty());
+
+        // Rows 1-2 hold Mandrake (1,1) and Mint (2,2)
+        let result = generate_sense_proof_dev(
+            &garden,
+            SenseKind::SmellAhead,
+            1,
+            0,
+            7,
+            pubkey,
+            &DEFAULT_RULES,
+        )
+        .unwrap();
+        assert_eq!(result.output.reading, SenseReading::Ahead { plants: 2 });
+        assert_eq!(result.image_id, get_sense_image_id(SenseKind::SmellAhead));
+        assert_ne!(result.image_id, get_image_id());
+    }
+
+    #[test]
+    fn test_dev_proof_bound_to_rules() {
+        let garden = create_test_garden();
+        let sparse = RuleSet {
+            max_plants: 4,
+            ..DEFAULT_RULES
+        };
+
+        let result = generate_cell_reveal_proof_dev(&garden, 0, 0, 1, [0u8; 32], &sparse).unwrap();
+        assert_eq!(result.output.rules_hash, compute_rules_hash(&sparse));
+        assert_eq!(&result.journal_bytes[73..105], &compute_rules_hash(&sparse));
+
+        // The 4-plant test garden is too big for a 3-plant rule set
+        let tight = RuleSet {
+            max_plants: 3,
+            ..DEFAULT_RULES
+        };
+        assert!(generate_cell_reveal_proof_dev(&garden, 0, 0, 1, [0u8; 32], &tight).is_err());
+    }
+
+    #[test]
+    fn test_dev_proof_on_larger_board() {
+        let rules = RuleSet {
+            board_width: 9,
+            board_height: 9,
+            ..DEFAULT_RULES
+        };
+        let mut cells = vec![0u8; 81];
+        cells[7 * 9 + 8] = PlantType::Mint as u8; // (8,7)
+        let garden = GardenLayout::with_size(9, 9, cells, [0u8; SALT_LEN]);
+
+        let result = generate_cell_reveal_proof_dev(&garden, 8, 7, 1, [0u8; 32], &rules).unwrap();
+        assert_eq!(result.output.plant_type, PlantType::Mint as u8);
+        assert_eq!((result.output.x, result.output.y), (8, 7));
+
+        let result =
+            generate_sense_proof_dev(&garden, SenseKind::SmellAhead, 4, 5, 1, [0u8; 32], &rules)
+                .unwrap();
+        assert_eq!(result.output.reading, SenseReading::Ahead { plants: 1 });
+
+        // Off the 9x9 board, and the standard rules reject the 9x9 layout
+        assert!(generate_cell_reveal_proof_dev(&garden, 9, 0, 1, [0u8; 32], &rules).is_err());
+        let standard = generate_cell_reveal_proof_dev(&garden, 0, 0, 1, [0u8; 32], &DEFAULT_RULES);
+        assert!(standard.is_err());
+    }
+
+    #[test]
+    fn test_dev_no_path_proof_generation() {
+        // Mandrakes across row 1 and in (1,2)..(3,2); (0,2) and (4,2) stay open
+        let rules = RuleSet {
+            max_plants: 8,
+            ..DEFAULT_RULES
+        };
+        let mut cells = [0u8; GRID_CELLS];
+        for x in 0..5 {
+            cells[5 + x] = PlantType::Mandrake as u8;
+        }
+        for x in 1..4 {
+            cells[10 + x] = PlantType::Mandrake as u8;
+        }
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+        let moon = MoonPhase::Balanced;
+
+        // A Shadow Wolf at (2,1) can only go straight into (2,2)
+        let result = generate_no_path_proof_dev(
+            &garden,
+            2,
+            1,
+            CreatureKind::ShadowWolf,
+            moon,
+            2,
+            0,
+            9,
+            [42u8; 32],
+            &rules,
+        )
+        .unwrap();
+        assert_eq!(result.output.creature_kind, CreatureKind::ShadowWolf);
+        assert_eq!(result.output.creature_hp, 2);
+        assert_eq!(result.journal_bytes.len(), NO_PATH_JOURNAL_LEN);
+        assert_eq!(result.journal_hash, sha256(&result.journal_bytes));
+        assert_eq!(result.image_id, get_no_path_image_id());
+        assert!(result.seal.is_empty());
+
+        // A Ghost reaches the open corner, so there is nothing to prove
+        let ghost = generate_no_path_proof_dev(
+            &garden,
+            1,
+            1,
+            CreatureKind::Ghost,
+            moon,
+            2,
+            0,
+            9,
+            [42u8; 32],
+            &rules,
+        );
+        assert!(ghost.is_err());
+    }
+
+    #[test]
+    fn test_dev_batch_reveal_proof_generation() {
+        let garden = create_test_garden();
+        let cells = [(1, 1), (1, 2), (2, 2)];
+
+        let result =
+            generate_batch_reveal_proof_dev(&garden, &cells, 9, [42u8; 32], &DEFAULT_RULES)
+                .unwrap();
+        assert_eq!(result.journal_bytes.len(), batch_journal_len(3));
+        assert_eq!(result.journal_hash, sha256(&result.journal_bytes));
+        assert_eq!(result.image_id, get_batch_reveal_image_id());
+        assert!(result.seal.is_empty());
+
+        // Each cell matches what a single cell reveal would output
+        for (cell, &(x, y)) in result.output.cells.iter().zip(&cells) {
+            let single =
+                generate_cell_reveal_proof_dev(&garden, x, y, 9, [42u8; 32], &DEFAULT_RULES)
+                    .unwrap();
+            assert_eq!(cell.plant_type, single.output.plant_type);
+            assert_eq!(cell.damage, single.output.damage);
+        }
+        // Same session binding as the single-cell journal
+        let single =
+            generate_cell_reveal_proof_dev(&garden, 1, 1, 9, [42u8; 32], &DEFAULT_RULES).unwrap();
+        let tail = result.journal_bytes.len() - 68;
+        assert_eq!(&result.journal_bytes[tail..], &single.journal_bytes[37..]);
+
+        assert!(
+            generate_batch_reveal_proof_dev(&garden, &[], 9, [0u8; 32], &DEFAULT_RULES).is_err()
+        );
+        let off_board = [(1, 1), (5, 0)];
+        assert!(
+            generate_batch_reveal_proof_dev(&garden, &off_board, 9, [0u8; 32], &DEFAULT_RULES)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_dev_garden_validity_proof_generation() {
+        let garden = create_test_garden();
+
+        let result =
+            generate_garden_validity_proof_dev(&garden, 9, [42u8; 32], &DEFAULT_RULES).unwrap();
+        assert_eq!(
+            result.output.garden_commitment,
+            compute_garden_commitment(&garden)
+        );
+        assert_eq!(result.journal_bytes.len(), GARDEN_VALIDITY_JOURNAL_LEN);
+        assert_eq!(result.journal_hash, sha256(&result.journal_bytes));
+        assert_eq!(result.image_id, get_garden_validity_image_id());
+        assert!(result.seal.is_empty());
+
+        // A garden that could never be revealed has no validity proof
+        let mut cells = [0u8; GRID_CELLS];
+        cells[22] = PlantType::Mint as u8; // (2,4), house row
+        let invalid = GardenLayout::new(cells, [1u8; SALT_LEN]);
+        let result = generate_garden_validity_proof_dev(&invalid, 9, [42u8; 32], &DEFAULT_RULES);
+        assert!(result.is_err());
+    }
 }
@@ -9,15 +9,23 @@
 //!     --session-id 42 \
 //!     --cell-x 2 --cell-y 1 \
 //!     --garden-file ~/.herbal/garden.json \
-//!     --pubkey abc123...
+//!     --pubkey abc123... \
+//!     --rules-file rules.json   # optional, defaults to the standard rules
 //! ```
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use std::fs;
 
-use herbal_host::{generate_cell_reveal_proof, get_image_id};
-use herbal_shared::{compute_garden_commitment, GardenLayout, GRID_CELLS, SALT_LEN};
+use herbal_host::{
+    generate_batch_reveal_proof, generate_cell_reveal_proof, generate_garden_validity_proof,
+    generate_no_path_proof, generate_sense_proof, get_batch_reveal_image_id,
+    get_garden_validity_image_id, get_image_id, get_no_path_image_id, get_sense_image_id,
+};
+use herbal_shared::{
+    compute_garden_commitment, CreatureKind, GardenLayout, MoonPhase, RuleSet, SenseKind,
+    SenseReading, DEFAULT_RULES, SALT_LEN,
+};
 
 #[derive(Parser)]
 #[command(name = "herbal-prover")]
@@ -36,11 +44,11 @@ enum Commands {
         #[arg(short, long)]
         session_id: u32,
 
-        /// X coordinate of the cell to reveal (0-4)
+        /// X coordinate of the cell to reveal (0 to board width - 1)
         #[arg(short = 'x', long)]
         cell_x: u8,
 
-        /// Y coordinate of the cell to reveal (0-4)
+        /// Y coordinate of the cell to reveal (0 to board height - 1)
         #[arg(short = 'y', long)]
         cell_y: u8,
 
@@ -48,10 +56,152 @@ enum Commands {
         #[arg(short, long)]
         garden_file: String,
 
-        /// Gardener's public key (hex, 64 chars)
+        /// Gardener's public key (hex, 64 chars): the raw ed25519 key of the
+        /// gardener's Stellar account. Must match the session's gardener on-chain.
         #[arg(short, long)]
         pubkey: String,
 
+        /// Path to the session's rule set JSON file (standard rules if omitted).
+        /// Must match the rule set the session was started with on-chain.
+        #[arg(short, long)]
+        rules_file: Option<String>,
+
+        /// Output format: hex (default) or json
+        #[arg(short, long, default_value = "hex")]
+        output: String,
+    },
+
+    /// Generate one proof revealing several cells, in order
+    RevealBatch {
+        /// Session ID of the game
+        #[arg(short, long)]
+        session_id: u32,
+
+        /// Cells to reveal as x,y pairs, e.g. `--cells 2,2 2,3`
+        #[arg(short, long, num_args = 1.., required = true)]
+        cells: Vec<String>,
+
+        /// Path to the garden layout JSON file
+        #[arg(short, long)]
+        garden_file: String,
+
+        /// Gardener's public key (hex, 64 chars), as for `prove`
+        #[arg(short, long)]
+        pubkey: String,
+
+        /// Path to the session's rule set JSON file, as for `prove`
+        #[arg(short, long)]
+        rules_file: Option<String>,
+
+        /// Output format: hex (default) or json
+        #[arg(short, long, default_value = "hex")]
+        output: String,
+    },
+
+    /// Disclose a finished game's garden: the full preimage, or a proof of chosen cells
+    Disclose {
+        /// Session ID of the game
+        #[arg(short, long)]
+        session_id: u32,
+
+        /// Cells to prove as x,y pairs; omit to disclose the whole garden preimage
+        #[arg(short, long, num_args = 1..)]
+        cells: Vec<String>,
+
+        /// Path to the garden layout JSON file
+        #[arg(short, long)]
+        garden_file: String,
+
+        /// Gardener's public key (hex, 64 chars), as for `prove`
+        #[arg(short, long)]
+        pubkey: String,
+
+        /// Path to the session's rule set JSON file, as for `prove`
+        #[arg(short, long)]
+        rules_file: Option<String>,
+
+        /// Output format: hex (default) or json
+        #[arg(short, long, default_value = "hex")]
+        output: String,
+    },
+
+    /// Generate a Spirit Sense proof (peek or smell) from the Creature's position
+    Sense {
+        /// Sense to answer: peek (Peek Adjacent) or smell (Smell Ahead)
+        #[arg(short, long)]
+        kind: String,
+
+        /// Session ID of the game
+        #[arg(short, long)]
+        session_id: u32,
+
+        /// X coordinate of the Creature
+        #[arg(short = 'x', long)]
+        cell_x: u8,
+
+        /// Y coordinate of the Creature
+        #[arg(short = 'y', long)]
+        cell_y: u8,
+
+        /// Path to the garden layout JSON file
+        #[arg(short, long)]
+        garden_file: String,
+
+        /// Gardener's public key (hex, 64 chars), as for `prove`
+        #[arg(short, long)]
+        pubkey: String,
+
+        /// Path to the session's rule set JSON file, as for `prove`
+        #[arg(short, long)]
+        rules_file: Option<String>,
+
+        /// Output format: hex (default) or json
+        #[arg(short, long, default_value = "hex")]
+        output: String,
+    },
+
+    /// Prove that every path from the Creature's position to the house is lethal
+    NoPath {
+        /// Session ID of the game
+        #[arg(short, long)]
+        session_id: u32,
+
+        /// X coordinate of the Creature
+        #[arg(short = 'x', long)]
+        cell_x: u8,
+
+        /// Y coordinate of the Creature
+        #[arg(short = 'y', long)]
+        cell_y: u8,
+
+        /// Creature kind: ghost, moth (Moon Moth) or wolf (Shadow Wolf)
+        #[arg(short, long, default_value = "ghost")]
+        kind: String,
+
+        /// Moon phase of the session: full, new or balanced
+        #[arg(short, long)]
+        moon: String,
+
+        /// HP the Creature has left
+        #[arg(long)]
+        hp: u8,
+
+        /// Moon clock of the session (only used when the rule set's moon cycles)
+        #[arg(long, default_value_t = 0)]
+        moon_clock: u8,
+
+        /// Path to the garden layout JSON file
+        #[arg(short, long)]
+        garden_file: String,
+
+        /// Gardener's public key (hex, 64 chars), as for `prove`
+        #[arg(short, long)]
+        pubkey: String,
+
+        /// Path to the session's rule set JSON file, as for `prove`
+        #[arg(short, long)]
+        rules_file: Option<String>,
+
         /// Output format: hex (default) or json
         #[arg(short, long, default_value = "hex")]
         output: String,
@@ -62,6 +212,22 @@ enum Commands {
         /// Path to the garden layout JSON file
         #[arg(short, long)]
         garden_file: String,
+
+        /// Path to the session's rule set JSON file, as for `prove`
+        #[arg(short, long)]
+        rules_file: Option<String>,
+
+        /// Also prove the commitment opens to a valid garden for this session
+        #[arg(short, long, requires = "pubkey")]
+        session_id: Option<u32>,
+
+        /// Gardener's public key (hex, 64 chars), as for `prove`; needed with --session-id
+        #[arg(short, long)]
+        pubkey: Option<String>,
+
+        /// Output format of the validity proof: hex (default) or json
+        #[arg(short, long, default_value = "hex")]
+        output: String,
     },
 
     /// Create a new garden layout interactively
@@ -69,6 +235,10 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "garden.json")]
         output: String,
+
+        /// Rule set JSON file giving the board size and plant limit (standard 5x5 if omitted)
+        #[arg(short, long)]
+        rules_file: Option<String>,
     },
 
     /// Get the image ID of the ZK circuit
@@ -85,12 +255,127 @@ fn main() -> Result<()> {
             cell_y,
             garden_file,
             pubkey,
+            rules_file,
+            output,
+        } => {
+            let rules = load_rules(rules_file.as_deref())?;
+            prove_command(
+                session_id,
+                cell_x,
+                cell_y,
+                &garden_file,
+                &pubkey,
+                &rules,
+                &output,
+            )
+        }
+        Commands::RevealBatch {
+            session_id,
+            cells,
+            garden_file,
+            pubkey,
+            rules_file,
+            output,
+        } => reveal_batch_command(
+            session_id,
+            &cells,
+            &garden_file,
+            &pubkey,
+            &load_rules(rules_file.as_deref())?,
+            &output,
+        ),
+        Commands::Disclose {
+            session_id,
+            cells,
+            garden_file,
+            pubkey,
+            rules_file,
+            output,
+        } => {
+            let rules = load_rules(rules_file.as_deref())?;
+            if cells.is_empty() {
+                disclose_garden_command(&garden_file, &rules, &output)
+            } else {
+                reveal_batch_command(session_id, &cells, &garden_file, &pubkey, &rules, &output)
+            }
+        }
+        Commands::Sense {
+            kind,
+            session_id,
+            cell_x,
+            cell_y,
+            garden_file,
+            pubkey,
+            rules_file,
+            output,
+        } => sense_command(
+            &kind,
+            session_id,
+            cell_x,
+            cell_y,
+            &garden_file,
+            &pubkey,
+            &load_rules(rules_file.as_deref())?,
+            &output,
+        ),
+        Commands::NoPath {
+            session_id,
+            cell_x,
+            cell_y,
+            kind,
+            moon,
+            hp,
+            moon_clock,
+            garden_file,
+            pubkey,
+            rules_file,
             output,
         } => {
-            prove_command(session_id, cell_x, cell_y, &garden_file, &pubkey, &output)
+            let kind = match kind.as_str() {
+                "ghost" => CreatureKind::Ghost,
+                "moth" => CreatureKind::MoonMoth,
+                "wolf" => CreatureKind::ShadowWolf,
+                other => anyhow::bail!("Unknown creature '{}'. Use ghost, moth or wolf", other),
+            };
+            let moon = match moon.as_str() {
+                "full" => MoonPhase::FullMoon,
+                "new" => MoonPhase::NewMoon,
+                "balanced" => MoonPhase::Balanced,
+                other => anyhow::bail!("Unknown moon '{}'. Use full, new or balanced", other),
+            };
+            no_path_command(
+                session_id,
+                cell_x,
+                cell_y,
+                kind,
+                moon,
+                hp,
+                moon_clock,
+                &garden_file,
+                &pubkey,
+                &load_rules(rules_file.as_deref())?,
+                &output,
+            )
+        }
+        Commands::Commit {
+            garden_file,
+            rules_file,
+            session_id,
+            pubkey,
+            output,
+        } => {
+            let rules = load_rules(rules_file.as_deref())?;
+            commit_command(&garden_file, &rules)?;
+            match (session_id, pubkey) {
+                (Some(session_id), Some(pubkey)) => {
+                    commit_proof_command(session_id, &garden_file, &pubkey, &rules, &output)
+                }
+                _ => Ok(()),
+            }
+        }
+        Commands::Create { output, rules_file } => {
+            create_command(&output, &load_rules(rules_file.as_deref())?)
         }
-        Commands::Commit { garden_file } => commit_command(&garden_file),
-        Commands::Create { output } => create_command(&output),
         Commands::ImageId => image_id_command(),
     }
 }
@@ -101,24 +386,17 @@ fn prove_command(
     cell_y: u8,
     garden_file: &str,
     pubkey: &str,
+    rules: &RuleSet,
     output_format: &str,
 ) -> Result<()> {
-    // Validate coordinates
-    if cell_x >= 5 || cell_y >= 5 {
-        anyhow::bail!("Coordinates must be 0-4. Got ({}, {})", cell_x, cell_y);
-    }
+    check_coordinates(cell_x, cell_y, rules)?;
 
     // Load garden from file
     let garden_json = fs::read_to_string(garden_file)?;
     let garden: GardenLayout = serde_json::from_str(&garden_json)?;
 
     // Parse pubkey
-    let pubkey_bytes = hex::decode(pubkey)?;
-    if pubkey_bytes.len() != 32 {
-        anyhow::bail!("Pubkey must be 32 bytes (64 hex chars)");
-    }
-    let mut gardener_pubkey = [0u8; 32];
-    gardener_pubkey.copy_from_slice(&pubkey_bytes);
+    let gardener_pubkey = parse_pubkey(pubkey)?;
 
     println!("=== Herbal Moonlight Prover ===");
     #[cfg(feature = "dev")]
@@ -131,7 +409,8 @@ fn prove_command(
     println!();
 
     // Generate proof
-    let result = generate_cell_reveal_proof(&garden, cell_x, cell_y, session_id, gardener_pubkey)?;
+    let result =
+        generate_cell_reveal_proof(&garden, cell_x, cell_y, session_id, gardener_pubkey, rules)?;
 
     println!("=== PROOF GENERATED ===");
     if result.is_dev_mode {
@@ -144,10 +423,10 @@ fn prove_command(
         // JSON output for programmatic use
         let json = serde_json::json!({
             "dev_mode": result.is_dev_mode,
-            "journal_bytes": hex::encode(&result.journal_bytes),
-            "journal_hash": hex::encode(&result.journal_hash),
-            "seal": hex::encode(&result.seal),
-            "image_id": hex::encode(&result.image_id),
+            "journal_bytes": hex::encode(result.journal_bytes),
+            "journal_hash": hex::encode(result.journal_hash),
+            "seal": hex::encode(result.seal),
+            "image_id": hex::encode(result.image_id),
             "output": {
                 "x": result.output.x,
                 "y": result.output.y,
@@ -155,19 +434,20 @@ fn prove_command(
                 "plant_type": result.output.plant_type,
                 "damage": result.output.damage,
                 "session_id": result.output.session_id,
+                "rules_hash": hex::encode(result.output.rules_hash),
             }
         });
         println!("{}", serde_json::to_string_pretty(&json)?);
     } else {
         // Hex output for manual use
-        println!("journal_bytes: {}", hex::encode(&result.journal_bytes));
-        println!("journal_hash: {}", hex::encode(&result.journal_hash));
+        println!("journal_bytes: {}", hex::encode(result.journal_bytes));
+        println!("journal_hash: {}", hex::encode(result.journal_hash));
         if result.seal.is_empty() {
             println!("seal: (empty - dev mode)");
         } else {
-            println!("seal: {}", hex::encode(&result.seal));
+            println!("seal: {}", hex::encode(result.seal));
         }
-        println!("image_id: {}", hex::encode(&result.image_id));
+        println!("image_id: {}", hex::encode(result.image_id));
         println!();
         println!("Result:");
         println!("  Cell ({}, {})", result.output.x, result.output.y);
@@ -181,13 +461,317 @@ fn prove_command(
     Ok(())
 }
 
-fn commit_command(garden_file: &str) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+fn sense_command(
+    kind: &str,
+    session_id: u32,
+    cell_x: u8,
+    cell_y: u8,
+    garden_file: &str,
+    pubkey: &str,
+    rules: &RuleSet,
+    output_format: &str,
+) -> Result<()> {
+    let kind = match kind {
+        "peek" => SenseKind::PeekAdjacent,
+        "smell" => SenseKind::SmellAhead,
+        other => anyhow::bail!("Unknown sense '{}'. Use peek or smell", other),
+    };
+
+    check_coordinates(cell_x, cell_y, rules)?;
+
+    // Load garden from file
+    let garden_json = fs::read_to_string(garden_file)?;
+    let garden: GardenLayout = serde_json::from_str(&garden_json)?;
+
+    let gardener_pubkey = parse_pubkey(pubkey)?;
+
+    println!("=== Herbal Moonlight Prover (Spirit Sense) ===");
+    println!("Session ID: {}", session_id);
+    println!("Sense: {:?} from ({}, {})", kind, cell_x, cell_y);
+    println!();
+
+    let result = generate_sense_proof(
+        &garden,
+        kind,
+        cell_x,
+        cell_y,
+        session_id,
+        gardener_pubkey,
+        rules,
+    )?;
+
+    if result.is_dev_mode {
+        println!("WARNING: This is a DEV MODE proof with empty seal!");
+        println!();
+    }
+
+    if output_format == "json" {
+        let reading = match result.output.reading {
+            SenseReading::Adjacent { left, right } => {
+                serde_json::json!({ "left_has_plant": left, "right_has_plant": right })
+            }
+            SenseReading::Ahead { plants } => serde_json::json!({ "plants_ahead": plants }),
+        };
+        let json = serde_json::json!({
+            "dev_mode": result.is_dev_mode,
+            "journal_bytes": hex::encode(result.journal_bytes),
+            "journal_hash": hex::encode(result.journal_hash),
+            "seal": hex::encode(result.seal),
+            "image_id": hex::encode(result.image_id),
+            "output": reading,
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!("journal_bytes: {}", hex::encode(result.journal_bytes));
+        println!("journal_hash: {}", hex::encode(result.journal_hash));
+        if result.seal.is_empty() {
+            println!("seal: (empty - dev mode)");
+        } else {
+            println!("seal: {}", hex::encode(result.seal));
+        }
+        println!("image_id: {}", hex::encode(result.image_id));
+        println!();
+        match result.output.reading {
+            SenseReading::Adjacent { left, right } => {
+                println!("Result: Left: {} Right: {}", left, right)
+            }
+            SenseReading::Ahead { plants } => {
+                println!("Result: {} plants detected ahead", plants)
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn reveal_batch_command(
+    session_id: u32,
+    cells: &[String],
+    garden_file: &str,
+    pubkey: &str,
+    rules: &RuleSet,
+    output_format: &str,
+) -> Result<()> {
+    let cells = cells
+        .iter()
+        .map(|cell| parse_cell(cell))
+        .collect::<Result<Vec<_>>>()?;
+    for &(x, y) in &cells {
+        check_coordinates(x, y, rules)?;
+    }
+
+    // Load garden from file
+    let garden_json = fs::read_to_string(garden_file)?;
+    let garden: GardenLayout = serde_json::from_str(&garden_json)?;
+
+    let gardener_pubkey = parse_pubkey(pubkey)?;
+
+    println!("=== Herbal Moonlight Prover (Batch Reveal) ===");
+    println!("Session ID: {}", session_id);
+    println!("Cells: {:?}", cells);
+    println!();
+
+    let result = generate_batch_reveal_proof(&garden, &cells, session_id, gardener_pubkey, rules)?;
+
+    if result.is_dev_mode {
+        println!("WARNING: This is a DEV MODE proof with empty seal!");
+        println!();
+    }
+
+    if output_format == "json" {
+        let json = serde_json::json!({
+            "dev_mode": result.is_dev_mode,
+            "journal_bytes": hex::encode(&result.journal_bytes),
+            "journal_hash": hex::encode(result.journal_hash),
+            "seal": hex::encode(&result.seal),
+            "image_id": hex::encode(result.image_id),
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!("journal_bytes: {}", hex::encode(&result.journal_bytes));
+        println!("journal_hash: {}", hex::encode(result.journal_hash));
+        if result.seal.is_empty() {
+            println!("seal: (empty - dev mode)");
+        } else {
+            println!("seal: {}", hex::encode(&result.seal));
+        }
+        println!("image_id: {}", hex::encode(result.image_id));
+        println!();
+        for cell in &result.output.cells {
+            if cell.has_plant {
+                println!(
+                    "({}, {}): plant type {} ({} damage)",
+                    cell.x, cell.y, cell.plant_type, cell.damage
+                );
+            } else {
+                println!("({}, {}): empty", cell.x, cell.y);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the commitment preimage of a garden for `disclose(Disclosure::Garden)`
+fn disclose_garden_command(garden_file: &str, rules: &RuleSet, output_format: &str) -> Result<()> {
+    let garden_json = fs::read_to_string(garden_file)?;
+    let garden: GardenLayout = serde_json::from_str(&garden_json)?;
+
+    // Only a garden that matches the session's board can match its commitment
+    if let Err(e) = garden.validate_with(rules) {
+        anyhow::bail!("Garden does not match the rule set: {:?}", e);
+    }
+
+    let commitment = compute_garden_commitment(&garden);
+
+    if output_format == "json" {
+        let json = serde_json::json!({
+            "cells": hex::encode(&garden.cells),
+            "salt": hex::encode(garden.salt),
+            "commitment": hex::encode(commitment),
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!("=== Herbal Moonlight Garden Disclosure ===");
+        println!("cells: {}", hex::encode(&garden.cells));
+        println!("salt: {}", hex::encode(garden.salt));
+        println!("commitment: {}", hex::encode(commitment));
+        println!();
+        println!("Submit cells and salt with disclose(session_id, Garden(cells, salt))");
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn no_path_command(
+    session_id: u32,
+    cell_x: u8,
+    cell_y: u8,
+    kind: CreatureKind,
+    moon: MoonPhase,
+    hp: u8,
+    moon_clock: u8,
+    garden_file: &str,
+    pubkey: &str,
+    rules: &RuleSet,
+    output_format: &str,
+) -> Result<()> {
+    check_coordinates(cell_x, cell_y, rules)?;
+
+    // Load garden from file
+    let garden_json = fs::read_to_string(garden_file)?;
+    let garden: GardenLayout = serde_json::from_str(&garden_json)?;
+
+    let gardener_pubkey = parse_pubkey(pubkey)?;
+
+    println!("=== Herbal Moonlight Prover (No Path) ===");
+    println!("Session ID: {}", session_id);
+    println!(
+        "Creature: {:?} at ({}, {}) with {} HP",
+        kind, cell_x, cell_y, hp
+    );
+    println!("Moon: {:?} (clock {})", moon, moon_clock);
+    println!();
+
+    let result = generate_no_path_proof(
+        &garden,
+        cell_x,
+        cell_y,
+        kind,
+        moon,
+        hp,
+        moon_clock,
+        session_id,
+        gardener_pubkey,
+        rules,
+    )?;
+
+    if result.is_dev_mode {
+        println!("WARNING: This is a DEV MODE proof with empty seal!");
+        println!();
+    }
+
+    if output_format == "json" {
+        let json = serde_json::json!({
+            "dev_mode": result.is_dev_mode,
+            "journal_bytes": hex::encode(&result.journal_bytes),
+            "journal_hash": hex::encode(result.journal_hash),
+            "seal": hex::encode(&result.seal),
+            "image_id": hex::encode(result.image_id),
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!("journal_bytes: {}", hex::encode(&result.journal_bytes));
+        println!("journal_hash: {}", hex::encode(result.journal_hash));
+        if result.seal.is_empty() {
+            println!("seal: (empty - dev mode)");
+        } else {
+            println!("seal: {}", hex::encode(&result.seal));
+        }
+        println!("image_id: {}", hex::encode(result.image_id));
+        println!();
+        println!("Result: every path to the house is lethal - submit with claim_no_path()");
+    }
+
+    Ok(())
+}
+
+/// Load a rule set from a JSON file, or the standard rules when no file is given
+fn load_rules(rules_file: Option<&str>) -> Result<RuleSet> {
+    let Some(path) = rules_file else {
+        return Ok(DEFAULT_RULES);
+    };
+    let rules: RuleSet = serde_json::from_str(&fs::read_to_string(path)?)?;
+    if !rules.is_valid() {
+        anyhow::bail!("Rule set in {} is not playable", path);
+    }
+    Ok(rules)
+}
+
+/// Check that (x, y) lies on the rule set's board
+fn check_coordinates(x: u8, y: u8, rules: &RuleSet) -> Result<()> {
+    if x as u32 >= rules.board_width || y as u32 >= rules.board_height {
+        anyhow::bail!(
+            "Coordinates must be within the {}x{} board. Got ({}, {})",
+            rules.board_width,
+            rules.board_height,
+            x,
+            y
+        );
+    }
+    Ok(())
+}
+
+/// Parse an "x,y" cell
+fn parse_cell(cell: &str) -> Result<(u8, u8)> {
+    let Some((x, y)) = cell.split_once(',') else {
+        anyhow::bail!("Cell must be given as x,y. Got '{}'", cell);
+    };
+    Ok((x.trim().parse()?, y.trim().parse()?))
+}
+
+/// Parse a 32-byte hex public key
+fn parse_pubkey(pubkey: &str) -> Result<[u8; 32]> {
+    let pubkey_bytes = hex::decode(pubkey)?;
+    if pubkey_bytes.len() != 32 {
+        anyhow::bail!("Pubkey must be 32 bytes (64 hex chars)");
+    }
+    let mut gardener_pubkey = [0u8; 32];
+    gardener_pubkey.copy_from_slice(&pubkey_bytes);
+    Ok(gardener_pubkey)
+}
+
+fn commit_command(garden_file: &str, rules: &RuleSet) -> Result<()> {
     // Load garden from file
     let garden_json = fs::read_to_string(garden_file)?;
     let garden: GardenLayout = serde_json::from_str(&garden_json)?;
 
     // Validate garden
-    garden.validate().map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow::anyhow!("{:?}", e))?;
 
     // Compute commitment
     let commitment = compute_garden_commitment(&garden);
@@ -196,33 +780,93 @@ fn commit_command(garden_file: &str) -> Result<()> {
     println!("File: {}", garden_file);
     println!("Plants: {}", garden.plant_count());
     println!();
-    println!("Commitment (hex): {}", hex::encode(&commitment));
+    println!("Commitment (hex): {}", hex::encode(commitment));
     println!();
     println!("Use this value for commit_garden() on-chain.");
 
     Ok(())
 }
 
-fn create_command(output_file: &str) -> Result<()> {
+/// Prove the garden's commitment opens to a valid garden, for commit_garden's validity_proof
+fn commit_proof_command(
+    session_id: u32,
+    garden_file: &str,
+    pubkey: &str,
+    rules: &RuleSet,
+    output_format: &str,
+) -> Result<()> {
+    let garden_json = fs::read_to_string(garden_file)?;
+    let garden: GardenLayout = serde_json::from_str(&garden_json)?;
+
+    let gardener_pubkey = parse_pubkey(pubkey)?;
+
+    println!();
+    println!("=== Herbal Moonlight Prover (Garden Validity) ===");
+    println!("Session ID: {}", session_id);
+    println!();
+
+    let result = generate_garden_validity_proof(&garden, session_id, gardener_pubkey, rules)?;
+
+    if result.is_dev_mode {
+        println!("WARNING: This is a DEV MODE proof with empty seal!");
+        println!();
+    }
+
+    if output_format == "json" {
+        let json = serde_json::json!({
+            "dev_mode": result.is_dev_mode,
+            "journal_bytes": hex::encode(&result.journal_bytes),
+            "journal_hash": hex::encode(result.journal_hash),
+            "seal": hex::encode(&result.seal),
+            "image_id": hex::encode(result.image_id),
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!("journal_bytes: {}", hex::encode(&result.journal_bytes));
+        println!("journal_hash: {}", hex::encode(result.journal_hash));
+        if result.seal.is_empty() {
+            println!("seal: (empty - dev mode)");
+        } else {
+            println!("seal: {}", hex::encode(&result.seal));
+        }
+        println!("image_id: {}", hex::encode(result.image_id));
+        println!();
+        println!("Pass these as the validity_proof of commit_garden()");
+    }
+
+    Ok(())
+}
+
+fn create_command(output_file: &str, rules: &RuleSet) -> Result<()> {
     use std::io::{self, Write};
 
+    let width = rules.board_width as usize;
+    let height = rules.board_height as usize;
+    let house_row = height - 1;
+    let max_plants = rules.max_plants as usize;
+
     println!("=== Create Garden Layout ===");
     println!();
-    println!("Enter plant positions (max 7 plants).");
+    println!("Enter plant positions (max {} plants).", max_plants);
     println!("Format: x,y,type (where type is 1=Lavender, 2=Mint, 3=Mandrake)");
     println!("Example: 0,0,1  (places Lavender at top-left)");
     println!("Enter 'done' when finished.");
     println!();
-    println!("Grid (0-4 for both x and y, row 4 is the house - no plants):");
-    println!("  0 1 2 3 4");
-    println!("0 . . . . .");
-    println!("1 . . . . .");
-    println!("2 . . . . .");
-    println!("3 . . . . .");
-    println!("4 [HOUSE - no plants]");
+    println!(
+        "Grid (x 0-{}, y 0-{}, row {} is the house - no plants):",
+        width - 1,
+        house_row,
+        house_row
+    );
+    let header: Vec<String> = (0..width).map(|x| x.to_string()).collect();
+    println!("  {}", header.join(" "));
+    for y in 0..house_row {
+        println!("{} {}", y, vec!["."; width].join(" "));
+    }
+    println!("{} [HOUSE - no plants]", house_row);
     println!();
 
-    let mut cells = [0u8; GRID_CELLS];
+    let mut cells = vec![0u8; width * height];
     let mut plant_count = 0;
 
     loop {
@@ -244,42 +888,42 @@ fn create_command(output_file: &str) -> Result<()> {
         }
 
         let x: usize = match parts[0].parse() {
-            Ok(v) if v < 5 => v,
+            Ok(v) if v < width => v,
             _ => {
-                println!("Invalid x (must be 0-4)");
+                println!("Invalid x (must be 0-{})", width - 1);
                 continue;
             }
         };
 
         let y: usize = match parts[1].parse() {
-            Ok(v) if v < 5 => v,
+            Ok(v) if v < height => v,
             _ => {
-                println!("Invalid y (must be 0-4)");
+                println!("Invalid y (must be 0-{})", house_row);
                 continue;
             }
         };
 
-        if y == 4 {
-            println!("Cannot place plants in row 4 (house)");
+        if y == house_row {
+            println!("Cannot place plants in row {} (house)", house_row);
             continue;
         }
 
         let plant_type: u8 = match parts[2].parse() {
-            Ok(v) if v >= 1 && v <= 3 => v,
+            Ok(v) if (1..=3).contains(&v) => v,
             _ => {
                 println!("Invalid type (must be 1=Lavender, 2=Mint, 3=Mandrake)");
                 continue;
             }
         };
 
-        let index = y * 5 + x;
+        let index = y * width + x;
         if cells[index] != 0 {
             println!("Cell ({}, {}) already has a plant", x, y);
             continue;
         }
 
-        if plant_count >= 7 {
-            println!("Maximum 7 plants allowed");
+        if plant_count >= max_plants {
+            println!("Maximum {} plants allowed", max_plants);
             continue;
         }
 
@@ -292,7 +936,10 @@ fn create_command(output_file: &str) -> Result<()> {
             3 => "Mandrake",
             _ => "Unknown",
         };
-        println!("Placed {} at ({}, {}). Total: {}/7", plant_name, x, y, plant_count);
+        println!(
+            "Placed {} at ({}, {}). Total: {}/{}",
+            plant_name, x, y, plant_count, max_plants
+        );
     }
 
     // Generate random salt
@@ -320,10 +967,12 @@ fn create_command(output_file: &str) -> Result<()> {
         salt.copy_from_slice(&salt_bytes);
     }
 
-    let garden = GardenLayout::new(cells, salt);
+    let garden = GardenLayout::with_size(width as u8, height as u8, cells, salt);
 
     // Validate
-    garden.validate().map_err(|e| anyhow::anyhow!("{:?}", e))?;
+    garden
+        .validate_with(rules)
+        .map_err(|e| anyhow::anyhow!("{:?}", e))?;
 
     // Save to file
     let json = serde_json::to_string_pretty(&garden)?;
@@ -336,7 +985,7 @@ fn create_command(output_file: &str) -> Result<()> {
     println!("=== Garden Created ===");
     println!("Saved to: {}", output_file);
     println!("Plants: {}", plant_count);
-    println!("Commitment: {}", hex::encode(&commitment));
+    println!("Commitment: {}", hex::encode(commitment));
     println!();
     println!("IMPORTANT: Keep this file SECRET. Only share the commitment.");
 
@@ -346,8 +995,33 @@ fn create_command(output_file: &str) -> Result<()> {
 fn image_id_command() -> Result<()> {
     let id = get_image_id();
     println!("=== ZK Circuit Image ID ===");
-    println!("{}", hex::encode(&id));
+    println!("{}", hex::encode(id));
     println!();
     println!("Use this value when deploying the contract.");
+    println!();
+    println!("Spirit Sense circuits (register with set_sense_image_id):");
+    println!(
+        "  PeekAdjacent: {}",
+        hex::encode(get_sense_image_id(SenseKind::PeekAdjacent))
+    );
+    println!(
+        "  SmellAhead:   {}",
+        hex::encode(get_sense_image_id(SenseKind::SmellAhead))
+    );
+    println!();
+    println!("No Path circuit (register with set_no_path_image_id):");
+    println!("  NoPath:       {}", hex::encode(get_no_path_image_id()));
+    println!();
+    println!("Batch Reveal circuit (register with set_batch_reveal_image_id):");
+    println!(
+        "  BatchReveal:  {}",
+        hex::encode(get_batch_reveal_image_id())
+    );
+    println!();
+    println!("Garden Validity circuit (register with set_commitment_image_id):");
+    println!(
+        "  GardenValidity: {}",
+        hex::encode(get_garden_validity_image_id())
+    );
     Ok(())
 }
@@ -0,0 +1,84 @@
+//! # Herbal Moonlight ZK Circuit - Batch Reveal
+//!
+//! Proves the content of a list of cells in a single receipt, so a
+//! multi-row move, a replay or a spectator reconstruction is settled with
+//! one proving run instead of one per cell.
+//!
+//! ## What this circuit proves:
+//! 1. The garden layout hashes to the committed value
+//! 2. The garden layout is valid under the session's rule set
+//!    (plant limit, no plants in house row)
+//! 3. Each listed cell contains a particular plant type, in the given order
+//! 4. This proof is bound to a specific session, gardener and rule set
+//!
+//! ## Privacy guarantees:
+//! - Only the listed cells are revealed, exactly as separate cell reveals would
+//! - The rest of the garden never leaves the zkVM
+
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use risc0_zkvm::guest::env;
+
+use herbal_shared::{
+    verify_commitment_and_header, BatchRevealInput, BatchRevealOutput, RevealedCell,
+    MAX_BATCH_CELLS,
+};
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    // ========================================
+    // STEP 1: Read private input
+    // ========================================
+    let input: BatchRevealInput = env::read();
+
+    // ========================================
+    // STEP 2: Validate coordinates
+    // ========================================
+    // The journal holds the count in a single byte
+    if input.cells.is_empty() || input.cells.len() > MAX_BATCH_CELLS {
+        panic!("batch must reveal 1..={} cells", MAX_BATCH_CELLS);
+    }
+    for &(x, y) in &input.cells {
+        if x as u32 >= input.rules.board_width || y as u32 >= input.rules.board_height {
+            panic!("coordinates out of bounds: ({}, {})", x, y);
+        }
+    }
+
+    // ========================================
+    // STEP 3: Validate garden and verify commitment
+    // ========================================
+    let header = verify_commitment_and_header(
+        &input.garden,
+        &input.rules,
+        Some(&input.expected_commitment),
+        input.session_id,
+        input.gardener_pubkey,
+    )
+    .unwrap_or_else(|e| panic!("garden rejected: {:?}", e));
+
+    // ========================================
+    // STEP 4: Extract cell contents
+    // ========================================
+    let cells = input
+        .cells
+        .iter()
+        .map(|&(x, y)| RevealedCell::from_garden(&input.garden, x, y))
+        .collect();
+
+    // ========================================
+    // STEP 5: Build and commit public output
+    // ========================================
+    let output = BatchRevealOutput {
+        garden_commitment: header.garden_commitment,
+        cells,
+        session_id: header.session_id,
+        gardener_pubkey: header.gardener_pubkey,
+        rules_hash: header.rules_hash,
+    };
+
+    env::commit_slice(&output.to_bytes());
+}
@@ -17,7 +17,7 @@ fn main() {
         let out_dir = env::var("OUT_DIR").unwrap();
         let dest_path = Path::new(&out_dir).join("methods.rs");
 
-        // Generate mock ELF (empty) and mock ID (deterministic hash)
+        // Generate mock ELFs (empty) and mock IDs (deterministic placeholders)
         let mock_methods = r#"
 /// Mock ELF for development (empty binary)
 pub const CELL_REVEAL_ELF: &[u8] = &[];
@@ -28,6 +28,51 @@ pub const CELL_REVEAL_ID: [u32; 8] = [
     0xDEADBEEF, 0xCAFEBABE, 0x12345678, 0x9ABCDEF0,
     0xFEEDFACE, 0x0BADF00D, 0xDEADC0DE, 0xBADCAFE0
 ];
+
+/// Mock ELF of the Peek Adjacent sense circuit
+pub const PEEK_ADJACENT_ELF: &[u8] = &[];
+
+/// Mock Image ID of the Peek Adjacent sense circuit
+pub const PEEK_ADJACENT_ID: [u32; 8] = [
+    0x5EE4AD10, 0xCAFEBABE, 0x12345678, 0x9ABCDEF0,
+    0xFEEDFACE, 0x0BADF00D, 0xDEADC0DE, 0xBADCAFE1
+];
+
+/// Mock ELF of the Smell Ahead sense circuit
+pub const SMELL_AHEAD_ELF: &[u8] = &[];
+
+/// Mock Image ID of the Smell Ahead sense circuit
+pub const SMELL_AHEAD_ID: [u32; 8] = [
+    0x5E11A4EA, 0xCAFEBABE, 0x12345678, 0x9ABCDEF0,
+    0xFEEDFACE, 0x0BADF00D, 0xDEADC0DE, 0xBADCAFE2
+];
+
+/// Mock ELF of the No Path circuit
+pub const NO_PATH_ELF: &[u8] = &[];
+
+/// Mock Image ID of the No Path circuit
+pub const NO_PATH_ID: [u32; 8] = [
+    0x0DEADE4D, 0xCAFEBABE, 0x12345678, 0x9ABCDEF0,
+    0xFEEDFACE, 0x0BADF00D, 0xDEADC0DE, 0xBADCAFE3
+];
+
+/// Mock ELF of the Batch Reveal circuit
+pub const BATCH_REVEAL_ELF: &[u8] = &[];
+
+/// Mock Image ID of the Batch Reveal circuit
+pub const BATCH_REVEAL_ID: [u32; 8] = [
+    0xBA7C4E5E, 0xCAFEBABE, 0x12345678, 0x9ABCDEF0,
+    0xFEEDFACE, 0x0BADF00D, 0xDEADC0DE, 0xBADCAFE4
+];
+
+/// Mock ELF of the Garden Validity circuit
+pub const GARDEN_VALIDITY_ELF: &[u8] = &[];
+
+/// Mock Image ID of the Garden Validity circuit
+pub const GARDEN_VALIDITY_ID: [u32; 8] = [
+    0x6A4DE4F0, 0xCAFEBABE, 0x12345678, 0x9ABCDEF0,
+    0xFEEDFACE, 0x0BADF00D, 0xDEADC0DE, 0xBADCAFE5
+];
 "#;
 
         fs::write(dest_path, mock_methods).expect("Failed to write mock methods.rs");
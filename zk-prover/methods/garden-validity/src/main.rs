@@ -0,0 +1,58 @@
+//! # Herbal Moonlight ZK Circuit - Garden Validity
+//!
+//! Proves at commit time that the Gardener's commitment opens to a garden
+//! that is valid under the session's rule set, so a commitment that could
+//! never be revealed is rejected before the game starts.
+//!
+//! ## What this circuit proves:
+//! 1. The commitment is the hash of a garden layout the Gardener knows
+//! 2. The garden layout is valid under the session's rule set
+//!    (board size, plant limit, valid plant types, no plants in house row)
+//! 3. This proof is bound to a specific session, gardener and rule set
+//!
+//! ## Privacy guarantees:
+//! - Only the commitment is output; no cell of the garden is revealed
+
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use risc0_zkvm::guest::env;
+
+use herbal_shared::{verify_commitment_and_header, GardenValidityInput, GardenValidityOutput};
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    // ========================================
+    // STEP 1: Read private input
+    // ========================================
+    let input: GardenValidityInput = env::read();
+
+    // ========================================
+    // STEP 2: Validate garden and compute commitment
+    // ========================================
+    // There is no commitment on-chain yet: the contract checks the output
+    // against the commitment being submitted
+    let header = verify_commitment_and_header(
+        &input.garden,
+        &input.rules,
+        None,
+        input.session_id,
+        input.gardener_pubkey,
+    )
+    .unwrap_or_else(|e| panic!("garden rejected: {:?}", e));
+
+    // ========================================
+    // STEP 3: Build and commit public output
+    // ========================================
+    let output = GardenValidityOutput {
+        garden_commitment: header.garden_commitment,
+        session_id: header.session_id,
+        gardener_pubkey: header.gardener_pubkey,
+        rules_hash: header.rules_hash,
+    };
+
+    env::commit_slice(&output.to_bytes());
+}
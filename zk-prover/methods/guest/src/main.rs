@@ -6,9 +6,10 @@
 //!
 //! ## What this circuit proves:
 //! 1. The garden layout hashes to the committed value
-//! 2. The garden layout is valid (max 7 plants, no plants in house row)
+//! 2. The garden layout is valid under the session's rule set
+//!    (plant limit, no plants in house row)
 //! 3. The cell at (x, y) contains a specific plant type
-//! 4. This proof is bound to a specific session and gardener
+//! 4. This proof is bound to a specific session, gardener and rule set
 //!
 //! ## Privacy guarantees:
 //! - The full garden layout is PRIVATE (never leaves the zkVM)
@@ -23,8 +24,7 @@ extern crate alloc;
 use risc0_zkvm::guest::env;
 
 use herbal_shared::{
-    compute_garden_commitment, CellRevealInput, CellRevealOutput, GardenLayout, PlantType,
-    GRID_SIZE,
+    verify_commitment_and_header, CellRevealInput, CellRevealOutput, GardenLayout, PlantType,
 };
 
 risc0_zkvm::guest::entry!(main);
@@ -41,39 +41,30 @@ fn main() {
     // STEP 2: Validate coordinates
     // ========================================
     // Ensure the requested cell is within bounds
-    if input.x >= GRID_SIZE as u8 || input.y >= GRID_SIZE as u8 {
+    if input.x as u32 >= input.rules.board_width || input.y as u32 >= input.rules.board_height {
         panic!("coordinates out of bounds: ({}, {})", input.x, input.y);
     }
 
     // ========================================
-    // STEP 3: Validate garden layout
+    // STEP 3: Validate garden and verify commitment
     // ========================================
     // Check that the garden is valid:
-    // - Max 7 plants
+    // - At most rules.max_plants plants (7 by default)
     // - Valid plant types only
-    // - No plants in house row (row 4)
-    if let Err(e) = input.garden.validate() {
-        panic!("invalid garden layout: {:?}", e);
-    }
+    // - No plants in house row (last row of the board)
+    // and that it hashes to the on-chain commitment, so the gardener
+    // cannot cheat by changing the garden
+    let header = verify_commitment_and_header(
+        &input.garden,
+        &input.rules,
+        Some(&input.expected_commitment),
+        input.session_id,
+        input.gardener_pubkey,
+    )
+    .unwrap_or_else(|e| panic!("garden rejected: {:?}", e));
 
     // ========================================
-    // STEP 4: Compute and verify commitment
-    // ========================================
-    // The commitment is SHA256(cells || salt)
-    // This ensures the gardener cannot cheat by changing the garden
-    let computed_commitment = compute_garden_commitment(&input.garden);
-
-    if computed_commitment != input.expected_commitment {
-        panic!(
-            "garden commitment mismatch - cheating detected! \
-             computed: {:?}, expected: {:?}",
-            &computed_commitment[..8],
-            &input.expected_commitment[..8]
-        );
-    }
-
-    // ========================================
-    // STEP 5: Extract cell content
+    // STEP 4: Extract cell content
     // ========================================
     // Get the plant at the requested coordinates
     let plant = input.garden.get_cell(input.x, input.y);
@@ -81,23 +72,24 @@ fn main() {
     let damage = plant.damage();
 
     // ========================================
-    // STEP 6: Build public output
+    // STEP 5: Build public output
     // ========================================
     // This output will be committed to the journal
     // and can be verified by the smart contract
     let output = CellRevealOutput {
-        garden_commitment: computed_commitment,
+        garden_commitment: header.garden_commitment,
         x: input.x,
         y: input.y,
         has_plant,
         plant_type: plant as u8,
         damage,
-        session_id: input.session_id,
-        gardener_pubkey: input.gardener_pubkey,
+        session_id: header.session_id,
+        gardener_pubkey: header.gardener_pubkey,
+        rules_hash: header.rules_hash,
     };
 
     // ========================================
-    // STEP 7: Commit to journal
+    // STEP 6: Commit to journal
     // ========================================
     // The journal is the PUBLIC output of the ZK proof
     // The smart contract will read this to get the revealed cell info
@@ -117,10 +109,13 @@ fn main() {
 //    for a garden that doesn't hash to the committed value
 //
 // 3. ZERO-KNOWLEDGE: The verifier learns ONLY the content of the single
-//    revealed cell, not the other 24 cells
+//    revealed cell, not the rest of the board
 //
 // 4. BINDING: The gardener is bound to the same garden throughout the game
 //    (they cannot change it after committing)
 //
 // 5. SESSION BINDING: The proof is bound to a specific session_id and
 //    gardener_pubkey, preventing replay attacks
+//
+// 6. RULES BINDING: The proof commits the hash of the rule set the garden
+//    was validated under, which the contract checks against the session
@@ -0,0 +1,88 @@
+//! # Herbal Moonlight ZK Circuit - No Path
+//!
+//! Gardener win condition: proves that the Creature has no valid path
+//! forward, i.e. every legal sequence of moves from its position to the
+//! house runs out of HP, WITHOUT revealing where the plants are.
+//!
+//! ## What this circuit proves:
+//! 1. The garden layout hashes to the committed value
+//! 2. The garden layout is valid under the session's rule set
+//!    (plant limit, no plants in house row)
+//! 3. A Creature of the given kind at (x, y) with the given HP dies on every
+//!    path to the house under the given moon phase (or, on a rule set whose
+//!    moon cycles, the phases that follow the given moon clock)
+//! 4. This proof is bound to a specific session, gardener and rule set
+//!
+//! ## Privacy guarantees:
+//! - Only the fact that every path is lethal is revealed
+//! - Plant types and positions are never output
+
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use risc0_zkvm::guest::env;
+
+use herbal_shared::{verify_commitment_and_header, NoPathInput, NoPathOutput};
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    // ========================================
+    // STEP 1: Read private input
+    // ========================================
+    let input: NoPathInput = env::read();
+
+    // ========================================
+    // STEP 2: Validate coordinates
+    // ========================================
+    if input.x as u32 >= input.rules.board_width || input.y as u32 >= input.rules.board_height {
+        panic!("coordinates out of bounds: ({}, {})", input.x, input.y);
+    }
+
+    // ========================================
+    // STEP 3: Validate garden and verify commitment
+    // ========================================
+    let header = verify_commitment_and_header(
+        &input.garden,
+        &input.rules,
+        Some(&input.expected_commitment),
+        input.session_id,
+        input.gardener_pubkey,
+    )
+    .unwrap_or_else(|e| panic!("garden rejected: {:?}", e));
+
+    // ========================================
+    // STEP 4: Search every path to the house
+    // ========================================
+    if input.garden.has_safe_path(
+        input.x,
+        input.y,
+        input.creature_hp as u32,
+        input.creature_kind,
+        input.moon_phase,
+        input.moon_clock as u32,
+        &input.rules,
+    ) {
+        panic!("the creature still has a path to the house");
+    }
+
+    // ========================================
+    // STEP 5: Build and commit public output
+    // ========================================
+    let output = NoPathOutput {
+        garden_commitment: header.garden_commitment,
+        x: input.x,
+        y: input.y,
+        creature_kind: input.creature_kind,
+        moon_phase: input.moon_phase,
+        creature_hp: input.creature_hp,
+        moon_clock: input.moon_clock,
+        session_id: header.session_id,
+        gardener_pubkey: header.gardener_pubkey,
+        rules_hash: header.rules_hash,
+    };
+
+    env::commit_slice(&output.to_bytes());
+}
@@ -0,0 +1,73 @@
+//! # Herbal Moonlight ZK Circuit - Smell Ahead
+//!
+//! Spirit Sense circuit: proves how many plants are in the two rows ahead
+//! of the Creature (y+1..=y+2), WITHOUT revealing their types or positions.
+//!
+//! ## What this circuit proves:
+//! 1. The garden layout hashes to the committed value
+//! 2. The garden layout is valid under the session's rule set
+//!    (plant limit, no plants in house row)
+//! 3. Rows y+1..=y+2 contain exactly N plants
+//! 4. This proof is bound to a specific session, gardener and rule set
+//!
+//! ## Privacy guarantees:
+//! - Only the aggregate count is revealed
+//! - Plant types and positions are never output
+
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use risc0_zkvm::guest::env;
+
+use herbal_shared::{verify_commitment_and_header, SenseInput, SenseOutput, SenseReading};
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    // ========================================
+    // STEP 1: Read private input
+    // ========================================
+    let input: SenseInput = env::read();
+
+    // ========================================
+    // STEP 2: Validate coordinates
+    // ========================================
+    if input.x as u32 >= input.rules.board_width || input.y as u32 >= input.rules.board_height {
+        panic!("coordinates out of bounds: ({}, {})", input.x, input.y);
+    }
+
+    // ========================================
+    // STEP 3: Validate garden and verify commitment
+    // ========================================
+    let header = verify_commitment_and_header(
+        &input.garden,
+        &input.rules,
+        Some(&input.expected_commitment),
+        input.session_id,
+        input.gardener_pubkey,
+    )
+    .unwrap_or_else(|e| panic!("garden rejected: {:?}", e));
+
+    // ========================================
+    // STEP 4: Count plants ahead
+    // ========================================
+    // Rows past the grid are ignored
+    let plants = input.garden.plants_ahead(input.y);
+
+    // ========================================
+    // STEP 5: Build and commit public output
+    // ========================================
+    let output = SenseOutput {
+        garden_commitment: header.garden_commitment,
+        x: input.x,
+        y: input.y,
+        reading: SenseReading::Ahead { plants },
+        session_id: header.session_id,
+        gardener_pubkey: header.gardener_pubkey,
+        rules_hash: header.rules_hash,
+    };
+
+    env::commit_slice(&output.to_bytes());
+}
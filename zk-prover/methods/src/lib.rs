@@ -1,10 +1,19 @@
 //! # Herbal Moonlight ZK Methods
 //!
 //! This crate contains the RiscZero guest methods (ZK circuits).
-//! The actual circuit code is in `guest/src/main.rs`.
+//! The cell reveal circuit is in `guest/src/main.rs`; the Spirit Sense
+//! circuits are in `peek-adjacent/` and `smell-ahead/`, the no-path
+//! win condition circuit is in `no-path/`, the multi-cell reveal
+//! circuit is in `batch-reveal/` and the commit-time validity circuit is
+//! in `garden-validity/`.
 //!
 //! After building, this crate exports:
 //! - `CELL_REVEAL_ELF`: The compiled guest binary
 //! - `CELL_REVEAL_ID`: The image ID of the circuit
+//! - `PEEK_ADJACENT_ELF` / `PEEK_ADJACENT_ID`: Peek Adjacent sense circuit
+//! - `SMELL_AHEAD_ELF` / `SMELL_AHEAD_ID`: Smell Ahead sense circuit
+//! - `NO_PATH_ELF` / `NO_PATH_ID`: No Path circuit
+//! - `BATCH_REVEAL_ELF` / `BATCH_REVEAL_ID`: Batch Reveal circuit
+//! - `GARDEN_VALIDITY_ELF` / `GARDEN_VALIDITY_ID`: Garden Validity circuit
 
 include!(concat!(env!("OUT_DIR"), "/methods.rs"));
@@ -0,0 +1,492 @@
+//! # Herbal Moonlight Rules
+//!
+//! The single source of truth for plant rules, moon and creature rules, and
+//! per-session rule sets.
+//! It is consumed by:
+//! - The Soroban contract (authoritative damage on reveal)
+//! - The ZK Guests, through `herbal-shared` (damage written to the journal)
+//! - The ZK Host (dev-mode proofs)
+//!
+//! This crate is `no_std` and has no required dependencies (serde is behind
+//! the `serde` feature) so it can be linked into the contract WASM as well as
+//! the RISC-V guest. Change a rule here and
+//! every consumer follows; the conformance tests in each consumer fail if
+//! one of them re-implements a rule instead.
+
+#![no_std]
+
+// ============================================================================
+// Plant Rules
+// ============================================================================
+
+/// Plant id of an empty cell
+pub const EMPTY: u8 = 0;
+
+/// Plant id of Lavender
+pub const LAVENDER: u8 = 1;
+
+/// Plant id of Mint
+pub const MINT: u8 = 2;
+
+/// Plant id of Mandrake
+pub const MANDRAKE: u8 = 3;
+
+/// Damage removed from the next plant hit after Lavender's Calming Mist
+pub const CALMING_MIST_REDUCTION: u8 = 1;
+
+/// Special effect triggered when a plant is revealed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlantEffect {
+    /// No effect beyond damage
+    None,
+    /// The next plant hit deals CALMING_MIST_REDUCTION less damage (minimum 1)
+    CalmingMist,
+}
+
+/// Rules for a single plant type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlantRule {
+    /// Plant id as stored in the garden layout and the journal
+    pub id: u8,
+    /// Display name
+    pub name: &'static str,
+    /// Damage dealt before moon phase modifiers
+    pub base_damage: u8,
+    /// Special effect on reveal
+    pub effect: PlantEffect,
+}
+
+/// All plant types. Ids are contiguous from 1.
+pub const PLANTS: [PlantRule; 3] = [
+    PlantRule {
+        id: LAVENDER,
+        name: "Lavender",
+        base_damage: 1,
+        effect: PlantEffect::CalmingMist,
+    },
+    PlantRule {
+        id: MINT,
+        name: "Mint",
+        base_damage: 2,
+        effect: PlantEffect::None,
+    },
+    PlantRule {
+        id: MANDRAKE,
+        name: "Mandrake",
+        base_damage: 3,
+        effect: PlantEffect::None,
+    },
+];
+
+/// Look up the rules for a plant id (None for empty or unknown ids)
+pub fn plant_rule(id: u8) -> Option<&'static PlantRule> {
+    PLANTS.iter().find(|rule| rule.id == id)
+}
+
+/// Base damage of a plant id (0 for empty or unknown ids)
+pub fn base_damage(id: u8) -> u8 {
+    plant_rule(id).map_or(0, |rule| rule.base_damage)
+}
+
+// ============================================================================
+// Moon Phases
+// ============================================================================
+
+/// Moon phase of a session (ids match the contract's `MoonPhase`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum MoonPhase {
+    /// Plants deal `full_moon_damage_reduction` less damage
+    FullMoon = 0,
+    /// Plants deal `new_moon_damage_bonus` more damage
+    NewMoon = 1,
+    /// Plants deal their base damage
+    Balanced = 2,
+}
+
+impl MoonPhase {
+    /// Convert from the id used in journals
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(MoonPhase::FullMoon),
+            1 => Some(MoonPhase::NewMoon),
+            2 => Some(MoonPhase::Balanced),
+            _ => None,
+        }
+    }
+
+    /// Damage of a plant hit before Calming Mist
+    pub fn adjust_damage(&self, base_damage: u32, rules: &RuleSet) -> u32 {
+        match self {
+            MoonPhase::FullMoon => base_damage.saturating_sub(rules.full_moon_damage_reduction),
+            MoonPhase::NewMoon => base_damage.saturating_add(rules.new_moon_damage_bonus),
+            MoonPhase::Balanced => base_damage,
+        }
+    }
+}
+
+/// Phases a cycling moon walks through, one every `moon_cycle_turns` turns:
+/// New Moon, waxing, Full Moon, waning
+pub const MOON_CYCLE: [MoonPhase; 4] = [
+    MoonPhase::NewMoon,
+    MoonPhase::Balanced,
+    MoonPhase::FullMoon,
+    MoonPhase::Balanced,
+];
+
+/// Damage dealt by entering a cell holding `plant_id`, given the Calming
+/// Mist `reduction` pending from an earlier hit.
+///
+/// Returns `(damage, reduction pending afterwards)`. Empty cells deal no
+/// damage and keep the pending reduction; any plant deals at least 1.
+pub fn hit(plant_id: u8, moon: MoonPhase, reduction: u32, rules: &RuleSet) -> (u32, u32) {
+    let Some(rule) = plant_rule(plant_id) else {
+        return (0, reduction);
+    };
+    let damage = moon
+        .adjust_damage(rule.base_damage as u32, rules)
+        .saturating_sub(reduction)
+        .max(1);
+    let next_reduction = match rule.effect {
+        PlantEffect::CalmingMist => CALMING_MIST_REDUCTION as u32,
+        PlantEffect::None => 0,
+    };
+    (damage, next_reduction)
+}
+
+// ============================================================================
+// Creature Kinds
+// ============================================================================
+
+/// HP a Moon Moth starts with below the rule set's starting HP
+pub const MOON_MOTH_HP_PENALTY: u32 = 2;
+
+/// HP a Shadow Wolf starts with above the rule set's starting HP
+pub const SHADOW_WOLF_HP_BONUS: u32 = 3;
+
+/// Most rows any creature kind advances in one move
+pub const MAX_MOVE_ROWS: u32 = 2;
+
+/// Creature types (ids match the contract's `CreatureKind`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum CreatureKind {
+    /// Standard HP; 1 row forward per turn, at most 1 column sideways
+    Ghost = 0,
+    /// Fragile; may Flutter 2 rows straight ahead, revealing every traversed cell
+    MoonMoth = 1,
+    /// Extra HP; 1 row forward per turn, never sideways once on the board
+    ShadowWolf = 2,
+}
+
+impl CreatureKind {
+    /// Convert from the id used in journals
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(CreatureKind::Ghost),
+            1 => Some(CreatureKind::MoonMoth),
+            2 => Some(CreatureKind::ShadowWolf),
+            _ => None,
+        }
+    }
+
+    /// Starting HP before the Full Moon bonus
+    pub fn starting_hp(&self, rules: &RuleSet) -> u32 {
+        match self {
+            CreatureKind::Ghost => rules.creature_starting_hp,
+            CreatureKind::MoonMoth => rules
+                .creature_starting_hp
+                .saturating_sub(MOON_MOTH_HP_PENALTY)
+                .max(1),
+            CreatureKind::ShadowWolf => rules.creature_starting_hp + SHADOW_WOLF_HP_BONUS,
+        }
+    }
+
+    /// Whether a move advancing `rows` rows and shifting `columns` columns is
+    /// allowed. Entering the board (from row 0) may pick any column.
+    pub fn can_move(&self, rows: u32, columns: u32, entering: bool) -> bool {
+        let columns = if entering { 0 } else { columns };
+        match self {
+            CreatureKind::Ghost => rows == 1 && columns <= 1,
+            CreatureKind::MoonMoth => (rows == 1 && columns <= 1) || (rows == 2 && columns == 0),
+            CreatureKind::ShadowWolf => rows == 1 && columns == 0,
+        }
+    }
+}
+
+// ============================================================================
+// Rule Sets
+// ============================================================================
+
+/// Smallest board width / height
+pub const MIN_BOARD_SIZE: u32 = 5;
+
+/// Largest board width / height (the journal stores coordinates as u8)
+pub const MAX_BOARD_SIZE: u32 = 9;
+
+/// Length of the canonical RuleSet encoding (11 little-endian u32 fields)
+pub const RULE_SET_LEN: usize = 11 * 4;
+
+/// Tunable rules of a game session.
+///
+/// The contract stores a copy on every session and the circuits commit
+/// `sha256(to_bytes())` to the journal, so a proof is bound to the rules
+/// it was generated under. Field order defines the encoding; the contract's
+/// `RuleSet` type must mirror it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuleSet {
+    /// Number of columns of the board
+    pub board_width: u32,
+    /// Number of rows of the board; the last row is the house
+    pub board_height: u32,
+    /// Creature HP at the start of the game
+    pub creature_starting_hp: u32,
+    /// Extra Creature HP on a Full Moon
+    pub full_moon_bonus_hp: u32,
+    /// Maximum plants in a garden (checked by the circuits)
+    pub max_plants: u32,
+    /// Chance of a Full Moon, in percent
+    pub full_moon_chance: u32,
+    /// Chance of a New Moon, in percent
+    pub new_moon_chance: u32,
+    /// Damage removed from every plant on a Full Moon
+    pub full_moon_damage_reduction: u32,
+    /// Damage added to every plant on a New Moon
+    pub new_moon_damage_bonus: u32,
+    /// HP cost of a Spirit Sense outside Full Moon
+    pub sense_hp_cost: u32,
+    /// Turns each phase of `MOON_CYCLE` lasts; 0 keeps the session's moon
+    /// for the whole game. A cycling moon ignores the moon chances: the
+    /// session seed picks where in the cycle it starts.
+    pub moon_cycle_turns: u32,
+}
+
+/// The standard rules (rule set 0)
+pub const DEFAULT_RULES: RuleSet = RuleSet {
+    board_width: 5,
+    board_height: 5,
+    creature_starting_hp: 6,
+    full_moon_bonus_hp: 2,
+    max_plants: 7,
+    full_moon_chance: 20,
+    new_moon_chance: 20,
+    full_moon_damage_reduction: 1,
+    new_moon_damage_bonus: 1,
+    sense_hp_cost: 1,
+    moon_cycle_turns: 0,
+};
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        DEFAULT_RULES
+    }
+}
+
+impl RuleSet {
+    /// Canonical encoding: every field as a little-endian u32, in declaration order
+    pub fn to_bytes(&self) -> [u8; RULE_SET_LEN] {
+        let fields = [
+            self.board_width,
+            self.board_height,
+            self.creature_starting_hp,
+            self.full_moon_bonus_hp,
+            self.max_plants,
+            self.full_moon_chance,
+            self.new_moon_chance,
+            self.full_moon_damage_reduction,
+            self.new_moon_damage_bonus,
+            self.sense_hp_cost,
+            self.moon_cycle_turns,
+        ];
+        let mut out = [0u8; RULE_SET_LEN];
+        for (chunk, field) in out.chunks_exact_mut(4).zip(fields) {
+            chunk.copy_from_slice(&field.to_le_bytes());
+        }
+        out
+    }
+
+    /// Length of a full moon cycle in turns (0 when the moon does not cycle)
+    pub fn moon_cycle_len(&self) -> u32 {
+        self.moon_cycle_turns * MOON_CYCLE.len() as u32
+    }
+
+    /// Moon phase `moon_clock` turns into the cycle. A moon that does not
+    /// cycle stays `fixed`.
+    pub fn moon_at(&self, fixed: MoonPhase, moon_clock: u32) -> MoonPhase {
+        if self.moon_cycle_turns == 0 {
+            return fixed;
+        }
+        MOON_CYCLE[(moon_clock % self.moon_cycle_len() / self.moon_cycle_turns) as usize]
+    }
+
+    /// Whether the rule set can be played: a board between 5x5 and 9x9, a
+    /// living Creature, room for the plants outside the house row, moon
+    /// chances that add up to at most 100%, and moon phases no longer than
+    /// the tallest board (so the moon clock fits a journal byte)
+    pub fn is_valid(&self) -> bool {
+        let board_sizes = MIN_BOARD_SIZE..=MAX_BOARD_SIZE;
+        if !board_sizes.contains(&self.board_width) || !board_sizes.contains(&self.board_height) {
+            return false;
+        }
+        let plantable_cells = self.board_width * (self.board_height - 1);
+        self.creature_starting_hp > 0
+            && self.max_plants <= plantable_cells
+            && self.full_moon_chance.saturating_add(self.new_moon_chance) <= 100
+            && self.moon_cycle_turns <= MAX_BOARD_SIZE
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plant_ids_contiguous() {
+        for (i, rule) in PLANTS.iter().enumerate() {
+            assert_eq!(rule.id as usize, i + 1);
+        }
+        assert!(plant_rule(EMPTY).is_none());
+        assert!(plant_rule(PLANTS.len() as u8 + 1).is_none());
+    }
+
+    #[test]
+    fn test_base_damage() {
+        assert_eq!(base_damage(EMPTY), 0);
+        assert_eq!(base_damage(LAVENDER), 1);
+        assert_eq!(base_damage(MINT), 2);
+        assert_eq!(base_damage(MANDRAKE), 3);
+        assert_eq!(base_damage(42), 0);
+    }
+
+    #[test]
+    fn test_moon_damage() {
+        assert_eq!(MoonPhase::FullMoon.adjust_damage(1, &DEFAULT_RULES), 0);
+        assert_eq!(MoonPhase::NewMoon.adjust_damage(3, &DEFAULT_RULES), 4);
+        assert_eq!(MoonPhase::Balanced.adjust_damage(2, &DEFAULT_RULES), 2);
+        for id in 0..3 {
+            assert_eq!(MoonPhase::from_u8(id).map(|moon| moon as u8), Some(id));
+        }
+        assert_eq!(MoonPhase::from_u8(3), None);
+    }
+
+    #[test]
+    fn test_moon_cycle() {
+        // A moon that does not cycle ignores the clock
+        assert_eq!(DEFAULT_RULES.moon_cycle_len(), 0);
+        assert_eq!(
+            DEFAULT_RULES.moon_at(MoonPhase::NewMoon, 7),
+            MoonPhase::NewMoon
+        );
+
+        let rules = RuleSet {
+            moon_cycle_turns: 2,
+            ..DEFAULT_RULES
+        };
+        assert_eq!(rules.moon_cycle_len(), 8);
+        let phases: [MoonPhase; 9] =
+            core::array::from_fn(|clock| rules.moon_at(MoonPhase::Balanced, clock as u32));
+        assert_eq!(
+            phases,
+            [
+                MoonPhase::NewMoon,
+                MoonPhase::NewMoon,
+                MoonPhase::Balanced,
+                MoonPhase::Balanced,
+                MoonPhase::FullMoon,
+                MoonPhase::FullMoon,
+                MoonPhase::Balanced,
+                MoonPhase::Balanced,
+                MoonPhase::NewMoon,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hit() {
+        let rules = &DEFAULT_RULES;
+        assert_eq!(hit(EMPTY, MoonPhase::Balanced, 1, rules), (0, 1));
+        assert_eq!(hit(LAVENDER, MoonPhase::Balanced, 0, rules), (1, 1));
+        assert_eq!(hit(MANDRAKE, MoonPhase::Balanced, 1, rules), (2, 0));
+        // Any plant deals at least 1
+        assert_eq!(hit(LAVENDER, MoonPhase::FullMoon, 1, rules), (1, 1));
+        assert_eq!(hit(MINT, MoonPhase::NewMoon, 0, rules), (3, 0));
+    }
+
+    #[test]
+    fn test_creature_kinds() {
+        let rules = &DEFAULT_RULES;
+        assert_eq!(CreatureKind::Ghost.starting_hp(rules), 6);
+        assert_eq!(CreatureKind::MoonMoth.starting_hp(rules), 4);
+        assert_eq!(CreatureKind::ShadowWolf.starting_hp(rules), 9);
+
+        assert!(CreatureKind::Ghost.can_move(1, 1, false));
+        assert!(!CreatureKind::Ghost.can_move(2, 0, false));
+        assert!(CreatureKind::MoonMoth.can_move(2, 0, false));
+        assert!(!CreatureKind::MoonMoth.can_move(2, 1, false));
+        assert!(!CreatureKind::ShadowWolf.can_move(1, 1, false));
+        assert!(CreatureKind::ShadowWolf.can_move(1, 4, true));
+    }
+
+    #[test]
+    fn test_rule_set_encoding() {
+        let bytes = DEFAULT_RULES.to_bytes();
+        assert_eq!(bytes.len(), RULE_SET_LEN);
+        assert_eq!(&bytes[0..4], &5u32.to_le_bytes());
+        assert_eq!(&bytes[4..8], &5u32.to_le_bytes());
+        assert_eq!(&bytes[36..40], &1u32.to_le_bytes());
+        assert_eq!(&bytes[40..44], &0u32.to_le_bytes());
+
+        let variant = RuleSet {
+            creature_starting_hp: 10,
+            ..DEFAULT_RULES
+        };
+        assert_ne!(variant.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_rule_set_validity() {
+        assert!(DEFAULT_RULES.is_valid());
+        assert!(RuleSet {
+            board_width: 9,
+            board_height: 7,
+            ..DEFAULT_RULES
+        }
+        .is_valid());
+        for invalid in [
+            RuleSet {
+                board_width: 4,
+                ..DEFAULT_RULES
+            },
+            RuleSet {
+                board_height: 10,
+                ..DEFAULT_RULES
+            },
+            RuleSet {
+                creature_starting_hp: 0,
+                ..DEFAULT_RULES
+            },
+            RuleSet {
+                max_plants: 21,
+                ..DEFAULT_RULES
+            },
+            RuleSet {
+                full_moon_chance: 60,
+                new_moon_chance: 41,
+                ..DEFAULT_RULES
+            },
+            RuleSet {
+                moon_cycle_turns: 10,
+                ..DEFAULT_RULES
+            },
+        ] {
+            assert!(!invalid.is_valid());
+        }
+    }
+}
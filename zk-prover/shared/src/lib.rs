@@ -12,6 +12,10 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use herbal_rules::{hit, CALMING_MIST_REDUCTION, MAX_MOVE_ROWS};
+pub use herbal_rules::{
+    CreatureKind, MoonPhase, RuleSet, DEFAULT_RULES, MAX_BOARD_SIZE, MIN_BOARD_SIZE,
+};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
@@ -19,38 +23,72 @@ use sha2::{Digest, Sha256};
 // Constants
 // ============================================================================
 
-/// Grid size (5x5 board)
-pub const GRID_SIZE: usize = 5;
+/// Board size of the standard rules (5x5 board); rule sets allow
+/// MIN_BOARD_SIZE..=MAX_BOARD_SIZE in each direction
+pub const GRID_SIZE: usize = DEFAULT_RULES.board_width as usize;
 
-/// Total number of cells in the grid
+/// Total number of cells on the standard board
 pub const GRID_CELLS: usize = GRID_SIZE * GRID_SIZE; // 25 cells
 
-/// Maximum number of plants allowed
-pub const MAX_PLANTS: usize = 7;
+/// Maximum number of plants allowed under the standard rules
+pub const MAX_PLANTS: usize = DEFAULT_RULES.max_plants as usize;
 
 /// Length of the salt for commitment
 pub const SALT_LEN: usize = 16;
 
 /// Length of the journal output in bytes
 /// Layout: [commitment:32][x:1][y:1][has_plant:1][plant_type:1][damage:1][session_id:4][gardener_pubkey:32]
-pub const JOURNAL_LEN: usize = 32 + 1 + 1 + 1 + 1 + 1 + 4 + 32; // = 73 bytes
+///         [rules_hash:32]
+pub const JOURNAL_LEN: usize = 32 + 1 + 1 + 1 + 1 + 1 + 4 + 32 + 32; // = 105 bytes
+
+/// Length of a Spirit Sense journal in bytes (same size and binding offsets as JOURNAL_LEN)
+/// Layout: [commitment:32][kind:1][x:1][y:1][reading:2][session_id:4][gardener_pubkey:32]
+///         [rules_hash:32]
+pub const SENSE_JOURNAL_LEN: usize = 32 + 1 + 1 + 1 + 2 + 4 + 32 + 32; // = 105 bytes
+
+/// Length of a No Path journal in bytes; the session binding is its last 68 bytes
+/// Layout: [commitment:32][x:1][y:1][creature_kind:1][moon_phase:1][creature_hp:1][moon_clock:1]
+///         [session_id:4][gardener_pubkey:32][rules_hash:32]
+pub const NO_PATH_JOURNAL_LEN: usize = 32 + 1 + 1 + 1 + 1 + 1 + 1 + 4 + 32 + 32; // = 106 bytes
+
+/// Length of a garden validity journal in bytes: the commitment and the session binding
+/// Layout: [commitment:32][session_id:4][gardener_pubkey:32][rules_hash:32]
+pub const GARDEN_VALIDITY_JOURNAL_LEN: usize = 32 + 4 + 32 + 32; // = 100 bytes
+
+/// Length of one cell in a batch reveal journal
+/// Layout: [x:1][y:1][has_plant:1][plant_type:1][damage:1]
+pub const BATCH_CELL_LEN: usize = 5;
+
+/// Maximum number of cells in one batch reveal (the count is a single journal byte)
+pub const MAX_BATCH_CELLS: usize = u8::MAX as usize;
+
+/// Length of a batch reveal journal with `count` cells; the session binding is its last 68 bytes
+/// Layout: [commitment:32][count:1][count x cell:5][session_id:4][gardener_pubkey:32]
+///         [rules_hash:32]
+pub const fn batch_journal_len(count: usize) -> usize {
+    32 + 1 + count * BATCH_CELL_LEN + 4 + 32 + 32
+}
+
+/// Number of rows below the Creature counted by Smell Ahead
+pub const SMELL_AHEAD_ROWS: u8 = 2;
 
 // ============================================================================
 // Plant Types
 // ============================================================================
 
 /// Types of plants that can be placed in the garden
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum PlantType {
     /// Empty cell (no plant)
+    #[default]
     Empty = 0,
-    /// Lavender - Support plant (1 HP damage)
-    Lavender = 1,
+    /// Lavender - Support plant (1 HP damage, Calming Mist)
+    Lavender = herbal_rules::LAVENDER,
     /// Mint - DPS plant (2 HP damage)
-    Mint = 2,
-    /// Mandrake - Tank plant (1 HP damage, counts as 3 HP block)
-    Mandrake = 3,
+    Mint = herbal_rules::MINT,
+    /// Mandrake - Heavy plant (3 HP damage)
+    Mandrake = herbal_rules::MANDRAKE,
 }
 
 impl PlantType {
@@ -65,14 +103,9 @@ impl PlantType {
         }
     }
 
-    /// Get the damage this plant deals
+    /// Get the base damage this plant deals (from the `herbal-rules` table)
     pub fn damage(&self) -> u8 {
-        match self {
-            PlantType::Empty => 0,
-            PlantType::Lavender => 1,
-            PlantType::Mint => 2,
-            PlantType::Mandrake => 1,
-        }
+        herbal_rules::base_damage(*self as u8)
     }
 
     /// Check if this is a valid plant (not empty)
@@ -81,46 +114,70 @@ impl PlantType {
     }
 }
 
-impl Default for PlantType {
-    fn default() -> Self {
-        PlantType::Empty
-    }
-}
-
 // ============================================================================
 // Garden Layout
 // ============================================================================
 
-/// Represents the complete garden layout (5x5 grid)
+/// Represents the complete garden layout (5x5 up to 9x9)
 ///
 /// The garden is stored as a flat array in row-major order:
-/// - Index = y * GRID_SIZE + x
-/// - (0,0) is top-left, (4,4) is bottom-right
+/// - Index = y * width + x
+/// - (0,0) is top-left, (width-1, height-1) is bottom-right
 ///
-/// Row 4 (indices 20-24) is the Gardener's house - no plants allowed
+/// The last row is the Gardener's house - no plants allowed
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GardenLayout {
-    /// Cells in row-major order: cells[y * 5 + x]
+    /// Number of columns (5 if omitted, for standard garden files)
+    #[serde(default = "default_board_size")]
+    pub width: u8,
+
+    /// Number of rows, including the house row (5 if omitted)
+    #[serde(default = "default_board_size")]
+    pub height: u8,
+
+    /// Cells in row-major order: cells[y * width + x]
     /// Each cell is a u8 representing PlantType
-    pub cells: [u8; GRID_CELLS],
+    pub cells: Vec<u8>,
 
     /// Random salt to prevent rainbow table attacks on commitment
     pub salt: [u8; SALT_LEN],
 }
 
+fn default_board_size() -> u8 {
+    GRID_SIZE as u8
+}
+
 impl GardenLayout {
-    /// Create a new garden layout
+    /// Create a new garden layout on the standard 5x5 board
     pub fn new(cells: [u8; GRID_CELLS], salt: [u8; SALT_LEN]) -> Self {
-        Self { cells, salt }
+        Self::with_size(GRID_SIZE as u8, GRID_SIZE as u8, cells.to_vec(), salt)
+    }
+
+    /// Create a garden layout of any size (checked by `validate_with`)
+    pub fn with_size(width: u8, height: u8, cells: Vec<u8>, salt: [u8; SALT_LEN]) -> Self {
+        Self {
+            width,
+            height,
+            cells,
+            salt,
+        }
+    }
+
+    /// Row of the Gardener's house (the last row)
+    pub fn house_row(&self) -> u8 {
+        self.height.saturating_sub(1)
     }
 
     /// Get the plant at a specific cell
     pub fn get_cell(&self, x: u8, y: u8) -> PlantType {
-        if x >= GRID_SIZE as u8 || y >= GRID_SIZE as u8 {
+        if x >= self.width || y >= self.height {
             return PlantType::Empty;
         }
-        let index = (y as usize) * GRID_SIZE + (x as usize);
-        PlantType::from_u8(self.cells[index]).unwrap_or(PlantType::Empty)
+        let index = (y as usize) * (self.width as usize) + (x as usize);
+        self.cells
+            .get(index)
+            .and_then(|&cell| PlantType::from_u8(cell))
+            .unwrap_or(PlantType::Empty)
     }
 
     /// Count the total number of plants in the garden
@@ -128,16 +185,94 @@ impl GardenLayout {
         self.cells.iter().filter(|&&c| c != 0).count()
     }
 
-    /// Serialize for hashing (cells + salt)
+    /// Whether the cells left (x-1, y) and right (x+1, y) hold a plant.
+    /// Cells outside the grid count as empty.
+    pub fn peek_adjacent(&self, x: u8, y: u8) -> (bool, bool) {
+        let left = match x.checked_sub(1) {
+            Some(left_x) => self.get_cell(left_x, y).is_plant(),
+            None => false,
+        };
+        let right = self.get_cell(x.saturating_add(1), y).is_plant();
+        (left, right)
+    }
+
+    /// Number of plants in the SMELL_AHEAD_ROWS rows after y (y+1..=y+2),
+    /// clipped to the grid
+    pub fn plants_ahead(&self, y: u8) -> u8 {
+        let mut count = 0;
+        for row in (y as usize + 1)..=(y as usize + SMELL_AHEAD_ROWS as usize) {
+            if row >= self.height as usize {
+                break;
+            }
+            for x in 0..self.width {
+                if self.get_cell(x, row as u8).is_plant() {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Whether a `kind` Creature at (x, y) with `hp` left can still reach the
+    /// house alive under `moon`, taking the damage of every cell it enters.
+    ///
+    /// On a rule set whose moon cycles, `moon_clock` is the session's moon
+    /// clock now and every move advances it by one turn; otherwise the moon
+    /// stays `moon`.
+    ///
+    /// The Creature is assumed to have a Calming Mist reduction pending, its
+    /// best case: a garden with no safe path here has none whatever the
+    /// session's actual `damage_reduction`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn has_safe_path(
+        &self,
+        x: u8,
+        y: u8,
+        hp: u32,
+        kind: CreatureKind,
+        moon: MoonPhase,
+        moon_clock: u32,
+        rules: &RuleSet,
+    ) -> bool {
+        let search = PathSearch {
+            garden: self,
+            kind,
+            moon,
+            rules,
+        };
+        search.safe_from(x, y, hp, CALMING_MIST_REDUCTION as u32, moon_clock)
+    }
+
+    /// Serialize for hashing (width + height + cells + salt)
+    ///
+    /// The dimensions are hashed so that a commitment opens as exactly one
+    /// board shape (a 5x4 and a 4x5 garden have the same number of cells).
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(GRID_CELLS + SALT_LEN);
+        let mut bytes = Vec::with_capacity(2 + self.cells.len() + SALT_LEN);
+        bytes.push(self.width);
+        bytes.push(self.height);
         bytes.extend_from_slice(&self.cells);
         bytes.extend_from_slice(&self.salt);
         bytes
     }
 
-    /// Validate the garden layout
+    /// Validate the garden layout under the standard rules
     pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&DEFAULT_RULES)
+    }
+
+    /// Validate the garden layout under a session's rule set
+    pub fn validate_with(&self, rules: &RuleSet) -> Result<(), ValidationError> {
+        if !rules.is_valid() {
+            return Err(ValidationError::UnsupportedRuleSet);
+        }
+        if self.width as u32 != rules.board_width
+            || self.height as u32 != rules.board_height
+            || self.cells.len() != self.width as usize * self.height as usize
+        {
+            return Err(ValidationError::BoardSizeMismatch);
+        }
+
         let mut plant_count = 0;
 
         for (i, &cell) in self.cells.iter().enumerate() {
@@ -149,16 +284,16 @@ impl GardenLayout {
             if cell != 0 {
                 plant_count += 1;
 
-                // Row 4 (indices 20-24) is the Gardener's house - no plants allowed
-                let row = i / GRID_SIZE;
-                if row == 4 {
+                // Last row is the Gardener's house - no plants allowed
+                let row = i / self.width as usize;
+                if row == self.house_row() as usize {
                     return Err(ValidationError::PlantInHouseRow);
                 }
             }
         }
 
-        // Maximum 7 plants allowed
-        if plant_count > MAX_PLANTS {
+        // Plant limit of the rule set (7 by default)
+        if plant_count > rules.max_plants as usize {
             return Err(ValidationError::TooManyPlants);
         }
 
@@ -166,12 +301,57 @@ impl GardenLayout {
     }
 }
 
+/// Depth-first search over the Creature's legal moves (see `has_safe_path`)
+struct PathSearch<'a> {
+    garden: &'a GardenLayout,
+    kind: CreatureKind,
+    moon: MoonPhase,
+    rules: &'a RuleSet,
+}
+
+impl PathSearch<'_> {
+    fn safe_from(&self, x: u8, y: u8, hp: u32, reduction: u32, moon_clock: u32) -> bool {
+        if y >= self.garden.house_row() {
+            return true;
+        }
+        // The move starts the next turn, under that turn's moon
+        let moon_clock = moon_clock + 1;
+        let moon = self.rules.moon_at(self.moon, moon_clock);
+        for rows in 1..=MAX_MOVE_ROWS as u8 {
+            let to_y = y + rows;
+            if to_y >= self.garden.height {
+                break;
+            }
+            for to_x in 0..self.garden.width {
+                let columns = to_x.abs_diff(x) as u32;
+                if !self.kind.can_move(rows as u32, columns, y == 0) {
+                    continue;
+                }
+                // A move of several rows crosses every cell of the new column
+                let (mut hp_left, mut pending) = (hp, reduction);
+                let mut alive = true;
+                for row in y + 1..=to_y {
+                    let plant = self.garden.get_cell(to_x, row) as u8;
+                    let (damage, next) = hit(plant, moon, pending, self.rules);
+                    pending = next;
+                    if damage >= hp_left {
+                        alive = false;
+                        break;
+                    }
+                    hp_left -= damage;
+                }
+                if alive && self.safe_from(to_x, to_y, hp_left, pending, moon_clock) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
 impl Default for GardenLayout {
     fn default() -> Self {
-        Self {
-            cells: [0u8; GRID_CELLS],
-            salt: [0u8; SALT_LEN],
-        }
+        Self::new([0u8; GRID_CELLS], [0u8; SALT_LEN])
     }
 }
 
@@ -182,14 +362,18 @@ impl Default for GardenLayout {
 /// Errors that can occur during garden validation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValidationError {
-    /// Too many plants (max 7)
+    /// Too many plants (more than the rule set's max_plants)
     TooManyPlants,
     /// Invalid plant type value
     InvalidPlantType,
-    /// Plant placed in house row (row 4)
+    /// Plant placed in house row (the last row)
     PlantInHouseRow,
     /// Coordinates out of bounds
     CoordinatesOutOfBounds,
+    /// Rule set cannot be played (see `RuleSet::is_valid`)
+    UnsupportedRuleSet,
+    /// Layout dimensions or cell count differ from the rule set's board
+    BoardSizeMismatch,
 }
 
 // ============================================================================
@@ -202,13 +386,80 @@ pub type GardenCommitment = [u8; 32];
 /// Compute the commitment (hash) of a garden layout
 pub fn compute_garden_commitment(garden: &GardenLayout) -> GardenCommitment {
     let mut hasher = Sha256::new();
-    hasher.update(&garden.to_bytes());
+    hasher.update(garden.to_bytes());
     let result = hasher.finalize();
     let mut commitment = [0u8; 32];
     commitment.copy_from_slice(&result);
     commitment
 }
 
+/// Rule set hash type (SHA256 of the canonical encoding)
+pub type RulesHash = [u8; 32];
+
+/// Compute the hash of a rule set, as stored on the contract's GameSession
+pub fn compute_rules_hash(rules: &RuleSet) -> RulesHash {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&Sha256::digest(rules.to_bytes()));
+    hash
+}
+
+// ============================================================================
+// Circuit Checks
+// ============================================================================
+
+/// Garden commitment and session binding that every journal carries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JournalHeader {
+    /// Commitment of the verified garden
+    pub garden_commitment: GardenCommitment,
+
+    /// Session ID the proof is for
+    pub session_id: u32,
+
+    /// Gardener who generated the proof
+    pub gardener_pubkey: [u8; 32],
+
+    /// Hash of the rule set the garden was validated under
+    pub rules_hash: RulesHash,
+}
+
+/// Reasons a circuit refuses to prove anything about a garden
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The garden is not valid under the session's rule set
+    InvalidGarden(ValidationError),
+    /// The garden does not open the commitment held by the contract
+    CommitmentMismatch,
+}
+
+/// Checks shared by every circuit: the garden must be valid under the
+/// session's rule set and, when the contract already holds a commitment
+/// (`expected_commitment`), open it. Returns the journal header binding the
+/// proof to the session, gardener and rule set.
+pub fn verify_commitment_and_header(
+    garden: &GardenLayout,
+    rules: &RuleSet,
+    expected_commitment: Option<&GardenCommitment>,
+    session_id: u32,
+    gardener_pubkey: [u8; 32],
+) -> Result<JournalHeader, CircuitError> {
+    garden
+        .validate_with(rules)
+        .map_err(CircuitError::InvalidGarden)?;
+
+    let garden_commitment = compute_garden_commitment(garden);
+    if expected_commitment.is_some_and(|expected| *expected != garden_commitment) {
+        return Err(CircuitError::CommitmentMismatch);
+    }
+
+    Ok(JournalHeader {
+        garden_commitment,
+        session_id,
+        gardener_pubkey,
+        rules_hash: compute_rules_hash(rules),
+    })
+}
+
 // ============================================================================
 // ZK Circuit Input/Output
 // ============================================================================
@@ -236,6 +487,9 @@ pub struct CellRevealInput {
 
     /// Public key of the Gardener (public)
     pub gardener_pubkey: [u8; 32],
+
+    /// Rule set of the session (public, committed as its hash)
+    pub rules: RuleSet,
 }
 
 /// Output from the ZK circuit (Journal)
@@ -267,6 +521,9 @@ pub struct CellRevealOutput {
 
     /// Gardener who generated this proof
     pub gardener_pubkey: [u8; 32],
+
+    /// Hash of the rule set the garden was validated under
+    pub rules_hash: RulesHash,
 }
 
 impl CellRevealOutput {
@@ -304,6 +561,10 @@ impl CellRevealOutput {
 
         // Gardener public key (32 bytes)
         out[offset..offset + 32].copy_from_slice(&self.gardener_pubkey);
+        offset += 32;
+
+        // Rules hash (32 bytes)
+        out[offset..offset + 32].copy_from_slice(&self.rules_hash);
 
         out
     }
@@ -346,6 +607,11 @@ impl CellRevealOutput {
         // Gardener public key
         let mut gardener_pubkey = [0u8; 32];
         gardener_pubkey.copy_from_slice(&bytes[offset..offset + 32]);
+        offset += 32;
+
+        // Rules hash
+        let mut rules_hash = [0u8; 32];
+        rules_hash.copy_from_slice(&bytes[offset..offset + 32]);
 
         Some(Self {
             garden_commitment,
@@ -356,6 +622,514 @@ impl CellRevealOutput {
             damage,
             session_id,
             gardener_pubkey,
+            rules_hash,
+        })
+    }
+}
+
+// ============================================================================
+// Spirit Sense Input/Output
+// ============================================================================
+
+/// Spirit Sense abilities (must match the contract's SenseKind)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum SenseKind {
+    /// Reveals whether the left/right cells have plants
+    PeekAdjacent = 0,
+    /// Reveals the plant count in the next two rows
+    SmellAhead = 1,
+}
+
+impl SenseKind {
+    /// Convert from u8 to SenseKind
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(SenseKind::PeekAdjacent),
+            1 => Some(SenseKind::SmellAhead),
+            _ => None,
+        }
+    }
+}
+
+/// What a Spirit Sense reveals. Never exposes plant types or exact positions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SenseReading {
+    /// Peek Adjacent: whether (x-1, y) and (x+1, y) hold a plant
+    Adjacent { left: bool, right: bool },
+    /// Smell Ahead: number of plants in rows y+1..=y+2
+    Ahead { plants: u8 },
+}
+
+impl SenseReading {
+    /// The sense that produced this reading
+    pub fn kind(&self) -> SenseKind {
+        match self {
+            SenseReading::Adjacent { .. } => SenseKind::PeekAdjacent,
+            SenseReading::Ahead { .. } => SenseKind::SmellAhead,
+        }
+    }
+}
+
+/// Input for the Spirit Sense circuits (peek-adjacent, smell-ahead)
+///
+/// Like CellRevealInput, the garden is PRIVATE; (x, y) is the Creature's
+/// position on-chain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SenseInput {
+    /// Complete garden layout (PRIVATE - never leaves the prover)
+    pub garden: GardenLayout,
+
+    /// X coordinate of the Creature (public)
+    pub x: u8,
+
+    /// Y coordinate of the Creature (public)
+    pub y: u8,
+
+    /// Expected commitment stored on-chain (public)
+    pub expected_commitment: [u8; 32],
+
+    /// Session ID of the game (public)
+    pub session_id: u32,
+
+    /// Public key of the Gardener (public)
+    pub gardener_pubkey: [u8; 32],
+
+    /// Rule set of the session (public, committed as its hash)
+    pub rules: RuleSet,
+}
+
+/// Output from the Spirit Sense circuits (Journal)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SenseOutput {
+    /// Hash of the verified garden
+    pub garden_commitment: [u8; 32],
+
+    /// X coordinate the sense was used from
+    pub x: u8,
+
+    /// Y coordinate the sense was used from
+    pub y: u8,
+
+    /// What the Creature learns
+    pub reading: SenseReading,
+
+    /// Session ID this proof is for
+    pub session_id: u32,
+
+    /// Gardener who generated this proof
+    pub gardener_pubkey: [u8; 32],
+
+    /// Hash of the rule set the garden was validated under
+    pub rules_hash: RulesHash,
+}
+
+impl SenseOutput {
+    /// Serialize the output to bytes for the journal
+    /// The reading is [left, right] for Peek Adjacent and [plants, 0] for Smell Ahead
+    pub fn to_bytes(&self) -> [u8; SENSE_JOURNAL_LEN] {
+        let mut out = [0u8; SENSE_JOURNAL_LEN];
+
+        out[0..32].copy_from_slice(&self.garden_commitment);
+        out[32] = self.reading.kind() as u8;
+        out[33] = self.x;
+        out[34] = self.y;
+        match self.reading {
+            SenseReading::Adjacent { left, right } => {
+                out[35] = left as u8;
+                out[36] = right as u8;
+            }
+            SenseReading::Ahead { plants } => {
+                out[35] = plants;
+            }
+        }
+        out[37..41].copy_from_slice(&self.session_id.to_le_bytes());
+        out[41..73].copy_from_slice(&self.gardener_pubkey);
+        out[73..105].copy_from_slice(&self.rules_hash);
+
+        out
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != SENSE_JOURNAL_LEN {
+            return None;
+        }
+
+        let mut garden_commitment = [0u8; 32];
+        garden_commitment.copy_from_slice(&bytes[0..32]);
+
+        let reading = match SenseKind::from_u8(bytes[32])? {
+            SenseKind::PeekAdjacent => SenseReading::Adjacent {
+                left: bytes[35] != 0,
+                right: bytes[36] != 0,
+            },
+            SenseKind::SmellAhead => SenseReading::Ahead { plants: bytes[35] },
+        };
+
+        let session_id = u32::from_le_bytes(bytes[37..41].try_into().ok()?);
+
+        let mut gardener_pubkey = [0u8; 32];
+        gardener_pubkey.copy_from_slice(&bytes[41..73]);
+
+        let mut rules_hash = [0u8; 32];
+        rules_hash.copy_from_slice(&bytes[73..105]);
+
+        Some(Self {
+            garden_commitment,
+            x: bytes[33],
+            y: bytes[34],
+            reading,
+            session_id,
+            gardener_pubkey,
+            rules_hash,
+        })
+    }
+}
+
+// ============================================================================
+// No Path Input/Output
+// ============================================================================
+
+/// Input for the no-path circuit: the Gardener proves that every path from
+/// the Creature's position to the house is lethal
+///
+/// The garden is PRIVATE; the Creature's state is public on-chain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoPathInput {
+    /// Complete garden layout (PRIVATE - never leaves the prover)
+    pub garden: GardenLayout,
+
+    /// X coordinate of the Creature (public)
+    pub x: u8,
+
+    /// Y coordinate of the Creature (public)
+    pub y: u8,
+
+    /// Kind of the Creature, deciding its moves (public)
+    pub creature_kind: CreatureKind,
+
+    /// Moon phase of the session (public)
+    pub moon_phase: MoonPhase,
+
+    /// HP the Creature has left (public)
+    pub creature_hp: u8,
+
+    /// Moon clock of the session, 0 unless the rule set's moon cycles (public)
+    pub moon_clock: u8,
+
+    /// Expected commitment stored on-chain (public)
+    pub expected_commitment: [u8; 32],
+
+    /// Session ID of the game (public)
+    pub session_id: u32,
+
+    /// Public key of the Gardener (public)
+    pub gardener_pubkey: [u8; 32],
+
+    /// Rule set of the session (public, committed as its hash)
+    pub rules: RuleSet,
+}
+
+/// Output from the no-path circuit (Journal)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NoPathOutput {
+    /// Hash of the verified garden
+    pub garden_commitment: [u8; 32],
+
+    /// X coordinate of the Creature
+    pub x: u8,
+
+    /// Y coordinate of the Creature
+    pub y: u8,
+
+    /// Kind of the Creature
+    pub creature_kind: CreatureKind,
+
+    /// Moon phase the damage was computed under
+    pub moon_phase: MoonPhase,
+
+    /// HP that every path to the house exhausts
+    pub creature_hp: u8,
+
+    /// Moon clock the cycling moon was followed from
+    pub moon_clock: u8,
+
+    /// Session ID this proof is for
+    pub session_id: u32,
+
+    /// Gardener who generated this proof
+    pub gardener_pubkey: [u8; 32],
+
+    /// Hash of the rule set the garden was validated under
+    pub rules_hash: RulesHash,
+}
+
+impl NoPathOutput {
+    /// Serialize the output to bytes for the journal
+    pub fn to_bytes(&self) -> [u8; NO_PATH_JOURNAL_LEN] {
+        let mut out = [0u8; NO_PATH_JOURNAL_LEN];
+
+        out[0..32].copy_from_slice(&self.garden_commitment);
+        out[32] = self.x;
+        out[33] = self.y;
+        out[34] = self.creature_kind as u8;
+        out[35] = self.moon_phase as u8;
+        out[36] = self.creature_hp;
+        out[37] = self.moon_clock;
+        out[38..42].copy_from_slice(&self.session_id.to_le_bytes());
+        out[42..74].copy_from_slice(&self.gardener_pubkey);
+        out[74..106].copy_from_slice(&self.rules_hash);
+
+        out
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != NO_PATH_JOURNAL_LEN {
+            return None;
+        }
+
+        let mut garden_commitment = [0u8; 32];
+        garden_commitment.copy_from_slice(&bytes[0..32]);
+
+        let session_id = u32::from_le_bytes(bytes[38..42].try_into().ok()?);
+
+        let mut gardener_pubkey = [0u8; 32];
+        gardener_pubkey.copy_from_slice(&bytes[42..74]);
+
+        let mut rules_hash = [0u8; 32];
+        rules_hash.copy_from_slice(&bytes[74..106]);
+
+        Some(Self {
+            garden_commitment,
+            x: bytes[32],
+            y: bytes[33],
+            creature_kind: CreatureKind::from_u8(bytes[34])?,
+            moon_phase: MoonPhase::from_u8(bytes[35])?,
+            creature_hp: bytes[36],
+            moon_clock: bytes[37],
+            session_id,
+            gardener_pubkey,
+            rules_hash,
+        })
+    }
+}
+
+// ============================================================================
+// Batch Reveal Input/Output
+// ============================================================================
+
+/// Input for the batch reveal circuit: several cells proven in one receipt
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchRevealInput {
+    /// Complete garden layout (PRIVATE - never leaves the prover)
+    pub garden: GardenLayout,
+
+    /// Coordinates to reveal, in order (public, 1..=MAX_BATCH_CELLS)
+    pub cells: Vec<(u8, u8)>,
+
+    /// Expected commitment stored on-chain (public)
+    pub expected_commitment: [u8; 32],
+
+    /// Session ID of the game (public)
+    pub session_id: u32,
+
+    /// Public key of the Gardener (public)
+    pub gardener_pubkey: [u8; 32],
+
+    /// Rule set of the session (public, committed as its hash)
+    pub rules: RuleSet,
+}
+
+/// One revealed cell of a batch, as in a single cell reveal
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RevealedCell {
+    /// X coordinate that was revealed
+    pub x: u8,
+
+    /// Y coordinate that was revealed
+    pub y: u8,
+
+    /// Whether there is a plant in this cell
+    pub has_plant: bool,
+
+    /// Type of plant (0 = empty, 1-3 = plant type)
+    pub plant_type: u8,
+
+    /// Damage this plant deals
+    pub damage: u8,
+}
+
+impl RevealedCell {
+    /// Read the content of (x, y) from the garden
+    pub fn from_garden(garden: &GardenLayout, x: u8, y: u8) -> Self {
+        let plant = garden.get_cell(x, y);
+        Self {
+            x,
+            y,
+            has_plant: plant.is_plant(),
+            plant_type: plant as u8,
+            damage: plant.damage(),
+        }
+    }
+}
+
+/// Output from the batch reveal circuit (variable-length journal)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchRevealOutput {
+    /// Hash of the verified garden
+    pub garden_commitment: [u8; 32],
+
+    /// Revealed cells, in the order they were requested
+    pub cells: Vec<RevealedCell>,
+
+    /// Session ID this proof is for
+    pub session_id: u32,
+
+    /// Gardener who generated this proof
+    pub gardener_pubkey: [u8; 32],
+
+    /// Hash of the rule set the garden was validated under
+    pub rules_hash: RulesHash,
+}
+
+impl BatchRevealOutput {
+    /// Serialize the output to bytes for the journal
+    /// (`cells` must hold at most MAX_BATCH_CELLS entries)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(batch_journal_len(self.cells.len()));
+
+        out.extend_from_slice(&self.garden_commitment);
+        out.push(self.cells.len() as u8);
+        for cell in &self.cells {
+            out.extend_from_slice(&[
+                cell.x,
+                cell.y,
+                cell.has_plant as u8,
+                cell.plant_type,
+                cell.damage,
+            ]);
+        }
+        out.extend_from_slice(&self.session_id.to_le_bytes());
+        out.extend_from_slice(&self.gardener_pubkey);
+        out.extend_from_slice(&self.rules_hash);
+
+        out
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let count = *bytes.get(32)? as usize;
+        if count == 0 || bytes.len() != batch_journal_len(count) {
+            return None;
+        }
+
+        let mut garden_commitment = [0u8; 32];
+        garden_commitment.copy_from_slice(&bytes[0..32]);
+
+        let cells = bytes[33..33 + count * BATCH_CELL_LEN]
+            .chunks_exact(BATCH_CELL_LEN)
+            .map(|cell| RevealedCell {
+                x: cell[0],
+                y: cell[1],
+                has_plant: cell[2] != 0,
+                plant_type: cell[3],
+                damage: cell[4],
+            })
+            .collect();
+
+        let binding = &bytes[33 + count * BATCH_CELL_LEN..];
+        let session_id = u32::from_le_bytes(binding[0..4].try_into().ok()?);
+
+        let mut gardener_pubkey = [0u8; 32];
+        gardener_pubkey.copy_from_slice(&binding[4..36]);
+
+        let mut rules_hash = [0u8; 32];
+        rules_hash.copy_from_slice(&binding[36..68]);
+
+        Some(Self {
+            garden_commitment,
+            cells,
+            session_id,
+            gardener_pubkey,
+            rules_hash,
+        })
+    }
+}
+
+// ============================================================================
+// Garden Validity Input/Output
+// ============================================================================
+
+/// Input for the garden validity circuit: proves at commit time that the
+/// commitment opens to a garden that is valid under the session's rules
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GardenValidityInput {
+    /// Complete garden layout (PRIVATE - never leaves the prover)
+    pub garden: GardenLayout,
+
+    /// Session ID of the game (public)
+    pub session_id: u32,
+
+    /// Public key of the Gardener (public)
+    pub gardener_pubkey: [u8; 32],
+
+    /// Rule set of the session (public, committed as its hash)
+    pub rules: RuleSet,
+}
+
+/// Output from the garden validity circuit (Journal)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GardenValidityOutput {
+    /// Commitment of the valid garden
+    pub garden_commitment: [u8; 32],
+
+    /// Session ID this proof is for
+    pub session_id: u32,
+
+    /// Gardener who generated this proof
+    pub gardener_pubkey: [u8; 32],
+
+    /// Hash of the rule set the garden was validated under
+    pub rules_hash: RulesHash,
+}
+
+impl GardenValidityOutput {
+    /// Serialize the output to bytes for the journal
+    pub fn to_bytes(&self) -> [u8; GARDEN_VALIDITY_JOURNAL_LEN] {
+        let mut out = [0u8; GARDEN_VALIDITY_JOURNAL_LEN];
+
+        out[0..32].copy_from_slice(&self.garden_commitment);
+        out[32..36].copy_from_slice(&self.session_id.to_le_bytes());
+        out[36..68].copy_from_slice(&self.gardener_pubkey);
+        out[68..100].copy_from_slice(&self.rules_hash);
+
+        out
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != GARDEN_VALIDITY_JOURNAL_LEN {
+            return None;
+        }
+
+        let mut garden_commitment = [0u8; 32];
+        garden_commitment.copy_from_slice(&bytes[0..32]);
+
+        let session_id = u32::from_le_bytes(bytes[32..36].try_into().ok()?);
+
+        let mut gardener_pubkey = [0u8; 32];
+        gardener_pubkey.copy_from_slice(&bytes[36..68]);
+
+        let mut rules_hash = [0u8; 32];
+        rules_hash.copy_from_slice(&bytes[68..100]);
+
+        Some(Self {
+            garden_commitment,
+            session_id,
+            gardener_pubkey,
+            rules_hash,
         })
     }
 }
@@ -382,7 +1156,21 @@ mod tests {
         assert_eq!(PlantType::Empty.damage(), 0);
         assert_eq!(PlantType::Lavender.damage(), 1);
         assert_eq!(PlantType::Mint.damage(), 2);
-        assert_eq!(PlantType::Mandrake.damage(), 1);
+        assert_eq!(PlantType::Mandrake.damage(), 3);
+    }
+
+    #[test]
+    fn test_plant_types_match_rules() {
+        // Every rule has a PlantType and every PlantType plant has a rule
+        for rule in herbal_rules::PLANTS.iter() {
+            let plant = PlantType::from_u8(rule.id).unwrap();
+            assert!(plant.is_plant());
+            assert_eq!(plant.damage(), rule.base_damage);
+        }
+        assert_eq!(
+            PlantType::from_u8(herbal_rules::PLANTS.len() as u8 + 1),
+            None
+        );
     }
 
     #[test]
@@ -420,8 +1208,8 @@ mod tests {
     fn test_garden_validation_too_many_plants() {
         let mut cells = [0u8; GRID_CELLS];
         // Place 8 plants (max is 7)
-        for i in 0..8 {
-            cells[i] = 1;
+        for cell in cells.iter_mut().take(8) {
+            *cell = 1;
         }
 
         let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
@@ -464,6 +1252,57 @@ mod tests {
         assert_ne!(commitment1, commitment2);
     }
 
+    #[test]
+    fn test_commitment_binds_board_shape() {
+        // The same 20 cells and salt, as a 5x4 and a 4x5 board
+        let cells = alloc::vec![0u8; 20];
+        let wide = GardenLayout::with_size(5, 4, cells.clone(), [42u8; SALT_LEN]);
+        let tall = GardenLayout::with_size(4, 5, cells, [42u8; SALT_LEN]);
+
+        assert_ne!(
+            compute_garden_commitment(&wide),
+            compute_garden_commitment(&tall)
+        );
+    }
+
+    #[test]
+    fn test_verify_commitment_and_header() {
+        let mut cells = [0u8; GRID_CELLS];
+        cells[7] = 2;
+        let garden = GardenLayout::new(cells, [9u8; SALT_LEN]);
+        let commitment = compute_garden_commitment(&garden);
+
+        let header =
+            verify_commitment_and_header(&garden, &DEFAULT_RULES, Some(&commitment), 7, [3u8; 32])
+                .unwrap();
+        assert_eq!(
+            header,
+            JournalHeader {
+                garden_commitment: commitment,
+                session_id: 7,
+                gardener_pubkey: [3u8; 32],
+                rules_hash: compute_rules_hash(&DEFAULT_RULES),
+            }
+        );
+        // Without an expected commitment (garden validity), only the garden is checked
+        let unbound = verify_commitment_and_header(&garden, &DEFAULT_RULES, None, 7, [3u8; 32]);
+        assert_eq!(unbound, Ok(header));
+
+        let result =
+            verify_commitment_and_header(&garden, &DEFAULT_RULES, Some(&[0u8; 32]), 7, [3u8; 32]);
+        assert_eq!(result, Err(CircuitError::CommitmentMismatch));
+
+        cells[22] = 1; // House row
+        let invalid = GardenLayout::new(cells, [9u8; SALT_LEN]);
+        let result = verify_commitment_and_header(&invalid, &DEFAULT_RULES, None, 7, [3u8; 32]);
+        assert_eq!(
+            result,
+            Err(CircuitError::InvalidGarden(
+                ValidationError::PlantInHouseRow
+            ))
+        );
+    }
+
     #[test]
     fn test_cell_reveal_output_serialization() {
         let output = CellRevealOutput {
@@ -475,6 +1314,7 @@ mod tests {
             damage: 2,
             session_id: 42,
             gardener_pubkey: [7u8; 32],
+            rules_hash: [9u8; 32],
         };
 
         let bytes = output.to_bytes();
@@ -494,10 +1334,314 @@ mod tests {
             damage: 0,
             session_id: 0,
             gardener_pubkey: [0u8; 32],
+            rules_hash: [0u8; 32],
         };
 
         let bytes = output.to_bytes();
         assert_eq!(bytes.len(), JOURNAL_LEN);
-        assert_eq!(bytes.len(), 73);
+        assert_eq!(bytes.len(), 105);
+    }
+
+    #[test]
+    fn test_garden_validation_with_rules() {
+        let mut cells = [0u8; GRID_CELLS];
+        for cell in cells.iter_mut().take(4) {
+            *cell = 1;
+        }
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+
+        let sparse = RuleSet {
+            max_plants: 3,
+            ..DEFAULT_RULES
+        };
+        assert!(garden.validate_with(&DEFAULT_RULES).is_ok());
+        assert_eq!(
+            garden.validate_with(&sparse),
+            Err(ValidationError::TooManyPlants)
+        );
+
+        let wide = RuleSet {
+            board_width: 7,
+            ..DEFAULT_RULES
+        };
+        assert_eq!(
+            garden.validate_with(&wide),
+            Err(ValidationError::BoardSizeMismatch)
+        );
+
+        let huge = RuleSet {
+            board_width: 12,
+            ..DEFAULT_RULES
+        };
+        assert_eq!(
+            garden.validate_with(&huge),
+            Err(ValidationError::UnsupportedRuleSet)
+        );
+    }
+
+    #[test]
+    fn test_larger_board() {
+        // 7 wide, 9 tall: the house is row 8
+        let rules = RuleSet {
+            board_width: 7,
+            board_height: 9,
+            ..DEFAULT_RULES
+        };
+        let mut cells = alloc::vec![0u8; 7 * 9];
+        cells[7 * 4 + 6] = 3; // (6,4) Mandrake
+        cells[7 * 7 + 5] = 2; // (5,7) Mint
+        let garden = GardenLayout::with_size(7, 9, cells.clone(), [0u8; SALT_LEN]);
+
+        assert_eq!(garden.validate_with(&rules), Ok(()));
+        assert_eq!(garden.validate(), Err(ValidationError::BoardSizeMismatch));
+        assert_eq!(garden.house_row(), 8);
+        assert_eq!(garden.get_cell(6, 4), PlantType::Mandrake);
+        assert_eq!(garden.get_cell(7, 4), PlantType::Empty); // past the right edge
+        assert_eq!(garden.peek_adjacent(5, 4), (false, true));
+        assert_eq!(garden.plants_ahead(5), 1); // rows 6-7
+        assert_eq!(garden.plants_ahead(7), 0); // row 8 only
+
+        cells[7 * 8] = 1; // (0,8) in the house row
+        let garden = GardenLayout::with_size(7, 9, cells, [0u8; SALT_LEN]);
+        assert_eq!(
+            garden.validate_with(&rules),
+            Err(ValidationError::PlantInHouseRow)
+        );
+
+        // Cell count must match the dimensions
+        let short = GardenLayout::with_size(7, 9, alloc::vec![0u8; 7 * 8], [0u8; SALT_LEN]);
+        assert_eq!(
+            short.validate_with(&rules),
+            Err(ValidationError::BoardSizeMismatch)
+        );
+    }
+
+    #[test]
+    fn test_rules_hash() {
+        let variant = RuleSet {
+            sense_hp_cost: 2,
+            ..DEFAULT_RULES
+        };
+        assert_eq!(
+            compute_rules_hash(&DEFAULT_RULES),
+            compute_rules_hash(&DEFAULT_RULES)
+        );
+        assert_ne!(
+            compute_rules_hash(&DEFAULT_RULES),
+            compute_rules_hash(&variant)
+        );
+    }
+
+    #[test]
+    fn test_peek_adjacent() {
+        let mut cells = [0u8; GRID_CELLS];
+        cells[5] = 1; // (0,1)
+        cells[7] = 2; // (2,1)
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+
+        assert_eq!(garden.peek_adjacent(1, 1), (true, true));
+        assert_eq!(garden.peek_adjacent(3, 1), (true, false));
+        // Left edge: nothing beyond column 0
+        assert_eq!(garden.peek_adjacent(0, 1), (false, false));
+        assert_eq!(garden.peek_adjacent(4, 1), (false, false));
+    }
+
+    #[test]
+    fn test_plants_ahead() {
+        let mut cells = [0u8; GRID_CELLS];
+        cells[5] = 1; // (0,1)
+        cells[12] = 3; // (2,2)
+        cells[18] = 2; // (3,3)
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+
+        assert_eq!(garden.plants_ahead(0), 2); // rows 1-2
+        assert_eq!(garden.plants_ahead(1), 2); // rows 2-3
+        assert_eq!(garden.plants_ahead(2), 1); // rows 3-4
+        assert_eq!(garden.plants_ahead(4), 0); // past the grid
+    }
+
+    #[test]
+    fn test_sense_output_serialization() {
+        for reading in [
+            SenseReading::Adjacent {
+                left: true,
+                right: false,
+            },
+            SenseReading::Ahead { plants: 3 },
+        ] {
+            let output = SenseOutput {
+                garden_commitment: [1u8; 32],
+                x: 2,
+                y: 1,
+                reading,
+                session_id: 42,
+                gardener_pubkey: [7u8; 32],
+                rules_hash: [9u8; 32],
+            };
+
+            let bytes = output.to_bytes();
+            assert_eq!(bytes.len(), SENSE_JOURNAL_LEN);
+            // Session binding sits at the same offsets as in the cell reveal journal
+            assert_eq!(&bytes[37..41], &42u32.to_le_bytes());
+            assert_eq!(&bytes[73..105], &[9u8; 32]);
+            assert_eq!(SenseOutput::from_bytes(&bytes), Some(output));
+        }
+    }
+
+    #[test]
+    fn test_has_safe_path() {
+        // Rows 1 and 2 are solid Mandrake (3 damage each on a Balanced moon)
+        let rules = RuleSet {
+            max_plants: 10,
+            ..DEFAULT_RULES
+        };
+        let mut cells = [0u8; GRID_CELLS];
+        for cell in cells.iter_mut().skip(5).take(10) {
+            *cell = 3;
+        }
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+        assert!(garden.validate_with(&rules).is_ok());
+
+        // Assumed pending Calming Mist: 2 + 3 damage to cross both rows
+        for kind in [
+            CreatureKind::Ghost,
+            CreatureKind::MoonMoth,
+            CreatureKind::ShadowWolf,
+        ] {
+            assert!(garden.has_safe_path(2, 0, 6, kind, MoonPhase::Balanced, 0, &rules));
+            assert!(!garden.has_safe_path(2, 0, 5, kind, MoonPhase::Balanced, 0, &rules));
+        }
+        // Full Moon: 1 + 2 damage
+        let (ghost, full) = (CreatureKind::Ghost, MoonPhase::FullMoon);
+        assert!(garden.has_safe_path(2, 0, 4, ghost, full, 0, &rules));
+        assert!(!garden.has_safe_path(2, 0, 3, ghost, full, 0, &rules));
+        // Past the wall any HP gets home
+        assert!(garden.has_safe_path(0, 2, 1, CreatureKind::Ghost, MoonPhase::NewMoon, 0, &rules));
+    }
+
+    #[test]
+    fn test_has_safe_path_follows_creature_moves() {
+        // A Mandrake wall in row 2 with a gap at (0,2), and Mint at (1,1)
+        let mut cells = [0u8; GRID_CELLS];
+        for x in 1..5 {
+            cells[10 + x] = 3;
+        }
+        cells[6] = 2;
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+        let (balanced, rules) = (MoonPhase::Balanced, &DEFAULT_RULES);
+
+        // From (1,1) a Ghost steps sideways into the gap; a Shadow Wolf cannot
+        assert!(garden.has_safe_path(1, 1, 1, CreatureKind::Ghost, balanced, 0, rules));
+        assert!(!garden.has_safe_path(1, 1, 2, CreatureKind::ShadowWolf, balanced, 0, rules));
+        assert!(garden.has_safe_path(0, 1, 1, CreatureKind::ShadowWolf, balanced, 0, rules));
+        // A Moon Moth Flutter still crosses the cells it flies over
+        assert!(!garden.has_safe_path(3, 1, 2, CreatureKind::MoonMoth, balanced, 0, rules));
+        assert!(garden.has_safe_path(3, 1, 3, CreatureKind::MoonMoth, balanced, 0, rules));
+    }
+
+    #[test]
+    fn test_has_safe_path_follows_moon_cycle() {
+        // Mandrake at (2,2) and (2,3), straight ahead of a Shadow Wolf at (2,1)
+        let mut cells = [0u8; GRID_CELLS];
+        cells[12] = 3;
+        cells[17] = 3;
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+        let rules = RuleSet {
+            moon_cycle_turns: 1,
+            ..DEFAULT_RULES
+        };
+        let wolf = CreatureKind::ShadowWolf;
+        let balanced = MoonPhase::Balanced;
+
+        // A fixed Balanced moon: 2 + 3 damage
+        assert!(garden.has_safe_path(2, 1, 6, wolf, balanced, 1, &DEFAULT_RULES));
+        assert!(!garden.has_safe_path(2, 1, 5, wolf, balanced, 1, &DEFAULT_RULES));
+        // From clock 1 the moves fall on the Full Moon and the waning moon: 1 + 3
+        assert!(garden.has_safe_path(2, 1, 5, wolf, balanced, 1, &rules));
+        assert!(!garden.has_safe_path(2, 1, 4, wolf, balanced, 1, &rules));
+        // From clock 3 on the New Moon and the waxing moon: 3 + 3
+        assert!(garden.has_safe_path(2, 1, 7, wolf, balanced, 3, &rules));
+        assert!(!garden.has_safe_path(2, 1, 6, wolf, balanced, 3, &rules));
+    }
+
+    #[test]
+    fn test_no_path_output_serialization() {
+        let output = NoPathOutput {
+            garden_commitment: [1u8; 32],
+            x: 3,
+            y: 2,
+            creature_kind: CreatureKind::MoonMoth,
+            moon_phase: MoonPhase::NewMoon,
+            creature_hp: 4,
+            moon_clock: 5,
+            session_id: 42,
+            gardener_pubkey: [7u8; 32],
+            rules_hash: [9u8; 32],
+        };
+
+        let bytes = output.to_bytes();
+        assert_eq!(bytes.len(), NO_PATH_JOURNAL_LEN);
+        assert_eq!(&bytes[32..38], &[3, 2, 1, 1, 4, 5]);
+        // Session binding sits in the last 68 bytes, as in every journal
+        assert_eq!(&bytes[38..42], &42u32.to_le_bytes());
+        assert_eq!(&bytes[74..106], &[9u8; 32]);
+        assert_eq!(NoPathOutput::from_bytes(&bytes), Some(output));
+
+        let mut bad_kind = bytes;
+        bad_kind[34] = 7;
+        assert_eq!(NoPathOutput::from_bytes(&bad_kind), None);
+    }
+
+    #[test]
+    fn test_batch_reveal_output_serialization() {
+        let mut cells = [0u8; GRID_CELLS];
+        cells[7] = 2; // (2,1) Mint
+        let garden = GardenLayout::new(cells, [0u8; SALT_LEN]);
+        let output = BatchRevealOutput {
+            garden_commitment: [1u8; 32],
+            cells: alloc::vec![
+                RevealedCell::from_garden(&garden, 2, 1),
+                RevealedCell::from_garden(&garden, 2, 2),
+            ],
+            session_id: 42,
+            gardener_pubkey: [7u8; 32],
+            rules_hash: [9u8; 32],
+        };
+
+        let bytes = output.to_bytes();
+        assert_eq!(bytes.len(), batch_journal_len(2));
+        assert_eq!(&bytes[32..43], &[2, 2, 1, 1, 2, 2, 2, 2, 0, 0, 0]);
+        // Session binding is always the last 68 bytes
+        let binding = bytes.len() - 68;
+        assert_eq!(&bytes[binding..binding + 4], &42u32.to_le_bytes());
+        assert_eq!(&bytes[binding + 36..], &[9u8; 32]);
+        assert_eq!(BatchRevealOutput::from_bytes(&bytes), Some(output));
+
+        // The count must match the length, and a batch is never empty
+        let mut bad_count = bytes.clone();
+        bad_count[32] = 3;
+        assert_eq!(BatchRevealOutput::from_bytes(&bad_count), None);
+        let mut empty = bytes[..33].to_vec();
+        empty[32] = 0;
+        empty.extend_from_slice(&bytes[binding..]);
+        assert_eq!(BatchRevealOutput::from_bytes(&empty), None);
+    }
+
+    #[test]
+    fn test_garden_validity_output_serialization() {
+        let output = GardenValidityOutput {
+            garden_commitment: [1u8; 32],
+            session_id: 42,
+            gardener_pubkey: [7u8; 32],
+            rules_hash: [9u8; 32],
+        };
+
+        let bytes = output.to_bytes();
+        assert_eq!(bytes.len(), GARDEN_VALIDITY_JOURNAL_LEN);
+        // Session binding is the last 68 bytes, as in every journal
+        assert_eq!(&bytes[32..36], &42u32.to_le_bytes());
+        assert_eq!(&bytes[68..], &[9u8; 32]);
+        assert_eq!(GardenValidityOutput::from_bytes(&bytes), Some(output));
+        assert_eq!(GardenValidityOutput::from_bytes(&bytes[..99]), None);
     }
 }